@@ -231,6 +231,8 @@ pub enum BinaryExprOp {
     GreaterThanOrEqual,
     #[serde(rename = "<=")]
     LessThanOrEqual,
+    #[serde(rename = "|>")]
+    Pipeline,
 }
 
 #[derive(Debug, Clone, PartialEq)]
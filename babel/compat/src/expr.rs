@@ -6,10 +6,11 @@ use swc_babel_ast::{
     AwaitExpression, BinaryExprLeft, BinaryExpression, CallExpression, Callee, ClassExpression,
     ConditionalExpression, Expression, FunctionExpression, LVal, LogicalExpression, MemberExprProp,
     MemberExpression, MetaProperty, NewExpression, ObjectExprProp, ObjectExpression, ObjectKey,
-    ObjectMember, ParenthesizedExpression, PrivateName, SequenceExpression,
-    SpreadElement as BabelSpreadElement, Super as BabelSuper, TaggedTemplateExprTypeParams,
-    TaggedTemplateExpression, TemplateElVal, TemplateElement, TemplateLiteral, TemplateLiteralExpr,
-    ThisExpression, UnaryExpression, UpdateExpression, YieldExpression,
+    ObjectMember, ParenthesizedExpression, PipelinePrimaryTopicReference, PrivateName,
+    SequenceExpression, SpreadElement as BabelSpreadElement, Super as BabelSuper,
+    TaggedTemplateExprTypeParams, TaggedTemplateExpression, TemplateElVal, TemplateElement,
+    TemplateLiteral, TemplateLiteralExpr, ThisExpression, UnaryExpression, UpdateExpression,
+    YieldExpression,
 };
 use swc_common::Spanned;
 use swc_ecma_ast::{
@@ -153,6 +154,11 @@ impl Babelify for Expr {
                 "illegal conversion: Cannot convert {:?} to ExprOutput - babel has no equivelent",
                 &self
             ),
+            Expr::TopicRef(t) => ExprOutput::Expr(Box::alloc().init(
+                Expression::PipelinePrimaryTopicRef(PipelinePrimaryTopicReference {
+                    base: ctx.base(t.span),
+                }),
+            )),
             Expr::Invalid(_) => panic!(
                 "illegal conversion: Cannot convert {:?} to ExprOutput - babel has no equivilent",
                 &self
@@ -64,6 +64,7 @@ impl Babelify for BinaryOp {
             BinaryOp::InstanceOf => BinaryOpOutput::BinOp(BinaryExprOp::Instanceof),
             BinaryOp::Exp => BinaryOpOutput::BinOp(BinaryExprOp::Exponentiation),
             BinaryOp::NullishCoalescing => BinaryOpOutput::LogicOp(LogicalExprOp::Nullish),
+            BinaryOp::Pipeline => BinaryOpOutput::BinOp(BinaryExprOp::Pipeline),
         }
     }
 }
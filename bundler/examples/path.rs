@@ -82,6 +82,7 @@ impl Load for PathLoader {
             fm,
             module,
             helpers: Default::default(),
+            side_effects: true,
         })
     }
 }
@@ -119,6 +119,13 @@ where
                 Bundle {
                     kind,
                     id,
+                    path: self
+                        .scope
+                        .get_module(id)
+                        .expect("module should exist at this point")
+                        .fm
+                        .name
+                        .clone(),
                     module: module.into(),
                 }
             })
@@ -1,9 +1,13 @@
 use crate::dep_graph::ModuleGraph;
-use crate::{bundler::load::TransformedModule, BundleKind, Bundler, Load, ModuleId, Resolve};
+use crate::{
+    bundler::load::{Specifier, TransformedModule},
+    BundleKind, Bundler, Load, ModuleId, Resolve,
+};
 use ahash::AHashMap;
 use anyhow::{bail, Error};
 use fxhash::FxHashMap;
 use fxhash::FxHashSet;
+use swc_atoms::JsWord;
 
 #[derive(Debug, Default)]
 struct PlanBuilder {
@@ -14,6 +18,54 @@ struct PlanBuilder {
     all: Vec<ModuleId>,
 
     kinds: FxHashMap<ModuleId, BundleKind>,
+
+    /// Modules without side effects, which are not used at all.
+    unused: FxHashSet<ModuleId>,
+}
+
+/// Exports of a module which are used by other modules.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum UsedExports {
+    All,
+    Names(FxHashSet<JsWord>),
+}
+
+impl Default for UsedExports {
+    fn default() -> Self {
+        UsedExports::Names(Default::default())
+    }
+}
+
+impl UsedExports {
+    fn is_empty(&self) -> bool {
+        match self {
+            UsedExports::All => false,
+            UsedExports::Names(names) => names.is_empty(),
+        }
+    }
+
+    fn contains(&self, name: &JsWord) -> bool {
+        match self {
+            UsedExports::All => true,
+            UsedExports::Names(names) => names.contains(name),
+        }
+    }
+
+    /// Returns true if `self` is changed.
+    fn extend(&mut self, other: UsedExports) -> bool {
+        match (&mut *self, other) {
+            (UsedExports::All, _) => false,
+            (_, UsedExports::All) => {
+                *self = UsedExports::All;
+                true
+            }
+            (UsedExports::Names(names), UsedExports::Names(other)) => {
+                let len = names.len();
+                names.extend(other);
+                names.len() != len
+            }
+        }
+    }
 }
 
 #[derive(Debug, Default)]
@@ -33,7 +85,10 @@ where
         &self,
         entries: AHashMap<String, TransformedModule>,
     ) -> Result<(Plan, ModuleGraph, Vec<Vec<ModuleId>>), Error> {
-        let mut builder = PlanBuilder::default();
+        let mut builder = PlanBuilder {
+            unused: self.find_unused_modules(entries.values().map(|m| m.id)),
+            ..Default::default()
+        };
 
         for (name, module) in entries {
             match builder.kinds.insert(module.id, BundleKind::Named { name }) {
@@ -54,6 +109,101 @@ where
         ))
     }
 
+    /// Finds modules which are marked as side-effect free and none of their
+    /// exports are used, starting from `entries`.
+    ///
+    /// Re-exports are followed by name, so importing one item from a barrel
+    /// file does not pull other modules re-exported by it.
+    fn find_unused_modules(&self, entries: impl Iterator<Item = ModuleId>) -> FxHashSet<ModuleId> {
+        let mut used: FxHashMap<ModuleId, UsedExports> = Default::default();
+        let mut queue = vec![];
+
+        for id in entries {
+            used.insert(id, UsedExports::All);
+            queue.push(id);
+        }
+
+        while let Some(id) = queue.pop() {
+            let m = match self.scope.get_module(id) {
+                Some(v) => v,
+                None => continue,
+            };
+            let exports = used.get(&id).cloned().unwrap_or_default();
+            if !m.side_effects && exports.is_empty() {
+                continue;
+            }
+
+            for (src, specifiers) in &m.imports.specifiers {
+                let dep = self.scope.get_module(src.module_id);
+                let is_es6 = dep.map(|dep| dep.is_es6).unwrap_or(false);
+
+                let requested = if !m.is_es6
+                    || !is_es6
+                    || !src.is_loaded_synchronously
+                    || self.scope.is_cjs(src.module_id)
+                {
+                    UsedExports::All
+                } else if specifiers.iter().any(|s| s.is_namespace()) {
+                    UsedExports::All
+                } else {
+                    UsedExports::Names(
+                        specifiers
+                            .iter()
+                            .filter_map(|s| match s {
+                                Specifier::Specific { local, alias } => {
+                                    Some(alias.as_ref().unwrap_or(local).sym().clone())
+                                }
+                                Specifier::Namespace { .. } => None,
+                            })
+                            .collect(),
+                    )
+                };
+
+                mark_as_used(&mut used, &mut queue, src.module_id, requested);
+            }
+
+            for (src, specifiers) in &m.exports.reexports {
+                let requested = if specifiers.is_empty() {
+                    // `export * from 'foo'`
+                    exports.clone()
+                } else {
+                    let mut requested = UsedExports::default();
+                    for s in specifiers {
+                        match s {
+                            Specifier::Specific { local, alias } => {
+                                if exports.contains(local.sym()) {
+                                    let mut names = FxHashSet::default();
+                                    names.insert(alias.as_ref().unwrap_or(local).sym().clone());
+                                    requested.extend(UsedExports::Names(names));
+                                }
+                            }
+                            Specifier::Namespace { local, .. } => {
+                                if exports.contains(local.sym()) {
+                                    requested = UsedExports::All;
+                                }
+                            }
+                        }
+                    }
+                    requested
+                };
+
+                mark_as_used(&mut used, &mut queue, src.module_id, requested);
+            }
+        }
+
+        used.into_iter()
+            .filter(|(id, exports)| {
+                exports.is_empty()
+                    && self
+                        .scope
+                        .get_module(*id)
+                        .map(|m| !m.side_effects)
+                        .unwrap_or(false)
+            })
+            .map(|(id, _)| id)
+            .collect()
+    }
+
     fn add_to_graph(
         &self,
         builder: &mut PlanBuilder,
@@ -90,6 +240,11 @@ where
             .iter()
             .chain(m.exports.reexports.iter())
         {
+            if builder.unused.contains(&src.module_id) {
+                log::debug!("Dropping unused module: {}", src.module_id);
+                continue;
+            }
+
             log::debug!("Dep: {} -> {}", module_id, src.module_id);
 
             builder.graph.add_edge(module_id, src.module_id, ());
@@ -104,3 +259,137 @@ where
         debug_assert_eq!(res, Some(module_id));
     }
 }
+
+fn mark_as_used(
+    used: &mut FxHashMap<ModuleId, UsedExports>,
+    queue: &mut Vec<ModuleId>,
+    id: ModuleId,
+    exports: UsedExports,
+) {
+    let is_new = !used.contains_key(&id);
+    if used.entry(id).or_default().extend(exports) || is_new {
+        queue.push(id);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::bundler::tests::suite;
+    use ahash::AHashMap;
+    use std::collections::HashMap;
+    use swc_common::{FileName, Mark};
+    use swc_ecma_ast::*;
+    use swc_ecma_transforms::resolver_with_mark;
+    use swc_ecma_utils::scope::analyze_scopes;
+    use swc_ecma_visit::FoldWith;
+
+    #[test]
+    fn side_effect_free_reexport() {
+        suite()
+            .file("main.js", "import { a } from './lib'; console.log(a);")
+            .file(
+                "lib.js",
+                "export { a } from './a'; export { b } from './b';",
+            )
+            .file("a.js", "export const a = 1;")
+            .file("b.js", "console.log('b'); export const b = 2;")
+            .side_effect_free("lib.js")
+            .side_effect_free("a.js")
+            .side_effect_free("b.js")
+            .run(|t| {
+                let module = t
+                    .bundler
+                    .load_transformed(&FileName::Real("main.js".into()))?
+                    .unwrap();
+                let mut entries = AHashMap::default();
+                entries.insert("main.js".to_string(), module);
+
+                let (plan, _, _) = t.bundler.determine_entries(entries)?;
+                assert!(plan.all.contains(&t.module("lib.js").id));
+                assert!(plan.all.contains(&t.module("a.js").id));
+                assert!(!plan.all.contains(&t.module("b.js").id));
+
+                Ok(())
+            });
+    }
+
+    #[test]
+    fn side_effect_import_is_preserved() {
+        suite()
+            .file("main.js", "import './a';")
+            .file("a.js", "console.log('a');")
+            .run(|t| {
+                let module = t
+                    .bundler
+                    .load_transformed(&FileName::Real("main.js".into()))?
+                    .unwrap();
+                let mut entries = AHashMap::default();
+                entries.insert("main.js".to_string(), module);
+
+                let (plan, _, _) = t.bundler.determine_entries(entries)?;
+                assert!(plan.all.contains(&t.module("a.js").id));
+
+                Ok(())
+            });
+    }
+
+    /// Bundles a barrel file whose unused re-export is dropped, and checks the
+    /// emitted code.
+    #[test]
+    fn bundle_with_dropped_reexport() {
+        suite()
+            .file(
+                "main.js",
+                "import './polyfill'; import { a } from './lib'; console.log(a);",
+            )
+            .file("polyfill.js", "console.log('polyfill');")
+            .file(
+                "lib.js",
+                "export { a } from './a'; export { b } from './b';",
+            )
+            .file("a.js", "export const a = 1;")
+            .file("b.js", "console.log('b'); export const b = 2;")
+            .side_effect_free("lib.js")
+            .side_effect_free("a.js")
+            .side_effect_free("b.js")
+            .run(|t| {
+                let mut entries = HashMap::default();
+                entries.insert("main.js".to_string(), FileName::Real("main.js".into()));
+
+                let bundles = t.bundler.bundle(entries)?;
+                assert_eq!(bundles.len(), 1);
+
+                let code = t.print(&bundles[0].module);
+
+                assert!(
+                    code.contains("'polyfill'"),
+                    "the side-effectful import should be emitted:\n{}",
+                    code
+                );
+                assert!(
+                    !code.contains("'b'"),
+                    "the unused module should be dropped:\n{}",
+                    code
+                );
+
+                // The emitted code should not export or reference `b`.
+                let module = t
+                    .parse(&code)
+                    .fold_with(&mut resolver_with_mark(Mark::fresh(Mark::root())));
+                assert!(
+                    module.body.iter().all(|item| match item {
+                        ModuleItem::ModuleDecl(..) => false,
+                        ModuleItem::Stmt(..) => true,
+                    }),
+                    "the bundle should not have imports or exports:\n{}",
+                    code
+                );
+                let analysis = analyze_scopes(&module);
+                for (id, _) in analysis.unresolved() {
+                    assert_eq!(&*id.0, "console", "dangling reference:\n{}", code);
+                }
+
+                Ok(())
+            });
+    }
+}
@@ -54,10 +54,19 @@ where
         module_specifier: &str,
     ) -> Result<Lrc<FileName>, Error> {
         self.run(|| {
-            let path = self
-                .resolver
-                .resolve(base, module_specifier)
-                .with_context(|| format!("failed to resolve {} from {}", module_specifier, base))?;
+            let path = match self
+                .hook
+                .resolve_id(base, module_specifier)
+                .context("Hook.resolve_id() failed")?
+            {
+                Some(path) => path,
+                None => self
+                    .resolver
+                    .resolve(base, module_specifier)
+                    .with_context(|| {
+                        format!("failed to resolve {} from {}", module_specifier, base)
+                    })?,
+            };
 
             let path = Lrc::new(path);
 
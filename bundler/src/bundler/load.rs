@@ -5,7 +5,7 @@ use crate::{
     load::ModuleData,
     util,
     util::IntoParallelIterator,
-    Load, Resolve,
+    Load, ModuleInfo, Resolve,
 };
 use anyhow::{Context, Error};
 use is_macro::Is;
@@ -36,6 +36,9 @@ pub(crate) struct TransformedModule {
 
     pub swc_helpers: Lrc<swc_ecma_transforms::helpers::Helpers>,
 
+    /// See [ModuleData::side_effects].
+    pub side_effects: bool,
+
     local_ctxt: SyntaxContext,
     export_ctxt: SyntaxContext,
 }
@@ -80,6 +83,15 @@ where
                 .context("failed to analyze module")?;
             files.dedup_by_key(|v| v.1.clone());
 
+            self.hook
+                .module_parsed(&ModuleInfo {
+                    id: v.id,
+                    file_name,
+                    module: &v.module,
+                    dependencies: files.iter().map(|(_, path)| &**path).collect(),
+                })
+                .with_context(|| format!("Hook.module_parsed({}) failed", file_name))?;
+
             log::debug!(
                 "({:?}, {:?}, {:?}) Storing module: {}",
                 v.id,
@@ -111,10 +123,14 @@ where
         self.run(|| {
             let (module_id, _, _) = self.scope.module_id_gen.gen(file_name);
 
-            let data = self
+            let mut data = self
                 .loader
                 .load(&file_name)
                 .with_context(|| format!("Bundler.loader.load({}) failed", file_name))?;
+            data.module = self
+                .hook
+                .transform(&file_name, data.module)
+                .with_context(|| format!("Hook.transform({}) failed", file_name))?;
             self.scope.mark_as_loaded(module_id);
             Ok((module_id, data))
         })
@@ -201,6 +217,7 @@ where
                     is_es6,
                     helpers: Default::default(),
                     swc_helpers: Lrc::new(data.helpers),
+                    side_effects: data.side_effects,
                     local_ctxt: SyntaxContext::empty().apply_mark(local_mark),
                     export_ctxt: SyntaxContext::empty().apply_mark(export_mark),
                 },
@@ -473,3 +490,34 @@ impl Visit for Es6ModuleDetector {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::bundler::tests::suite;
+    use swc_common::FileName;
+
+    #[test]
+    fn resolve_id_hook() {
+        suite()
+            .file(
+                "main.js",
+                "import { foo } from 'virtual:foo'; console.log(foo);",
+            )
+            .file("<virtual:foo>", "export const foo = 1;")
+            .run(|t| {
+                let module = t
+                    .bundler
+                    .load_transformed(&FileName::Real("main.js".into()))?
+                    .unwrap();
+                assert_eq!(module.imports.specifiers.len(), 1);
+
+                assert!(t
+                    .bundler
+                    .scope
+                    .get_module_by_path(&FileName::Custom("virtual:foo".into()))
+                    .is_some());
+
+                Ok(())
+            });
+    }
+}
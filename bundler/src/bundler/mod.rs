@@ -64,6 +64,9 @@ pub enum BundleKind {
 pub struct Bundle {
     pub kind: BundleKind,
     pub id: ModuleId,
+    /// Path of the entry module of the bundle, which is used to resolve
+    /// imports of other bundles.
+    pub path: FileName,
     /// Merged module.
     pub module: Module,
 }
@@ -171,7 +174,20 @@ where
 
         let bundles = self.chunk(local)?;
 
-        let bundles = self.finalize(bundles)?;
+        let mut bundles = self.finalize(bundles)?;
+
+        self.run(|| -> Result<_, Error> {
+            for bundle in &mut bundles {
+                self.hook
+                    .render_chunk(bundle)
+                    .context("Hook.render_chunk() failed")?;
+            }
+
+            self.hook
+                .generate_bundle(&mut bundles)
+                .context("Hook.generate_bundle() failed")
+        })?;
+
         Ok(bundles)
     }
 
@@ -19,10 +19,13 @@ where
                 node = node.fold_with(&mut constant_propagation())
             }
 
-            node = node.fold_with(&mut Repeat::new(dce::dce(dce::Config {
-                used: None,
-                used_mark: self.used_mark,
-            })));
+            node = node.fold_with(&mut Repeat::new(dce::dce(
+                dce::Config {
+                    used: None,
+                    used_mark: self.used_mark,
+                },
+                self.loader.comments(),
+            )));
 
             node
         })
@@ -2,10 +2,12 @@
 use super::{load::TransformedModule, Bundler, Config};
 use crate::{load::ModuleData, util::HygieneRemover, Load, ModuleRecord, Resolve};
 use anyhow::Error;
+use fxhash::FxHashSet;
 use indexmap::IndexMap;
 use std::path::PathBuf;
 use swc_common::{sync::Lrc, FileName, SourceMap, Span, GLOBALS};
 use swc_ecma_ast::*;
+use swc_ecma_codegen::{text_writer::JsWriter, Emitter};
 use swc_ecma_parser::{lexer::Lexer, JscTarget, Parser, StringInput};
 use swc_ecma_utils::drop_span;
 use swc_ecma_visit::VisitMutWith;
@@ -18,6 +20,7 @@ pub(crate) struct Tester<'a> {
 pub struct Loader {
     cm: Lrc<SourceMap>,
     files: IndexMap<String, String>,
+    side_effect_free: FxHashSet<String>,
 }
 
 impl Load for Loader {
@@ -42,6 +45,7 @@ impl Load for Loader {
             fm,
             module,
             helpers: Default::default(),
+            side_effects: !self.side_effect_free.contains(&f.to_string()),
         })
     }
 }
@@ -87,6 +91,21 @@ impl<'a> Tester<'a> {
         parser.parse_module().unwrap()
     }
 
+    #[allow(dead_code)]
+    pub fn print(&self, m: &Module) -> String {
+        let mut buf = vec![];
+        {
+            let mut emitter = Emitter {
+                cfg: Default::default(),
+                cm: self.cm.clone(),
+                comments: None,
+                wr: Box::new(JsWriter::new(self.cm.clone(), "\n", &mut buf, None)),
+            };
+            emitter.emit_module(m).unwrap();
+        }
+        String::from_utf8(buf).unwrap()
+    }
+
     #[allow(dead_code)]
     pub fn assert_eq(&self, m: &Module, expected: &str) {
         let expected = self.parse(expected);
@@ -107,6 +126,8 @@ pub(crate) fn suite() -> TestBuilder {
 #[derive(Default)]
 pub(crate) struct TestBuilder {
     files: IndexMap<String, String>,
+    side_effect_free: FxHashSet<String>,
+    hook: Option<Box<dyn crate::Hook>>,
 }
 
 impl TestBuilder {
@@ -115,10 +136,26 @@ impl TestBuilder {
         self
     }
 
-    pub fn run<F>(self, op: F)
+    /// Marks a file as `"sideEffects": false`.
+    #[allow(dead_code)]
+    pub fn side_effect_free(mut self, name: &str) -> Self {
+        self.side_effect_free.insert(name.to_string());
+        self
+    }
+
+    /// Replaces the default hook.
+    #[allow(dead_code)]
+    pub fn hook(mut self, hook: Box<dyn crate::Hook>) -> Self {
+        self.hook = Some(hook);
+        self
+    }
+
+    pub fn run<F>(mut self, op: F)
     where
         F: FnOnce(&mut Tester) -> Result<(), Error>,
     {
+        let hook = self.hook.take().unwrap_or_else(|| Box::new(Hook));
+
         testing::run_test2(true, |cm, _| {
             GLOBALS.with(|globals| {
                 let bundler = Bundler::new(
@@ -127,6 +164,7 @@ impl TestBuilder {
                     Loader {
                         cm: cm.clone(),
                         files: self.files.clone(),
+                        side_effect_free: self.side_effect_free.clone(),
                     },
                     Default::default(),
                     Config {
@@ -135,7 +173,7 @@ impl TestBuilder {
                         external_modules: vec![],
                         module: Default::default(),
                     },
-                    Box::new(Hook),
+                    hook,
                 );
 
                 for (name, _) in self.files {
@@ -164,4 +202,12 @@ impl crate::Hook for Hook {
     fn get_import_meta_props(&self, _: Span, _: &ModuleRecord) -> Result<Vec<KeyValueProp>, Error> {
         unreachable!()
     }
+
+    fn resolve_id(&self, _: &FileName, s: &str) -> Result<Option<FileName>, Error> {
+        if s.starts_with("virtual:") {
+            return Ok(Some(FileName::Custom(s.into())));
+        }
+
+        Ok(None)
+    }
 }
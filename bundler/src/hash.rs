@@ -5,7 +5,8 @@ use swc_common::{sync::Lrc, SourceMap, Span};
 use swc_ecma_ast::Module;
 use swc_ecma_codegen::{text_writer::WriteJs, Emitter};
 
-pub(crate) fn calc_hash(cm: Lrc<SourceMap>, m: &Module) -> Result<String, Error> {
+/// Calculates a crc64 hash of the code generated from `m`, in radix 36.
+pub fn calc_hash(cm: Lrc<SourceMap>, m: &Module) -> Result<String, Error> {
     let digest = crc64::Digest::new(crc64::ECMA);
     let mut buf = Hasher { digest };
 
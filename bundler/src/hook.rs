@@ -1,6 +1,7 @@
+use crate::{Bundle, ModuleId};
 use anyhow::Error;
 use swc_common::{FileName, Span};
-use swc_ecma_ast::KeyValueProp;
+use swc_ecma_ast::{KeyValueProp, Module};
 
 #[non_exhaustive]
 pub struct ModuleRecord {
@@ -8,10 +9,226 @@ pub struct ModuleRecord {
     pub is_entry: bool,
 }
 
+/// Passed to [Hook::module_parsed].
+#[non_exhaustive]
+pub struct ModuleInfo<'a> {
+    pub id: ModuleId,
+    pub file_name: &'a FileName,
+    pub module: &'a Module,
+    /// Resolved paths of imported or reexported modules.
+    pub dependencies: Vec<&'a FileName>,
+}
+
+/// Lifecycle hooks of the bundler, modeled after the plugin hooks of rollup.
+///
+/// All methods except [Hook::get_import_meta_props] have a default
+/// implementation which does nothing.
 pub trait Hook: swc_common::sync::Sync + swc_common::sync::Send {
     fn get_import_meta_props(
         &self,
         span: Span,
         module_record: &ModuleRecord,
     ) -> Result<Vec<KeyValueProp>, Error>;
+
+    /// Called before [crate::Resolve::resolve]. If this returns [Some], the
+    /// resolver is not invoked.
+    ///
+    /// This can be used to implement virtual modules, in conjunction with
+    /// [crate::Load].
+    fn resolve_id(
+        &self,
+        _importer: &FileName,
+        _specifier: &str,
+    ) -> Result<Option<FileName>, Error> {
+        Ok(None)
+    }
+
+    /// Called for each module right after it's loaded by [crate::Load].
+    fn transform(&self, _file_name: &FileName, module: Module) -> Result<Module, Error> {
+        Ok(module)
+    }
+
+    /// Called after imports and exports of a module are resolved.
+    fn module_parsed(&self, _info: &ModuleInfo) -> Result<(), Error> {
+        Ok(())
+    }
+
+    /// Called for each bundle after it's finalized.
+    fn render_chunk(&self, _bundle: &mut Bundle) -> Result<(), Error> {
+        Ok(())
+    }
+
+    /// Called with all bundles at the end of [crate::Bundler::bundle].
+    ///
+    /// Bundles can be added or removed at this phase.
+    fn generate_bundle(&self, _bundles: &mut Vec<Bundle>) -> Result<(), Error> {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{bundler::tests::suite, BundleKind};
+    use std::{
+        collections::HashMap,
+        sync::{Arc, Mutex},
+    };
+    use swc_ecma_ast::*;
+    use swc_ecma_utils::{quote_str, ExprFactory};
+
+    /// Records calls of hooks.
+    #[derive(Default, Clone)]
+    struct Recorder {
+        /// File names passed to [Hook::module_parsed], with the dependencies.
+        parsed: Arc<Mutex<Vec<(String, Vec<String>)>>>,
+        /// Entry names of bundles passed to [Hook::generate_bundle], with
+        /// `true` if [Hook::render_chunk] is applied to it.
+        generated: Arc<Mutex<Vec<(String, bool)>>>,
+    }
+
+    impl Hook for Recorder {
+        fn get_import_meta_props(
+            &self,
+            _: Span,
+            _: &ModuleRecord,
+        ) -> Result<Vec<KeyValueProp>, Error> {
+            unreachable!()
+        }
+
+        /// Changes `import './a'` in `main.js` to `import './b'`.
+        fn transform(&self, file_name: &FileName, mut module: Module) -> Result<Module, Error> {
+            if file_name.to_string() == "main.js" {
+                for item in &mut module.body {
+                    if let ModuleItem::ModuleDecl(ModuleDecl::Import(import)) = item {
+                        if &*import.src.value == "./a" {
+                            import.src = quote_str!("./b");
+                        }
+                    }
+                }
+            }
+
+            Ok(module)
+        }
+
+        fn module_parsed(&self, info: &ModuleInfo) -> Result<(), Error> {
+            self.parsed.lock().unwrap().push((
+                info.file_name.to_string(),
+                info.dependencies.iter().map(|f| f.to_string()).collect(),
+            ));
+            Ok(())
+        }
+
+        fn render_chunk(&self, bundle: &mut Bundle) -> Result<(), Error> {
+            bundle.module.body.push(rendered());
+            Ok(())
+        }
+
+        /// Removes the bundle of `other.js`.
+        fn generate_bundle(&self, bundles: &mut Vec<Bundle>) -> Result<(), Error> {
+            let mut generated = self.generated.lock().unwrap();
+            for bundle in bundles.iter() {
+                generated.push((
+                    entry_name(bundle),
+                    bundle.module.body.last() == Some(&rendered()),
+                ));
+            }
+            generated.sort();
+
+            bundles.retain(|bundle| entry_name(bundle) != "other.js");
+            Ok(())
+        }
+    }
+
+    /// `"rendered";`
+    fn rendered() -> ModuleItem {
+        ModuleItem::Stmt(Lit::Str(quote_str!("rendered")).into_stmt())
+    }
+
+    fn entry_name(bundle: &Bundle) -> String {
+        match &bundle.kind {
+            BundleKind::Named { name } => name.clone(),
+            kind => unreachable!("unexpected bundle: {:?}", kind),
+        }
+    }
+
+    fn run<F>(recorder: &Recorder, op: F)
+    where
+        F: FnOnce(Vec<Bundle>, &crate::bundler::tests::Tester) -> Result<(), Error>,
+    {
+        suite()
+            .file("main.js", "import './a'; console.log('main');")
+            .file("a.js", "console.log('a');")
+            .file("b.js", "console.log('b');")
+            .file("other.js", "console.log('other');")
+            .hook(Box::new(recorder.clone()))
+            .run(|t| {
+                let mut entries = HashMap::default();
+                for &name in &["main.js", "other.js"] {
+                    entries.insert(name.to_string(), FileName::Real(name.into()));
+                }
+                let bundles = t.bundler.bundle(entries)?;
+
+                op(bundles, t)
+            });
+    }
+
+    #[test]
+    fn transformed_module_is_parsed() {
+        run(&Recorder::default(), |bundles, t| {
+            let code = t.print(&bundles[0].module);
+            assert!(code.contains("'b'"), "{}", code);
+            assert!(!code.contains("'a'"), "{}", code);
+
+            Ok(())
+        });
+    }
+
+    #[test]
+    fn module_parsed_sees_every_module() {
+        let recorder = Recorder::default();
+        run(&recorder, |_, _| Ok(()));
+
+        let mut parsed = recorder.parsed.lock().unwrap().clone();
+        parsed.sort();
+        assert_eq!(
+            parsed,
+            vec![
+                ("a.js".to_string(), vec![]),
+                ("b.js".to_string(), vec![]),
+                ("main.js".to_string(), vec!["b.js".to_string()]),
+                ("other.js".to_string(), vec![]),
+            ]
+        );
+    }
+
+    #[test]
+    fn render_chunk_rewrites_bundles() {
+        run(&Recorder::default(), |bundles, _| {
+            for bundle in &bundles {
+                assert_eq!(bundle.module.body.last(), Some(&rendered()));
+            }
+
+            Ok(())
+        });
+    }
+
+    #[test]
+    fn generate_bundle_receives_final_bundles() {
+        let recorder = Recorder::default();
+        run(&recorder, |bundles, _| {
+            let names = bundles.iter().map(entry_name).collect::<Vec<_>>();
+            assert_eq!(names, vec!["main.js"]);
+
+            Ok(())
+        });
+
+        assert_eq!(
+            *recorder.generated.lock().unwrap(),
+            vec![
+                ("main.js".to_string(), true),
+                ("other.js".to_string(), true)
+            ]
+        );
+    }
 }
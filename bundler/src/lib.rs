@@ -1,6 +1,7 @@
 pub use self::{
     bundler::{Bundle, BundleKind, Bundler, Config, ModuleType},
-    hook::{Hook, ModuleRecord},
+    hash::calc_hash,
+    hook::{Hook, ModuleInfo, ModuleRecord},
     id::ModuleId,
     load::{Load, ModuleData},
     resolve::Resolve,
@@ -1,5 +1,5 @@
 use anyhow::Error;
-use swc_common::{sync::Lrc, FileName, SourceFile};
+use swc_common::{comments::Comments, sync::Lrc, FileName, SourceFile};
 use swc_ecma_ast::Module;
 use swc_ecma_transforms::helpers::Helpers;
 
@@ -24,11 +24,22 @@ pub struct ModuleData {
     ///             module
     ///         });
     ///
-    ///         Ok(ModuleData { fm, module, helpers })
+    ///         Ok(ModuleData {
+    ///             fm,
+    ///             module,
+    ///             helpers,
+    ///             side_effects: true,
+    ///         })
     ///     }
     /// }
     /// ```
     pub helpers: Helpers,
+
+    /// If `false`, the module is dropped from the bundle if none of its
+    /// exports are used, even if it's imported.
+    ///
+    /// This corresponds to `sideEffects` of `package.json`.
+    pub side_effects: bool,
 }
 
 /// Responsible for providing files to the bundler.
@@ -41,16 +52,32 @@ pub struct ModuleData {
 /// This trait is designed to allow passing pre-parsed module.
 pub trait Load: swc_common::sync::Send + swc_common::sync::Sync {
     fn load(&self, file: &FileName) -> Result<ModuleData, Error>;
+
+    /// Comments of loaded modules.
+    ///
+    /// If this returns [Some], tree shaking treats calls annotated with
+    /// `/*#__PURE__*/` as side effect free.
+    fn comments(&self) -> Option<&dyn Comments> {
+        None
+    }
 }
 
 impl<T: ?Sized + Load> Load for Box<T> {
     fn load(&self, file: &FileName) -> Result<ModuleData, Error> {
         (**self).load(file)
     }
+
+    fn comments(&self) -> Option<&dyn Comments> {
+        (**self).comments()
+    }
 }
 
 impl<'a, T: ?Sized + Load> Load for &'a T {
     fn load(&self, file: &FileName) -> Result<ModuleData, Error> {
         (**self).load(file)
     }
+
+    fn comments(&self) -> Option<&dyn Comments> {
+        (**self).comments()
+    }
 }
@@ -273,6 +273,8 @@ pub struct Handler {
     pub flags: HandlerFlags,
 
     err_count: AtomicUsize,
+    /// Number of diagnostics of any level, including duplicated ones.
+    diagnostic_count: AtomicUsize,
     emitter: Lock<Box<dyn Emitter>>,
     continue_after_error: LockCell<bool>,
     delayed_span_bugs: Lock<Vec<Diagnostic>>,
@@ -378,6 +380,7 @@ impl Handler {
         Handler {
             flags,
             err_count: AtomicUsize::new(0),
+            diagnostic_count: AtomicUsize::new(0),
             emitter: Lock::new(e),
             continue_after_error: LockCell::new(true),
             delayed_span_bugs: Lock::new(Vec::new()),
@@ -623,6 +626,12 @@ impl Handler {
         self.err_count() > 0
     }
 
+    /// Returns the number of emitted diagnostics of any level, including
+    /// warnings and diagnostics skipped because they are duplicates.
+    pub fn diagnostic_count(&self) -> usize {
+        self.diagnostic_count.load(SeqCst)
+    }
+
     pub fn print_error_count(&self) {
         let s = match self.err_count() {
             0 => return,
@@ -716,6 +725,7 @@ impl Handler {
 
     fn emit_db(&self, db: &DiagnosticBuilder<'_>) {
         let diagnostic = &**db;
+        self.diagnostic_count.fetch_add(1, SeqCst);
 
         TRACK_DIAGNOSTICS.with(|track_diagnostics| {
             track_diagnostics.borrow()(diagnostic);
@@ -0,0 +1,296 @@
+use super::{tag, Error, MAGIC, VERSION};
+use serde::{
+    de::{
+        DeserializeSeed, EnumAccess, IntoDeserializer, MapAccess, SeqAccess, VariantAccess, Visitor,
+    },
+    forward_to_deserialize_any, Deserialize,
+};
+use std::{convert::TryFrom, str};
+
+/// Decodes a value encoded by [to_vec](super::to_vec).
+pub fn from_slice<'de, T>(data: &'de [u8]) -> Result<T, Error>
+where
+    T: Deserialize<'de>,
+{
+    if data.len() < MAGIC.len() + 1 || &data[..MAGIC.len()] != MAGIC {
+        return Err(Error::new("invalid header"));
+    }
+    let version = data[MAGIC.len()];
+    if version != VERSION {
+        return Err(Error::new(format_args!(
+            "unsupported version {} (expected {})",
+            version, VERSION
+        )));
+    }
+
+    let mut de = Deserializer {
+        data,
+        pos: MAGIC.len() + 1,
+        strings: vec![],
+    };
+    let value = T::deserialize(&mut de)?;
+    if de.pos != data.len() {
+        return Err(de.error("trailing bytes"));
+    }
+
+    Ok(value)
+}
+
+struct Deserializer<'de> {
+    data: &'de [u8],
+    pos: usize,
+    /// Strings read so far.
+    strings: Vec<&'de str>,
+}
+
+impl<'de> Deserializer<'de> {
+    fn error(&self, msg: &str) -> Error {
+        Error::new(format_args!("{} at {}", msg, self.pos))
+    }
+
+    fn peek(&self) -> Result<u8, Error> {
+        self.data
+            .get(self.pos)
+            .copied()
+            .ok_or_else(|| self.error("unexpected eof"))
+    }
+
+    fn read_u8(&mut self) -> Result<u8, Error> {
+        let b = self.peek()?;
+        self.pos += 1;
+        Ok(b)
+    }
+
+    fn read_bytes(&mut self, len: usize) -> Result<&'de [u8], Error> {
+        let bytes = self
+            .pos
+            .checked_add(len)
+            .and_then(|end| self.data.get(self.pos..end))
+            .ok_or_else(|| self.error("unexpected eof"))?;
+        self.pos += len;
+        Ok(bytes)
+    }
+
+    fn read_varint(&mut self) -> Result<u64, Error> {
+        let mut v = 0u64;
+        let mut shift = 0;
+        loop {
+            let b = self.read_u8()?;
+            if shift >= 64 {
+                return Err(self.error("varint is too long"));
+            }
+            v |= u64::from(b & 0x7f) << shift;
+            if b & 0x80 == 0 {
+                return Ok(v);
+            }
+            shift += 7;
+        }
+    }
+
+    fn read_len(&mut self) -> Result<usize, Error> {
+        let len = self.read_varint()?;
+        usize::try_from(len).map_err(|_| self.error("length is too large"))
+    }
+
+    /// Reads a string after its tag.
+    fn read_str(&mut self, tag: u8) -> Result<&'de str, Error> {
+        if tag == tag::STR_REF {
+            let idx = self.read_len()?;
+            return self
+                .strings
+                .get(idx)
+                .copied()
+                .ok_or_else(|| self.error("invalid string index"));
+        }
+
+        let len = self.read_len()?;
+        let bytes = self.read_bytes(len)?;
+        let s = str::from_utf8(bytes).map_err(|_| self.error("invalid utf8"))?;
+        self.strings.push(s);
+        Ok(s)
+    }
+
+    fn expect_end(&mut self) -> Result<(), Error> {
+        if self.read_u8()? != tag::END {
+            return Err(self.error("expected end of array or object"));
+        }
+        Ok(())
+    }
+}
+
+impl<'de, 'a> serde::Deserializer<'de> for &'a mut Deserializer<'de> {
+    type Error = Error;
+
+    fn deserialize_any<V>(self, visitor: V) -> Result<V::Value, Error>
+    where
+        V: Visitor<'de>,
+    {
+        match self.read_u8()? {
+            tag::NULL => visitor.visit_unit(),
+            tag::FALSE => visitor.visit_bool(false),
+            tag::TRUE => visitor.visit_bool(true),
+            tag::UINT => visitor.visit_u64(self.read_varint()?),
+            tag::NEG_INT => {
+                let n = self.read_varint()?;
+                let n = i64::try_from(n).map_err(|_| self.error("integer is too small"))?;
+                visitor.visit_i64(!n)
+            }
+            tag::F64 => {
+                let mut bytes = [0; 8];
+                bytes.copy_from_slice(self.read_bytes(8)?);
+                visitor.visit_f64(f64::from_le_bytes(bytes))
+            }
+            t @ tag::STR | t @ tag::STR_REF => visitor.visit_borrowed_str(self.read_str(t)?),
+            tag::BYTES => {
+                let len = self.read_len()?;
+                visitor.visit_borrowed_bytes(self.read_bytes(len)?)
+            }
+            tag::ARRAY => {
+                let value = visitor.visit_seq(Compound { de: &mut *self })?;
+                self.expect_end()?;
+                Ok(value)
+            }
+            tag::MAP => {
+                let value = visitor.visit_map(Compound { de: &mut *self })?;
+                self.expect_end()?;
+                Ok(value)
+            }
+            _ => {
+                self.pos -= 1;
+                Err(self.error("invalid tag"))
+            }
+        }
+    }
+
+    fn deserialize_option<V>(self, visitor: V) -> Result<V::Value, Error>
+    where
+        V: Visitor<'de>,
+    {
+        if self.peek()? == tag::NULL {
+            self.pos += 1;
+            visitor.visit_none()
+        } else {
+            visitor.visit_some(self)
+        }
+    }
+
+    fn deserialize_newtype_struct<V>(self, _: &'static str, visitor: V) -> Result<V::Value, Error>
+    where
+        V: Visitor<'de>,
+    {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_enum<V>(
+        self,
+        _: &'static str,
+        _: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Error>
+    where
+        V: Visitor<'de>,
+    {
+        match self.read_u8()? {
+            t @ tag::STR | t @ tag::STR_REF => {
+                visitor.visit_enum(self.read_str(t)?.into_deserializer())
+            }
+            tag::MAP => {
+                let value = visitor.visit_enum(Compound { de: &mut *self })?;
+                self.expect_end()?;
+                Ok(value)
+            }
+            _ => {
+                self.pos -= 1;
+                Err(self.error("expected string or object for enum"))
+            }
+        }
+    }
+
+    forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf unit unit_struct seq tuple
+        tuple_struct map struct identifier ignored_any
+    }
+}
+
+/// Accesses elements of an array or an object, or a variant.
+struct Compound<'a, 'de> {
+    de: &'a mut Deserializer<'de>,
+}
+
+impl<'de> SeqAccess<'de> for Compound<'_, 'de> {
+    type Error = Error;
+
+    fn next_element_seed<T>(&mut self, seed: T) -> Result<Option<T::Value>, Error>
+    where
+        T: DeserializeSeed<'de>,
+    {
+        if self.de.peek()? == tag::END {
+            return Ok(None);
+        }
+        seed.deserialize(&mut *self.de).map(Some)
+    }
+}
+
+impl<'de> MapAccess<'de> for Compound<'_, 'de> {
+    type Error = Error;
+
+    fn next_key_seed<K>(&mut self, seed: K) -> Result<Option<K::Value>, Error>
+    where
+        K: DeserializeSeed<'de>,
+    {
+        if self.de.peek()? == tag::END {
+            return Ok(None);
+        }
+        seed.deserialize(&mut *self.de).map(Some)
+    }
+
+    fn next_value_seed<V>(&mut self, seed: V) -> Result<V::Value, Error>
+    where
+        V: DeserializeSeed<'de>,
+    {
+        seed.deserialize(&mut *self.de)
+    }
+}
+
+impl<'de, 'a> EnumAccess<'de> for Compound<'a, 'de> {
+    type Error = Error;
+    type Variant = Self;
+
+    fn variant_seed<V>(self, seed: V) -> Result<(V::Value, Self), Error>
+    where
+        V: DeserializeSeed<'de>,
+    {
+        let variant = seed.deserialize(&mut *self.de)?;
+        Ok((variant, self))
+    }
+}
+
+impl<'de, 'a> VariantAccess<'de> for Compound<'a, 'de> {
+    type Error = Error;
+
+    fn unit_variant(self) -> Result<(), Error> {
+        serde::de::IgnoredAny::deserialize(&mut *self.de).map(drop)
+    }
+
+    fn newtype_variant_seed<T>(self, seed: T) -> Result<T::Value, Error>
+    where
+        T: DeserializeSeed<'de>,
+    {
+        seed.deserialize(&mut *self.de)
+    }
+
+    fn tuple_variant<V>(self, _: usize, visitor: V) -> Result<V::Value, Error>
+    where
+        V: Visitor<'de>,
+    {
+        serde::Deserializer::deserialize_any(&mut *self.de, visitor)
+    }
+
+    fn struct_variant<V>(self, _: &'static [&'static str], visitor: V) -> Result<V::Value, Error>
+    where
+        V: Visitor<'de>,
+    {
+        serde::Deserializer::deserialize_any(&mut *self.de, visitor)
+    }
+}
@@ -0,0 +1,92 @@
+//! Compact binary encoding of the ast.
+//!
+//! The encoding is produced from the serde implementations generated by
+//! `#[ast_node]`, so a decoded value has the same shape as the json of the
+//! ast. Strings (including field names and atoms) are interned, which makes
+//! the output much smaller than json.
+//!
+//! # Format
+//!
+//! The output starts with `SWCA` and [VERSION], followed by a value.
+//!
+//! | Tag    | Value                                                       |
+//! | ------ | ----------------------------------------------------------- |
+//! | `0x00` | `null`                                                      |
+//! | `0x01` | `false`                                                     |
+//! | `0x02` | `true`                                                      |
+//! | `0x03` | unsigned integer, as a LEB128 varint                        |
+//! | `0x04` | negative integer `-1 - n`, where `n` is a LEB128 varint     |
+//! | `0x05` | 64-bit float, little endian                                 |
+//! | `0x06` | string, as a varint length and utf8 bytes                   |
+//! | `0x07` | varint index of a string which appeared before              |
+//! | `0x08` | bytes, as a varint length and bytes                         |
+//! | `0x09` | array, values followed by `0x0b`                            |
+//! | `0x0a` | object, pairs of a key and a value followed by `0x0b`       |
+//!
+//! Strings are indexed in the order of their first appearance, which is
+//! encoded using `0x06`.
+//!
+//! Enum variants which are not untagged are encoded like json, i.e. as a
+//! string or an object with a single key.
+
+pub use self::{de::from_slice, ser::to_vec};
+use std::fmt::{self, Display, Formatter};
+
+mod de;
+mod ser;
+
+/// Version of the encoding, which is bumped on incompatible changes of the
+/// format.
+///
+/// Note that changes of the ast are not versioned.
+pub const VERSION: u8 = 1;
+
+const MAGIC: &[u8; 4] = b"SWCA";
+
+mod tag {
+    pub(super) const NULL: u8 = 0x00;
+    pub(super) const FALSE: u8 = 0x01;
+    pub(super) const TRUE: u8 = 0x02;
+    pub(super) const UINT: u8 = 0x03;
+    pub(super) const NEG_INT: u8 = 0x04;
+    pub(super) const F64: u8 = 0x05;
+    pub(super) const STR: u8 = 0x06;
+    pub(super) const STR_REF: u8 = 0x07;
+    pub(super) const BYTES: u8 = 0x08;
+    pub(super) const ARRAY: u8 = 0x09;
+    pub(super) const MAP: u8 = 0x0a;
+    pub(super) const END: u8 = 0x0b;
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Error {
+    msg: String,
+}
+
+impl Error {
+    fn new(msg: impl Display) -> Self {
+        Error {
+            msg: msg.to_string(),
+        }
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        Display::fmt(&self.msg, f)
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl serde::ser::Error for Error {
+    fn custom<T: Display>(msg: T) -> Self {
+        Error::new(msg)
+    }
+}
+
+impl serde::de::Error for Error {
+    fn custom<T: Display>(msg: T) -> Self {
+        Error::new(msg)
+    }
+}
@@ -112,6 +112,11 @@ pub struct ClassProp {
 
     #[serde(default)]
     pub definite: bool,
+
+    /// `accessor foo = 1`, which declares a getter / setter pair backed by a
+    /// private storage.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub is_accessor: bool,
 }
 
 #[ast_node("PrivateProperty")]
@@ -157,6 +162,11 @@ pub struct PrivateProp {
 
     #[serde(default)]
     pub definite: bool,
+
+    /// `accessor foo = 1`, which declares a getter / setter pair backed by a
+    /// private storage.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub is_accessor: bool,
 }
 
 macro_rules! method {
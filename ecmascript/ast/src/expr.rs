@@ -17,6 +17,7 @@ use crate::{
 };
 use is_macro::Is;
 use serde::{self, Deserialize, Serialize};
+use string_enum::StringEnum;
 use swc_common::EqIgnoreSpan;
 use swc_common::{ast_node, Span, Spanned, DUMMY_SP};
 
@@ -149,6 +150,9 @@ pub enum Expr {
     #[tag("OptionalChainingExpression")]
     OptChain(OptChainExpr),
 
+    #[tag("TopicReference")]
+    TopicRef(TopicRef),
+
     #[tag("Invalid")]
     Invalid(Invalid),
 }
@@ -608,6 +612,25 @@ pub struct OptChainExpr {
     pub expr: Box<Expr>,
 }
 
+/// Topic reference of the hack-style pipeline operator, like `%` in
+/// `x |> f(%)`.
+#[ast_node("TopicReference")]
+#[derive(Eq, Hash, Copy, EqIgnoreSpan)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct TopicRef {
+    pub span: Span,
+    pub token: TopicToken,
+}
+
+#[derive(StringEnum, Clone, Copy, Eq, PartialEq, PartialOrd, Ord, Hash, EqIgnoreSpan)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub enum TopicToken {
+    /// `%`
+    Percent,
+    /// `^`
+    Caret,
+}
+
 test_de!(
     jsx_element,
     JSXElement,
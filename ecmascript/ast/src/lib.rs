@@ -16,7 +16,8 @@ pub use self::{
         ArrayLit, ArrowExpr, AssignExpr, AwaitExpr, BinExpr, BlockStmtOrExpr, CallExpr, ClassExpr,
         CondExpr, Expr, ExprOrSpread, ExprOrSuper, FnExpr, MemberExpr, MetaPropExpr, NewExpr,
         ObjectLit, OptChainExpr, ParenExpr, PatOrExpr, PropOrSpread, SeqExpr, SpreadElement, Super,
-        TaggedTpl, ThisExpr, Tpl, TplElement, UnaryExpr, UpdateExpr, YieldExpr,
+        TaggedTpl, ThisExpr, TopicRef, TopicToken, Tpl, TplElement, UnaryExpr, UpdateExpr,
+        YieldExpr,
     },
     function::{Function, Param, ParamOrTsParamProp},
     ident::{BindingIdent, Ident, IdentExt, PrivateName},
@@ -71,6 +72,7 @@ use swc_common::{ast_node, Span};
 
 #[macro_use]
 mod macros;
+pub mod binary;
 mod class;
 mod decl;
 mod expr;
@@ -61,6 +61,9 @@ pub enum BinaryOp {
 
     /// `??`
     NullishCoalescing,
+
+    /// `|>`
+    Pipeline,
 }
 
 impl BinaryOp {
@@ -98,6 +101,9 @@ impl BinaryOp {
             BinaryOp::Exp => 11,
 
             BinaryOp::NullishCoalescing => 1,
+
+            // Lower than any other binary operator.
+            BinaryOp::Pipeline => 0,
         }
     }
 }
@@ -522,6 +522,7 @@ impl<'a> Emitter<'a> {
             Expr::TsTypeAssertion(ref n) => emit!(n),
             Expr::TsConstAssertion(ref n) => emit!(n),
             Expr::OptChain(ref n) => emit!(n),
+            Expr::TopicRef(ref n) => emit!(n),
             Expr::Invalid(ref n) => emit!(n),
         }
     }
@@ -978,6 +979,11 @@ impl<'a> Emitter<'a> {
             space!();
         }
 
+        if n.is_accessor {
+            keyword!("accessor");
+            space!();
+        }
+
         emit!(n.key);
         if let Some(type_ann) = &n.type_ann {
             punct!(":");
@@ -1020,6 +1026,11 @@ impl<'a> Emitter<'a> {
             space!();
         }
 
+        if n.is_accessor {
+            keyword!("accessor");
+            space!();
+        }
+
         if n.computed {
             punct!("[");
             emit!(n.key);
@@ -1183,6 +1194,13 @@ impl<'a> Emitter<'a> {
         keyword!("this");
     }
 
+    #[emitter]
+    fn emit_topic_ref(&mut self, node: &TopicRef) -> Result {
+        self.emit_leading_comments_of_span(node.span(), false)?;
+
+        operator!(node.token.as_str());
+    }
+
     #[emitter]
     fn emit_tpl_lit(&mut self, node: &Tpl) -> Result {
         debug_assert!(node.quasis.len() == node.exprs.len() + 1);
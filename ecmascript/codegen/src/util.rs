@@ -230,6 +230,8 @@ impl StartsWithAlphaNum for Expr {
 
             Expr::OptChain(ref e) => e.expr.starts_with_alpha_num(),
 
+            Expr::TopicRef(..) => false,
+
             Expr::Invalid(..) => true,
         }
     }
@@ -0,0 +1,624 @@
+//! Whole-program dependency graph.
+//!
+//! [GraphBuilder] starts from a set of entry files, loads and parses every
+//! reachable module and connects them with the [DependencyDescriptor]s
+//! returned by [analyze_dependencies].
+
+use crate::{analyze_dependencies, DependencyDescriptor, DependencyKind, DependencySpecifier};
+use anyhow::{anyhow, Context, Error};
+use petgraph::{
+    algo::tarjan_scc,
+    graph::{DiGraph, NodeIndex},
+    visit::{Dfs, EdgeRef, Reversed},
+};
+#[cfg(feature = "concurrent")]
+use rayon::iter::{IntoParallelIterator, ParallelIterator};
+use serde::Serialize;
+use std::{collections::HashMap, fmt::Write};
+use swc_common::{comments::SingleThreadedComments, sync::Lrc, FileName, SourceFile, SourceMap};
+use swc_ecma_parser::{Parser, StringInput, Syntax};
+
+/// Resolves a module specifier relative to the importing module.
+pub trait Resolve: swc_common::sync::Send + swc_common::sync::Sync {
+    fn resolve(&self, base: &FileName, module_specifier: &str) -> Result<FileName, Error>;
+}
+
+impl<T: ?Sized + Resolve> Resolve for Box<T> {
+    fn resolve(&self, base: &FileName, module_specifier: &str) -> Result<FileName, Error> {
+        (**self).resolve(base, module_specifier)
+    }
+}
+
+impl<'a, T: ?Sized + Resolve> Resolve for &'a T {
+    fn resolve(&self, base: &FileName, module_specifier: &str) -> Result<FileName, Error> {
+        (**self).resolve(base, module_specifier)
+    }
+}
+
+/// Source of a module, as returned by [Load].
+pub struct LoadedModule {
+    /// The file should be registered to the [SourceMap] passed to
+    /// [GraphBuilder::new], as it's used to compute locations.
+    pub fm: Lrc<SourceFile>,
+    pub syntax: Syntax,
+}
+
+pub trait Load: swc_common::sync::Send + swc_common::sync::Sync {
+    fn load(&self, file: &FileName) -> Result<LoadedModule, Error>;
+}
+
+impl<T: ?Sized + Load> Load for Box<T> {
+    fn load(&self, file: &FileName) -> Result<LoadedModule, Error> {
+        (**self).load(file)
+    }
+}
+
+impl<'a, T: ?Sized + Load> Load for &'a T {
+    fn load(&self, file: &FileName) -> Result<LoadedModule, Error> {
+        (**self).load(file)
+    }
+}
+
+/// A dependency which could not be resolved.
+#[derive(Debug)]
+pub struct UnresolvedDependency {
+    pub importer: FileName,
+    pub dependency: DependencyDescriptor,
+    pub error: Error,
+}
+
+/// A module which could not be loaded or parsed.
+#[derive(Debug)]
+pub struct FailedModule {
+    pub file: FileName,
+    pub error: Error,
+}
+
+pub struct GraphBuilder<L, R>
+where
+    L: Load,
+    R: Resolve,
+{
+    cm: Lrc<SourceMap>,
+    loader: L,
+    resolver: R,
+}
+
+/// Result of [GraphBuilder::process].
+type Processed = Result<Vec<(DependencyDescriptor, Result<FileName, Error>)>, Error>;
+
+impl<L, R> GraphBuilder<L, R>
+where
+    L: Load,
+    R: Resolve,
+{
+    pub fn new(cm: Lrc<SourceMap>, loader: L, resolver: R) -> Self {
+        GraphBuilder {
+            cm,
+            loader,
+            resolver,
+        }
+    }
+
+    /// Builds a graph of all modules reachable from `entries`.
+    ///
+    /// Modules are processed breadth-first, and each layer is processed in
+    /// parallel if the `concurrent` feature is enabled. Failures are recorded
+    /// in the graph instead of aborting the whole build.
+    pub fn build(&self, entries: &[FileName]) -> ModuleGraph {
+        let mut graph = ModuleGraph::default();
+
+        let mut queue = vec![];
+        for entry in entries {
+            let (idx, is_new) = graph.add_module(entry.clone());
+            graph.entries.push(idx);
+            if is_new {
+                queue.push(entry.clone());
+            }
+        }
+
+        while !queue.is_empty() {
+            let results = self.process_all(queue);
+            queue = vec![];
+
+            for (file, result) in results {
+                let from = graph.indices[&file];
+                let deps = match result {
+                    Ok(deps) => deps,
+                    Err(error) => {
+                        graph.failed.push(FailedModule { file, error });
+                        continue;
+                    }
+                };
+
+                for (dep, resolved) in deps {
+                    match resolved {
+                        Ok(resolved) => {
+                            let (to, is_new) = graph.add_module(resolved.clone());
+                            if is_new {
+                                queue.push(resolved);
+                            }
+                            graph.graph.add_edge(from, to, dep);
+                        }
+                        Err(error) => graph.unresolved.push(UnresolvedDependency {
+                            importer: file.clone(),
+                            dependency: dep,
+                            error,
+                        }),
+                    }
+                }
+            }
+        }
+
+        graph
+    }
+
+    #[cfg(feature = "concurrent")]
+    fn process_all(&self, files: Vec<FileName>) -> Vec<(FileName, Processed)> {
+        files
+            .into_par_iter()
+            .map(|file| {
+                let res = self.process(&file);
+                (file, res)
+            })
+            .collect()
+    }
+
+    #[cfg(not(feature = "concurrent"))]
+    fn process_all(&self, files: Vec<FileName>) -> Vec<(FileName, Processed)> {
+        files
+            .into_iter()
+            .map(|file| {
+                let res = self.process(&file);
+                (file, res)
+            })
+            .collect()
+    }
+
+    fn process(&self, file: &FileName) -> Processed {
+        let loaded = self
+            .loader
+            .load(file)
+            .with_context(|| format!("failed to load {}", file))?;
+
+        let comments = SingleThreadedComments::default();
+        let mut parser = Parser::new(
+            loaded.syntax,
+            StringInput::from(&*loaded.fm),
+            Some(&comments),
+        );
+        let module = parser
+            .parse_module()
+            .map_err(|err| anyhow!("failed to parse {}: {}", file, err.into_kind().msg()))?;
+
+        Ok(analyze_dependencies(&module, &self.cm, &comments)
+            .into_iter()
+            .map(|dep| {
+                let resolved = if dep.is_template {
+                    Err(anyhow!(
+                        "cannot resolve template literal `{}` from {}",
+                        dep.specifier,
+                        file
+                    ))
+                } else {
+                    self.resolver
+                        .resolve(file, &dep.specifier)
+                        .with_context(|| {
+                            format!("failed to resolve {} from {}", dep.specifier, file)
+                        })
+                };
+                (dep, resolved)
+            })
+            .collect())
+    }
+}
+
+/// Dependency graph of a program.
+///
+/// Nodes are modules and edges are [DependencyDescriptor]s, pointing from the
+/// importer to the imported module.
+#[derive(Debug, Default)]
+pub struct ModuleGraph {
+    graph: DiGraph<FileName, DependencyDescriptor>,
+    indices: HashMap<FileName, NodeIndex>,
+    entries: Vec<NodeIndex>,
+    unresolved: Vec<UnresolvedDependency>,
+    failed: Vec<FailedModule>,
+}
+
+/// An edge of [ModuleGraph].
+#[derive(Debug, Clone, Copy)]
+pub struct DependencyEdge<'a> {
+    pub from: &'a FileName,
+    pub to: &'a FileName,
+    pub dependency: &'a DependencyDescriptor,
+}
+
+impl DependencyEdge<'_> {
+    pub fn is_type_only(&self) -> bool {
+        is_type_only(&self.dependency.kind)
+    }
+}
+
+fn is_type_only(kind: &DependencyKind) -> bool {
+    matches!(
+        kind,
+        DependencyKind::ImportType | DependencyKind::ExportType
+    )
+}
+
+impl ModuleGraph {
+    fn add_module(&mut self, file: FileName) -> (NodeIndex, bool) {
+        if let Some(&idx) = self.indices.get(&file) {
+            return (idx, false);
+        }
+
+        let idx = self.graph.add_node(file.clone());
+        self.indices.insert(file, idx);
+        (idx, true)
+    }
+
+    pub fn entries(&self) -> impl '_ + Iterator<Item = &FileName> {
+        self.entries.iter().map(move |&idx| &self.graph[idx])
+    }
+
+    /// All modules in the graph, in the order of discovery.
+    pub fn modules(&self) -> impl '_ + Iterator<Item = &FileName> {
+        self.graph.node_indices().map(move |idx| &self.graph[idx])
+    }
+
+    pub fn contains(&self, file: &FileName) -> bool {
+        self.indices.contains_key(file)
+    }
+
+    pub fn edges(&self) -> impl '_ + Iterator<Item = DependencyEdge<'_>> {
+        self.graph.edge_references().map(move |e| DependencyEdge {
+            from: &self.graph[e.source()],
+            to: &self.graph[e.target()],
+            dependency: e.weight(),
+        })
+    }
+
+    /// Edges created by `import type`, `export type` or `import('foo')` in a
+    /// type position.
+    pub fn type_only_edges(&self) -> impl '_ + Iterator<Item = DependencyEdge<'_>> {
+        self.edges().filter(|e| e.is_type_only())
+    }
+
+    /// Direct dependencies of `file`.
+    pub fn dependencies_of(&self, file: &FileName) -> Vec<DependencyEdge<'_>> {
+        let idx = match self.indices.get(file) {
+            Some(&idx) => idx,
+            None => return vec![],
+        };
+
+        self.graph
+            .edges(idx)
+            .map(|e| DependencyEdge {
+                from: &self.graph[e.source()],
+                to: &self.graph[e.target()],
+                dependency: e.weight(),
+            })
+            .collect()
+    }
+
+    /// Returns `changed` and every module which transitively depends on one of
+    /// them.
+    ///
+    /// This is what should be rebuilt or retested if `changed` is modified.
+    pub fn affected_by(&self, changed: &[FileName]) -> Vec<&FileName> {
+        let reversed = Reversed(&self.graph);
+        let mut dfs = Dfs::empty(reversed);
+        dfs.stack
+            .extend(changed.iter().filter_map(|f| self.indices.get(f).copied()));
+
+        let mut affected = vec![];
+        while let Some(idx) = dfs.next(reversed) {
+            affected.push(&self.graph[idx]);
+        }
+        affected
+    }
+
+    /// Returns the import cycles of the graph.
+    ///
+    /// Each cycle is a strongly connected component, so a module is never
+    /// listed in two cycles. If `include_type_only` is false, type-only edges
+    /// are ignored because they are erased before runtime.
+    pub fn cycles(&self, include_type_only: bool) -> Vec<Vec<&FileName>> {
+        let mut filtered =
+            DiGraph::<(), ()>::with_capacity(self.graph.node_count(), self.graph.edge_count());
+        for _ in self.graph.node_indices() {
+            filtered.add_node(());
+        }
+        for e in self.graph.edge_references() {
+            if include_type_only || !is_type_only(&e.weight().kind) {
+                filtered.update_edge(e.source(), e.target(), ());
+            }
+        }
+
+        tarjan_scc(&filtered)
+            .into_iter()
+            .filter(|scc| scc.len() > 1 || filtered.contains_edge(scc[0], scc[0]))
+            .map(|scc| scc.into_iter().map(|idx| &self.graph[idx]).collect())
+            .collect()
+    }
+
+    pub fn unresolved(&self) -> &[UnresolvedDependency] {
+        &self.unresolved
+    }
+
+    /// Modules which failed to load or parse.
+    pub fn failed(&self) -> &[FailedModule] {
+        &self.failed
+    }
+
+    /// Renders the graph in the graphviz format.
+    ///
+    /// Type-only edges are dashed and dynamic imports are dotted.
+    pub fn to_dot(&self) -> String {
+        let mut s = String::from("digraph {\n");
+        for idx in self.graph.node_indices() {
+            let _ = writeln!(
+                s,
+                "    {} [label={:?}];",
+                idx.index(),
+                self.graph[idx].to_string()
+            );
+        }
+        for e in self.graph.edge_references() {
+            let dep = e.weight();
+            let style = if is_type_only(&dep.kind) {
+                ", style=dashed"
+            } else if dep.is_dynamic {
+                ", style=dotted"
+            } else {
+                ""
+            };
+            let _ = writeln!(
+                s,
+                "    {} -> {} [label={:?}{}];",
+                e.source().index(),
+                e.target().index(),
+                &*dep.specifier,
+                style
+            );
+        }
+        s.push_str("}\n");
+        s
+    }
+
+    /// Serializes the graph as json.
+    pub fn to_json(&self) -> Result<String, Error> {
+        let modules = self
+            .graph
+            .node_indices()
+            .map(|idx| self.graph[idx].to_string())
+            .collect();
+
+        let dependencies = self
+            .graph
+            .edge_references()
+            .map(|e| JsonEdge {
+                from: e.source().index(),
+                to: e.target().index(),
+                dependency: JsonDependency::from(e.weight()),
+            })
+            .collect();
+
+        let unresolved = self
+            .unresolved
+            .iter()
+            .map(|u| JsonUnresolved {
+                importer: u.importer.to_string(),
+                dependency: JsonDependency::from(&u.dependency),
+                error: format!("{:#}", u.error),
+            })
+            .collect();
+
+        let failed = self
+            .failed
+            .iter()
+            .map(|f| JsonFailed {
+                file: f.file.to_string(),
+                error: format!("{:#}", f.error),
+            })
+            .collect();
+
+        let json = JsonGraph {
+            modules,
+            entries: self.entries.iter().map(|idx| idx.index()).collect(),
+            dependencies,
+            unresolved,
+            failed,
+        };
+
+        serde_json::to_string(&json).context("failed to serialize module graph")
+    }
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct JsonGraph<'a> {
+    modules: Vec<String>,
+    entries: Vec<usize>,
+    dependencies: Vec<JsonEdge<'a>>,
+    unresolved: Vec<JsonUnresolved<'a>>,
+    failed: Vec<JsonFailed>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct JsonEdge<'a> {
+    from: usize,
+    to: usize,
+    #[serde(flatten)]
+    dependency: JsonDependency<'a>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct JsonDependency<'a> {
+    kind: &'a DependencyKind,
+    is_dynamic: bool,
+    specifier: &'a str,
+    specifiers: &'a [DependencySpecifier],
+    line: usize,
+    col: usize,
+}
+
+impl<'a> From<&'a DependencyDescriptor> for JsonDependency<'a> {
+    fn from(dep: &'a DependencyDescriptor) -> Self {
+        JsonDependency {
+            kind: &dep.kind,
+            is_dynamic: dep.is_dynamic,
+            specifier: &*dep.specifier,
+            specifiers: &dep.specifiers,
+            line: dep.line,
+            col: dep.col,
+        }
+    }
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct JsonUnresolved<'a> {
+    importer: String,
+    #[serde(flatten)]
+    dependency: JsonDependency<'a>,
+    error: String,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct JsonFailed {
+    file: String,
+    error: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anyhow::bail;
+    use swc_ecma_parser::TsConfig;
+
+    struct Loader {
+        cm: Lrc<SourceMap>,
+        files: HashMap<&'static str, &'static str>,
+    }
+
+    impl Load for Loader {
+        fn load(&self, file: &FileName) -> Result<LoadedModule, Error> {
+            let name = match file {
+                FileName::Custom(name) => name,
+                _ => unreachable!(),
+            };
+            let src = match self.files.get(&**name) {
+                Some(src) => src,
+                None => bail!("not found"),
+            };
+
+            Ok(LoadedModule {
+                fm: self.cm.new_source_file(file.clone(), src.to_string()),
+                syntax: Syntax::Typescript(TsConfig {
+                    dynamic_import: true,
+                    ..Default::default()
+                }),
+            })
+        }
+    }
+
+    struct Resolver;
+
+    impl Resolve for Resolver {
+        fn resolve(&self, _: &FileName, module_specifier: &str) -> Result<FileName, Error> {
+            if !module_specifier.starts_with("./") {
+                bail!("not a relative path")
+            }
+
+            Ok(FileName::Custom(module_specifier[2..].to_string()))
+        }
+    }
+
+    fn build(files: Vec<(&'static str, &'static str)>) -> ModuleGraph {
+        let cm = Lrc::new(SourceMap::default());
+        let loader = Loader {
+            cm: cm.clone(),
+            files: files.into_iter().collect(),
+        };
+
+        GraphBuilder::new(cm, loader, Resolver).build(&[FileName::Custom("a".into())])
+    }
+
+    fn name(s: &str) -> FileName {
+        FileName::Custom(s.into())
+    }
+
+    #[test]
+    fn graph() {
+        let graph = build(vec![
+            (
+                "a",
+                "import { b } from './b'; import type { T } from './c';",
+            ),
+            (
+                "b",
+                "export * from './d'; const lodash = require('lodash');",
+            ),
+            ("c", "export type T = string;"),
+            ("d", "export const d = import('./missing');"),
+        ]);
+
+        assert_eq!(
+            graph.modules().collect::<Vec<_>>(),
+            vec![
+                &name("a"),
+                &name("b"),
+                &name("c"),
+                &name("d"),
+                &name("missing")
+            ]
+        );
+        assert_eq!(graph.type_only_edges().count(), 1);
+        assert_eq!(graph.unresolved().len(), 1);
+        assert_eq!(&*graph.unresolved()[0].dependency.specifier, "lodash");
+        assert_eq!(graph.failed().len(), 1);
+        assert_eq!(graph.failed()[0].file, name("missing"));
+        assert!(graph.cycles(true).is_empty());
+
+        let mut affected = graph.affected_by(&[name("d")]);
+        affected.sort();
+        assert_eq!(affected, vec![&name("a"), &name("b"), &name("d")]);
+    }
+
+    #[test]
+    fn cycles() {
+        let graph = build(vec![
+            ("a", "import './b';"),
+            ("b", "import './c'; import type { A } from './a';"),
+            ("c", "import './b';"),
+        ]);
+
+        assert_eq!(graph.cycles(false).len(), 1);
+        assert_eq!(graph.cycles(false)[0].len(), 2);
+
+        let cycles = graph.cycles(true);
+        assert_eq!(cycles.len(), 1);
+        assert_eq!(cycles[0].len(), 3);
+    }
+
+    #[test]
+    fn dot_and_json() {
+        let graph = build(vec![("a", "import './b';"), ("b", "")]);
+
+        assert_eq!(
+            graph.to_dot(),
+            "digraph {\n    0 [label=\"<a>\"];\n    1 [label=\"<b>\"];\n    0 -> 1 \
+             [label=\"./b\"];\n}\n"
+        );
+
+        let json: serde_json::Value = serde_json::from_str(&graph.to_json().unwrap()).unwrap();
+        assert_eq!(json["modules"], serde_json::json!(["<a>", "<b>"]));
+        assert_eq!(json["dependencies"][0]["kind"], "import");
+        assert_eq!(json["dependencies"][0]["to"], 1);
+    }
+}
@@ -1,5 +1,6 @@
+use serde::Serialize;
 use std::collections::HashMap;
-use swc_atoms::JsWord;
+use swc_atoms::{js_word, JsWord};
 use swc_common::{
     comments::{Comment, SingleThreadedComments},
     Loc, SourceMap, Span, DUMMY_SP,
@@ -7,6 +8,8 @@ use swc_common::{
 use swc_ecma_ast as ast;
 use swc_ecma_visit::{self, Node, Visit, VisitWith};
 
+pub mod graph;
+
 pub fn analyze_dependencies(
     module: &ast::Module,
     source_map: &SourceMap,
@@ -22,7 +25,118 @@ pub fn analyze_dependencies(
     v.items
 }
 
-#[derive(Clone, Debug, Eq, PartialEq)]
+/// Returns the names exported by `module`, including re-exports.
+///
+/// `export * from 'foo'` is not included, as the exported names are not known
+/// without analyzing `foo`. It's returned by [analyze_dependencies] with
+/// [DependencySpecifier::All].
+pub fn analyze_exports(module: &ast::Module, source_map: &SourceMap) -> Vec<ExportDescriptor> {
+    let mut exports = vec![];
+
+    let mut add = |name: &JsWord, span: Span, is_type_only: bool, src: Option<&ast::Str>| {
+        let location = source_map.lookup_char_pos(span.lo);
+        exports.push(ExportDescriptor {
+            name: name.clone(),
+            is_type_only,
+            src: src.map(|src| src.value.clone()),
+            line: location.line,
+            col: location.col_display,
+        });
+    };
+
+    for item in &module.body {
+        let decl = match item {
+            ast::ModuleItem::ModuleDecl(decl) => decl,
+            ast::ModuleItem::Stmt(_) => continue,
+        };
+
+        match decl {
+            ast::ModuleDecl::ExportDecl(export) => match &export.decl {
+                ast::Decl::Class(c) => add(&c.ident.sym, c.ident.span, false, None),
+                ast::Decl::Fn(f) => add(&f.ident.sym, f.ident.span, false, None),
+                ast::Decl::Var(var) => {
+                    let mut ids = vec![];
+                    for decl in &var.decls {
+                        collect_binding_idents(&decl.name, &mut ids);
+                    }
+                    for id in ids {
+                        add(&id.sym, id.span, false, None);
+                    }
+                }
+                ast::Decl::TsInterface(i) => add(&i.id.sym, i.id.span, true, None),
+                ast::Decl::TsTypeAlias(a) => add(&a.id.sym, a.id.span, true, None),
+                ast::Decl::TsEnum(e) => add(&e.id.sym, e.id.span, false, None),
+                ast::Decl::TsModule(m) => match &m.id {
+                    ast::TsModuleName::Ident(id) => add(&id.sym, id.span, false, None),
+                    ast::TsModuleName::Str(_) => {}
+                },
+            },
+            ast::ModuleDecl::ExportNamed(export) => {
+                for s in &export.specifiers {
+                    match s {
+                        ast::ExportSpecifier::Namespace(s) => add(
+                            &s.name.sym,
+                            s.name.span,
+                            export.type_only,
+                            export.src.as_ref(),
+                        ),
+                        ast::ExportSpecifier::Default(s) => add(
+                            &s.exported.sym,
+                            s.exported.span,
+                            export.type_only,
+                            export.src.as_ref(),
+                        ),
+                        ast::ExportSpecifier::Named(s) => {
+                            let exported = s.exported.as_ref().unwrap_or(&s.orig);
+                            add(
+                                &exported.sym,
+                                exported.span,
+                                export.type_only,
+                                export.src.as_ref(),
+                            )
+                        }
+                    }
+                }
+            }
+            ast::ModuleDecl::ExportDefaultDecl(export) => {
+                let is_type_only = matches!(export.decl, ast::DefaultDecl::TsInterfaceDecl(_));
+                add(&js_word!("default"), export.span, is_type_only, None)
+            }
+            ast::ModuleDecl::ExportDefaultExpr(export) => {
+                add(&js_word!("default"), export.span, false, None)
+            }
+            _ => {}
+        }
+    }
+
+    exports
+}
+
+fn collect_binding_idents<'a>(pat: &'a ast::Pat, ids: &mut Vec<&'a ast::Ident>) {
+    match pat {
+        ast::Pat::Ident(i) => ids.push(&i.id),
+        ast::Pat::Array(a) => {
+            for elem in a.elems.iter().flatten() {
+                collect_binding_idents(elem, ids);
+            }
+        }
+        ast::Pat::Rest(r) => collect_binding_idents(&r.arg, ids),
+        ast::Pat::Object(o) => {
+            for prop in &o.props {
+                match prop {
+                    ast::ObjectPatProp::KeyValue(kv) => collect_binding_idents(&kv.value, ids),
+                    ast::ObjectPatProp::Assign(a) => ids.push(&a.key),
+                    ast::ObjectPatProp::Rest(r) => collect_binding_idents(&r.arg, ids),
+                }
+            }
+        }
+        ast::Pat::Assign(a) => collect_binding_idents(&a.left, ids),
+        ast::Pat::Invalid(_) | ast::Pat::Expr(_) => {}
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
 pub enum DependencyKind {
     Import,
     ImportType,
@@ -50,6 +164,49 @@ pub struct DependencyDescriptor {
     /// Import assertions for this dependency.
     /// NOTE: it's filled only for static imports and exports.
     pub import_assertions: HashMap<String, String>,
+    /// Bindings imported or re-exported from the dependency.
+    ///
+    /// Empty for side-effect only imports like `import 'foo'`.
+    pub specifiers: Vec<DependencySpecifier>,
+    /// Set if the specifier is a template literal with expressions, like
+    /// `` import(`./locale/${lang}.js`) ``. `specifier` is then a glob pattern
+    /// where each expression is replaced with `*`.
+    pub is_template: bool,
+}
+
+/// A binding imported or re-exported from a dependency.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum DependencySpecifier {
+    /// `import foo from 'foo'` or `export foo from 'foo'`.
+    Default { local: JsWord },
+    /// `import { foo as bar } from 'foo'` or `export { foo as bar } from
+    /// 'foo'`.
+    ///
+    /// For re-exports, `local` is the exported name.
+    Named { imported: JsWord, local: JsWord },
+    /// `import * as ns from 'foo'` or `export * as ns from 'foo'`.
+    Namespace { local: JsWord },
+    /// `export * from 'foo'`, which re-exports all named exports.
+    All,
+    /// Dynamic imports, `require` calls and `typeof import('foo')`, which may
+    /// use any export.
+    Unknown,
+}
+
+/// A name exported by a module.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ExportDescriptor {
+    /// The exported name. `default` for default exports.
+    pub name: JsWord,
+    /// `true` for interfaces, type aliases and `export type { .. }`.
+    pub is_type_only: bool,
+    /// The module specifier if it's a re-export like `export { foo } from
+    /// 'foo'`.
+    pub src: Option<JsWord>,
+    /// The location of the exported name.
+    pub line: usize,
+    pub col: usize,
 }
 
 struct DependencyCollector<'a> {
@@ -85,6 +242,22 @@ impl<'a> Visit for DependencyCollector<'a> {
             DependencyKind::Import
         };
         let import_assertions = parse_import_assertions(node.asserts.as_ref());
+        let specifiers = node
+            .specifiers
+            .iter()
+            .map(|s| match s {
+                ast::ImportSpecifier::Named(s) => DependencySpecifier::Named {
+                    imported: s.imported.as_ref().unwrap_or(&s.local).sym.clone(),
+                    local: s.local.sym.clone(),
+                },
+                ast::ImportSpecifier::Default(s) => DependencySpecifier::Default {
+                    local: s.local.sym.clone(),
+                },
+                ast::ImportSpecifier::Namespace(s) => DependencySpecifier::Namespace {
+                    local: s.local.sym.clone(),
+                },
+            })
+            .collect();
         self.items.push(DependencyDescriptor {
             kind,
             is_dynamic: false,
@@ -95,6 +268,8 @@ impl<'a> Visit for DependencyCollector<'a> {
             specifier_col: specifier_location.col_display,
             specifier_line: specifier_location.line,
             import_assertions,
+            specifiers,
+            is_template: false,
         });
     }
 
@@ -111,6 +286,22 @@ impl<'a> Visit for DependencyCollector<'a> {
                 DependencyKind::Export
             };
             let import_assertions = parse_import_assertions(node.asserts.as_ref());
+            let specifiers = node
+                .specifiers
+                .iter()
+                .map(|s| match s {
+                    ast::ExportSpecifier::Named(s) => DependencySpecifier::Named {
+                        imported: s.orig.sym.clone(),
+                        local: s.exported.as_ref().unwrap_or(&s.orig).sym.clone(),
+                    },
+                    ast::ExportSpecifier::Default(s) => DependencySpecifier::Default {
+                        local: s.exported.sym.clone(),
+                    },
+                    ast::ExportSpecifier::Namespace(s) => DependencySpecifier::Namespace {
+                        local: s.name.sym.clone(),
+                    },
+                })
+                .collect();
             self.items.push(DependencyDescriptor {
                 kind,
                 is_dynamic: false,
@@ -121,6 +312,8 @@ impl<'a> Visit for DependencyCollector<'a> {
                 specifier_col: specifier_location.col_display,
                 specifier_line: specifier_location.line,
                 import_assertions,
+                specifiers,
+                is_template: false,
             });
         }
     }
@@ -142,6 +335,8 @@ impl<'a> Visit for DependencyCollector<'a> {
             specifier_col: specifier_location.col_display,
             specifier_line: specifier_location.line,
             import_assertions,
+            specifiers: vec![DependencySpecifier::All],
+            is_template: false,
         });
     }
 
@@ -151,6 +346,17 @@ impl<'a> Visit for DependencyCollector<'a> {
         let location = self.get_location(span);
         let leading_comments = self.get_leading_comments(span);
         let specifier_location = self.get_location(node.arg.span);
+        // `import('foo').Bar.Baz` uses the export `Bar`.
+        let imported = match &node.qualifier {
+            Some(name) => {
+                let i = leftmost_ident(name);
+                DependencySpecifier::Named {
+                    imported: i.sym.clone(),
+                    local: i.sym.clone(),
+                }
+            }
+            None => DependencySpecifier::Unknown,
+        };
         self.items.push(DependencyDescriptor {
             kind: DependencyKind::ImportType,
             is_dynamic: false,
@@ -161,6 +367,8 @@ impl<'a> Visit for DependencyCollector<'a> {
             specifier_col: specifier_location.col_display,
             specifier_line: specifier_location.line,
             import_assertions: HashMap::default(),
+            specifiers: vec![imported],
+            is_template: false,
         });
     }
 
@@ -196,30 +404,59 @@ impl<'a> Visit for DependencyCollector<'a> {
         let is_dynamic = !self.is_top_level || kind == DependencyKind::Import;
 
         if let Some(arg) = node.args.get(0) {
-            if let Lit(lit) = &*arg.expr {
-                if let ast::Lit::Str(str_) = lit {
-                    let specifier = str_.value.clone();
-                    let span = node.span;
-                    let location = self.get_location(span);
-                    let leading_comments = self.get_leading_comments(span);
-                    let specifier_location = self.get_location(str_.span);
-                    self.items.push(DependencyDescriptor {
-                        kind,
-                        is_dynamic,
-                        leading_comments,
-                        col: location.col_display,
-                        line: location.line,
-                        specifier,
-                        specifier_col: specifier_location.col_display,
-                        specifier_line: specifier_location.line,
-                        import_assertions: HashMap::default(),
-                    });
-                }
-            }
+            let (specifier, specifier_span, is_template) = match &*arg.expr {
+                Lit(ast::Lit::Str(str_)) => (str_.value.clone(), str_.span, false),
+                Tpl(tpl) => match tpl_to_specifier(tpl) {
+                    Some(specifier) => (specifier, tpl.span, !tpl.exprs.is_empty()),
+                    None => return,
+                },
+                _ => return,
+            };
+            let span = node.span;
+            let location = self.get_location(span);
+            let leading_comments = self.get_leading_comments(span);
+            let specifier_location = self.get_location(specifier_span);
+            self.items.push(DependencyDescriptor {
+                kind,
+                is_dynamic,
+                leading_comments,
+                col: location.col_display,
+                line: location.line,
+                specifier,
+                specifier_col: specifier_location.col_display,
+                specifier_line: specifier_location.line,
+                import_assertions: HashMap::default(),
+                specifiers: vec![DependencySpecifier::Unknown],
+                is_template,
+            });
+        }
+    }
+}
+
+fn leftmost_ident(mut name: &ast::TsEntityName) -> &ast::Ident {
+    loop {
+        match name {
+            ast::TsEntityName::Ident(i) => return i,
+            ast::TsEntityName::TsQualifiedName(q) => name = &q.left,
         }
     }
 }
 
+/// Converts a template literal to a specifier, replacing expressions with
+/// `*`.
+///
+/// Returns [None] if the template contains an invalid escape sequence.
+fn tpl_to_specifier(tpl: &ast::Tpl) -> Option<JsWord> {
+    let mut specifier = String::new();
+    for (i, quasi) in tpl.quasis.iter().enumerate() {
+        if i != 0 {
+            specifier.push('*');
+        }
+        specifier.push_str(&quasi.cooked.as_ref()?.value);
+    }
+    Some(specifier.into())
+}
+
 /// Parses import assertions into a hashmap. According to proposal the values
 /// can only be strings (https://github.com/tc39/proposal-import-assertions#should-more-than-just-strings-be-supported-as-attribute-values)
 /// and thus non-string values are skipped.
@@ -338,6 +575,10 @@ try {
                     specifier_col: 21,
                     specifier_line: 1,
                     import_assertions: HashMap::default(),
+                    specifiers: vec![DependencySpecifier::Namespace {
+                        local: "bar".into()
+                    }],
+                    is_template: false,
                 },
                 DependencyDescriptor {
                     kind: DependencyKind::ImportType,
@@ -353,6 +594,11 @@ try {
                     specifier_col: 25,
                     specifier_line: 3,
                     import_assertions: HashMap::default(),
+                    specifiers: vec![DependencySpecifier::Named {
+                        imported: "Foo".into(),
+                        local: "Foo".into()
+                    }],
+                    is_template: false,
                 },
                 DependencyDescriptor {
                     kind: DependencyKind::Export,
@@ -368,6 +614,10 @@ try {
                     specifier_col: 22,
                     specifier_line: 5,
                     import_assertions: HashMap::default(),
+                    specifiers: vec![DependencySpecifier::Namespace {
+                        local: "Buzz".into()
+                    }],
+                    is_template: false,
                 },
                 DependencyDescriptor {
                     kind: DependencyKind::ExportType,
@@ -390,6 +640,11 @@ try {
                     specifier_col: 26,
                     specifier_line: 10,
                     import_assertions: HashMap::default(),
+                    specifiers: vec![DependencySpecifier::Named {
+                        imported: "Fizz".into(),
+                        local: "Fizz".into()
+                    }],
+                    is_template: false,
                 },
                 DependencyDescriptor {
                     kind: DependencyKind::Require,
@@ -401,6 +656,8 @@ try {
                     specifier_col: 25,
                     specifier_line: 11,
                     import_assertions: HashMap::default(),
+                    specifiers: vec![DependencySpecifier::Unknown],
+                    is_template: false,
                 },
                 DependencyDescriptor {
                     kind: DependencyKind::Import,
@@ -412,6 +669,8 @@ try {
                     specifier_col: 13,
                     specifier_line: 14,
                     import_assertions: HashMap::default(),
+                    specifiers: vec![DependencySpecifier::Unknown],
+                    is_template: false,
                 },
                 DependencyDescriptor {
                     kind: DependencyKind::Import,
@@ -423,6 +682,8 @@ try {
                     specifier_col: 29,
                     specifier_line: 17,
                     import_assertions: HashMap::default(),
+                    specifiers: vec![DependencySpecifier::Unknown],
+                    is_template: false,
                 },
                 DependencyDescriptor {
                     kind: DependencyKind::Require,
@@ -434,6 +695,8 @@ try {
                     specifier_col: 24,
                     specifier_line: 23,
                     import_assertions: HashMap::default(),
+                    specifiers: vec![DependencySpecifier::Unknown],
+                    is_template: false,
                 }
             ]
         );
@@ -465,6 +728,10 @@ export { bar } from "./test.json" assert { "type": "json" };
                     specifier_col: 21,
                     specifier_line: 1,
                     import_assertions: expected_assertions1.clone(),
+                    specifiers: vec![DependencySpecifier::Namespace {
+                        local: "bar".into()
+                    }],
+                    is_template: false,
                 },
                 DependencyDescriptor {
                     kind: DependencyKind::Export,
@@ -476,6 +743,8 @@ export { bar } from "./test.json" assert { "type": "json" };
                     specifier_col: 14,
                     specifier_line: 2,
                     import_assertions: expected_assertions1,
+                    specifiers: vec![DependencySpecifier::All],
+                    is_template: false,
                 },
                 DependencyDescriptor {
                     kind: DependencyKind::Export,
@@ -487,8 +756,112 @@ export { bar } from "./test.json" assert { "type": "json" };
                     specifier_col: 20,
                     specifier_line: 3,
                     import_assertions: expected_assertions2,
+                    specifiers: vec![DependencySpecifier::Named {
+                        imported: "bar".into(),
+                        local: "bar".into()
+                    }],
+                    is_template: false,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_specifiers() {
+        let source = r#"import foo, { a, b as c } from "./a.ts";
+export { default as d, e } from "./b.ts";
+import(`./locale/${lang}/${ns}.json`);
+import(`./static.ts`);
+type T = typeof import("./c.ts").Foo.Bar;
+"#;
+        let (module, source_map, comments) = helper("test.ts", &source).unwrap();
+        let dependencies = analyze_dependencies(&module, &source_map, &comments);
+        assert_eq!(dependencies.len(), 5);
+        assert_eq!(
+            dependencies[0].specifiers,
+            vec![
+                DependencySpecifier::Default {
+                    local: "foo".into()
+                },
+                DependencySpecifier::Named {
+                    imported: "a".into(),
+                    local: "a".into()
+                },
+                DependencySpecifier::Named {
+                    imported: "b".into(),
+                    local: "c".into()
                 },
             ]
         );
+        assert_eq!(
+            dependencies[1].specifiers,
+            vec![
+                DependencySpecifier::Named {
+                    imported: "default".into(),
+                    local: "d".into()
+                },
+                DependencySpecifier::Named {
+                    imported: "e".into(),
+                    local: "e".into()
+                },
+            ]
+        );
+        assert_eq!(&*dependencies[2].specifier, "./locale/*/*.json");
+        assert!(dependencies[2].is_template);
+        assert!(dependencies[2].is_dynamic);
+        assert_eq!(&*dependencies[3].specifier, "./static.ts");
+        assert!(!dependencies[3].is_template);
+        assert_eq!(dependencies[4].kind, DependencyKind::ImportType);
+        assert_eq!(
+            dependencies[4].specifiers,
+            vec![DependencySpecifier::Named {
+                imported: "Foo".into(),
+                local: "Foo".into()
+            }]
+        );
+    }
+
+    #[test]
+    fn test_exports() {
+        let source = r#"export const a = 1, { b, c: [d = 2, ...e] } = obj;
+export function f() {}
+export class C {}
+export interface I {}
+export type T = string;
+export enum E {}
+export namespace N {}
+const g = 1;
+export { g as h };
+export type { I as J };
+export { k } from "./k.ts";
+export * from "./all.ts";
+export default f;
+"#;
+        let (module, source_map, _) = helper("test.ts", &source).unwrap();
+        let exports = analyze_exports(&module, &source_map);
+        assert_eq!(
+            exports
+                .iter()
+                .map(|e| (&*e.name, e.is_type_only, e.src.as_deref()))
+                .collect::<Vec<_>>(),
+            vec![
+                ("a", false, None),
+                ("b", false, None),
+                ("d", false, None),
+                ("e", false, None),
+                ("f", false, None),
+                ("C", false, None),
+                ("I", true, None),
+                ("T", true, None),
+                ("E", false, None),
+                ("N", false, None),
+                ("h", false, None),
+                ("J", true, None),
+                ("k", false, Some("./k.ts")),
+                ("default", false, None),
+            ]
+        );
+        assert_eq!(exports[0].line, 1);
+        assert_eq!(exports[0].col, 13);
     }
 }
@@ -0,0 +1,921 @@
+//! Code coverage instrumentation compatible with `babel-plugin-istanbul`.
+
+use crate::util::MapWithMut;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::BTreeMap;
+use swc_atoms::JsWord;
+use swc_common::{
+    comments::{Comments, CommentsExt},
+    sync::Lrc,
+    BytePos, SourceMap, Span, Spanned, DUMMY_SP,
+};
+use swc_ecma_ast::*;
+use swc_ecma_utils::{prepend, prepend_stmts, quote_ident, quote_str, ExprFactory, StmtLike};
+use swc_ecma_visit::{as_folder, noop_visit_mut_type, Fold, VisitMut, VisitMutWith};
+
+/// Version of the coverage object, taken from `istanbul-lib-instrument`.
+const COVERAGE_SCHEMA: &str = "1a1c01bbd47fc00a2c39e90264f33305004495a9";
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "camelCase")]
+pub struct Config {
+    /// Name of the global variable which holds coverage data of all files.
+    ///
+    /// Defaults to `__coverage__`.
+    #[serde(default = "default_coverage_variable")]
+    pub coverage_variable: String,
+}
+
+fn default_coverage_variable() -> String {
+    "__coverage__".into()
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            coverage_variable: default_coverage_variable(),
+        }
+    }
+}
+
+/// A location in the original source. `line` is 1-based and `column` is
+/// 0-based, like istanbul.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Position {
+    pub line: u32,
+    pub column: u32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Range {
+    pub start: Position,
+    pub end: Position,
+    /// `true` if the range is excluded by an `istanbul ignore` hint.
+    #[serde(default, skip_serializing_if = "is_false")]
+    pub skip: bool,
+}
+
+fn is_false(v: &bool) -> bool {
+    !*v
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FnMapping {
+    pub name: String,
+    pub decl: Range,
+    pub loc: Range,
+    pub line: u32,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BranchMapping {
+    pub loc: Range,
+    #[serde(rename = "type")]
+    pub kind: String,
+    pub locations: Vec<Range>,
+    pub line: u32,
+}
+
+/// The `coverageData` object of a file, in the format used by istanbul.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CoverageData {
+    pub path: String,
+    pub statement_map: BTreeMap<usize, Range>,
+    pub fn_map: BTreeMap<usize, FnMapping>,
+    pub branch_map: BTreeMap<usize, BranchMapping>,
+    pub s: BTreeMap<usize, u32>,
+    pub f: BTreeMap<usize, u32>,
+    pub b: BTreeMap<usize, Vec<u32>>,
+    #[serde(rename = "_coverageSchema")]
+    pub coverage_schema: String,
+    pub hash: String,
+}
+
+/// Instruments a file with `__coverage__` counters for statements,
+/// functions and branches.
+///
+/// Locations are mapped through `input_source_map` if it's provided, and
+/// `/* istanbul ignore next */`, `/* istanbul ignore if */`,
+/// `/* istanbul ignore else */` and `/* istanbul ignore file */` are
+/// respected if `comments` is provided.
+///
+/// This pass should be applied before any other pass, as it relies on the
+/// spans of the original source.
+pub fn coverage<C>(
+    cm: Lrc<SourceMap>,
+    comments: Option<C>,
+    input_source_map: Option<sourcemap::SourceMap>,
+    config: Config,
+) -> impl Fold
+where
+    C: Comments,
+{
+    as_folder(Coverage {
+        cm,
+        comments,
+        input_source_map,
+        config,
+        data: Default::default(),
+        // Replaced with a name derived from the hash of the file.
+        cov_fn: quote_ident!("cov"),
+        fn_name: None,
+    })
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum IgnoreHint {
+    Next,
+    If,
+    Else,
+    File,
+}
+
+struct Coverage<C>
+where
+    C: Comments,
+{
+    cm: Lrc<SourceMap>,
+    comments: Option<C>,
+    input_source_map: Option<sourcemap::SourceMap>,
+    config: Config,
+    data: CoverageData,
+    cov_fn: Ident,
+    /// Name of the function which is going to be visited.
+    fn_name: Option<(JsWord, Span)>,
+}
+
+impl<C> Coverage<C>
+where
+    C: Comments,
+{
+    /// Returns `true` if the file should not be instrumented.
+    fn init(&mut self, span: Span) -> bool {
+        if self.ignore_hint(span.lo) == Some(IgnoreHint::File) {
+            return true;
+        }
+
+        let file = self.cm.lookup_char_pos(span.lo).file;
+        let hash = format!("{:x}", file.src_hash);
+        self.cov_fn = Ident::new(
+            format!("cov_{}", &hash[..hash.len().min(10)]).into(),
+            DUMMY_SP,
+        );
+        self.data = CoverageData {
+            path: file.name.to_string(),
+            coverage_schema: COVERAGE_SCHEMA.into(),
+            hash,
+            ..Default::default()
+        };
+
+        false
+    }
+
+    fn ignore_hint(&self, pos: BytePos) -> Option<IgnoreHint> {
+        let comments = self.comments.as_ref()?;
+
+        comments.with_leading(pos, |comments| {
+            comments.iter().find_map(|c| {
+                let mut words = c.text.split_whitespace();
+                if words.next()? != "istanbul" || words.next()? != "ignore" {
+                    return None;
+                }
+
+                match words.next()? {
+                    "next" => Some(IgnoreHint::Next),
+                    "if" => Some(IgnoreHint::If),
+                    "else" => Some(IgnoreHint::Else),
+                    "file" => Some(IgnoreHint::File),
+                    _ => None,
+                }
+            })
+        })
+    }
+
+    /// Maps `pos` through the input source map. Tokens of other lines are not
+    /// used, and `pos` is kept as is if its line has no token before it.
+    fn pos(&self, pos: BytePos) -> Position {
+        let loc = self.cm.lookup_char_pos(pos);
+        let line = loc.line as u32;
+        let column = loc.col.0 as u32;
+
+        if let Some(map) = &self.input_source_map {
+            if let Some(token) = map.lookup_token(line - 1, column) {
+                if token.get_dst_line() == line - 1 {
+                    return Position {
+                        line: token.get_src_line() + 1,
+                        column: token.get_src_col() + (column - token.get_dst_col()),
+                    };
+                }
+            }
+        }
+
+        Position { line, column }
+    }
+
+    fn range(&self, span: Span) -> Range {
+        Range {
+            start: self.pos(span.lo),
+            end: self.pos(span.hi),
+            skip: false,
+        }
+    }
+
+    /// `cov_xxx().s[0]++`
+    fn counter(&self, kind: &str, idx: usize, branch: Option<usize>) -> Expr {
+        let mut e = Expr::Call(CallExpr {
+            span: DUMMY_SP,
+            callee: self.cov_fn.clone().as_callee(),
+            args: vec![],
+            type_args: Default::default(),
+        })
+        .make_member(quote_ident!(kind))
+        .computed_member(num(idx));
+
+        if let Some(branch) = branch {
+            e = e.computed_member(num(branch));
+        }
+
+        Expr::Update(UpdateExpr {
+            span: DUMMY_SP,
+            op: op!("++"),
+            prefix: false,
+            arg: Box::new(e),
+        })
+    }
+
+    fn new_statement(&mut self, span: Span) -> Stmt {
+        let idx = self.data.statement_map.len();
+        let range = self.range(span);
+        self.data.statement_map.insert(idx, range);
+        self.data.s.insert(idx, 0);
+
+        self.counter("s", idx, None).into_stmt()
+    }
+
+    fn new_function(&mut self, name: Option<(JsWord, Span)>, loc: Span) -> Stmt {
+        let idx = self.data.fn_map.len();
+        let (name, decl) = match name {
+            Some((name, span)) => (name.to_string(), span),
+            None => (format!("(anonymous_{})", idx), loc),
+        };
+        let decl = self.range(decl);
+        let loc = self.range(loc);
+        self.data.fn_map.insert(
+            idx,
+            FnMapping {
+                name,
+                decl,
+                line: decl.start.line,
+                loc,
+            },
+        );
+        self.data.f.insert(idx, 0);
+
+        self.counter("f", idx, None).into_stmt()
+    }
+
+    fn new_branch(&mut self, kind: &str, span: Span, locations: Vec<Range>) -> usize {
+        let idx = self.data.branch_map.len();
+        let loc = self.range(span);
+        self.data.b.insert(idx, vec![0; locations.len()]);
+        self.data.branch_map.insert(
+            idx,
+            BranchMapping {
+                loc,
+                kind: kind.into(),
+                locations,
+                line: loc.start.line,
+            },
+        );
+
+        idx
+    }
+
+    fn instrument_body(
+        &mut self,
+        name: Option<(JsWord, Span)>,
+        loc: Span,
+        body: &mut Option<BlockStmt>,
+    ) {
+        let counter = self.new_function(name, loc);
+        body.visit_mut_with(self);
+        if let Some(body) = body {
+            prepend(&mut body.stmts, counter);
+        }
+    }
+
+    /// Wraps the leaves of a logical expression with branch counters.
+    fn instrument_logical(&mut self, e: &mut Expr, idx: usize, locations: &mut Vec<Range>) {
+        match e {
+            Expr::Bin(BinExpr {
+                op: op!("&&"),
+                left,
+                right,
+                ..
+            })
+            | Expr::Bin(BinExpr {
+                op: op!("||"),
+                left,
+                right,
+                ..
+            })
+            | Expr::Bin(BinExpr {
+                op: op!("??"),
+                left,
+                right,
+                ..
+            }) => {
+                self.instrument_logical(left, idx, locations);
+                self.instrument_logical(right, idx, locations);
+            }
+            _ => {
+                locations.push(self.range(e.span()));
+                if self.ignore_hint(e.span().lo) == Some(IgnoreHint::Next) {
+                    locations.last_mut().unwrap().skip = true;
+                    return;
+                }
+
+                e.visit_mut_with(self);
+                let counter = self.counter("b", idx, Some(locations.len() - 1));
+                e.map_with_mut(|e| with_counter(counter, e));
+            }
+        }
+    }
+
+    fn visit_mut_stmt_like<T>(&mut self, stmts: &mut Vec<T>)
+    where
+        T: CountedStmt + VisitMutWith<Self>,
+    {
+        let mut buf = Vec::with_capacity(stmts.len() * 2);
+        let mut is_directive = true;
+
+        for mut stmt in stmts.take() {
+            match stmt.as_stmt() {
+                Some(Stmt::Expr(ExprStmt { expr, .. })) if is_directive => match &**expr {
+                    Expr::Lit(Lit::Str(..)) => {
+                        buf.push(stmt);
+                        continue;
+                    }
+                    _ => is_directive = false,
+                },
+                _ => is_directive = false,
+            }
+
+            if self.ignore_hint(stmt.span().lo) == Some(IgnoreHint::Next) {
+                buf.push(stmt);
+                continue;
+            }
+
+            if let Some(span) = stmt.counted_span() {
+                buf.push(T::from_stmt(self.new_statement(span)));
+            }
+
+            stmt.visit_mut_with(self);
+            buf.push(stmt);
+        }
+
+        *stmts = buf;
+    }
+
+    fn coverage_fn(&self) -> Vec<Stmt> {
+        let path = quote_ident!("path");
+        let hash = quote_ident!("hash");
+        let global = quote_ident!("global");
+        let gcv = quote_ident!("gcv");
+        let coverage_data = quote_ident!("coverageData");
+        let coverage = quote_ident!("coverage");
+        let actual_coverage = quote_ident!("actualCoverage");
+
+        let data = serde_json::to_value(&self.data).expect("failed to serialize coverage data");
+        let file_coverage = Expr::Ident(coverage.clone()).computed_member(path.clone());
+
+        let stmts = vec![
+            var(
+                path.clone(),
+                Expr::Lit(Lit::Str(quote_str!(&*self.data.path))),
+            ),
+            var(
+                hash.clone(),
+                Expr::Lit(Lit::Str(quote_str!(&*self.data.hash))),
+            ),
+            // new Function("return this")()
+            var(
+                global.clone(),
+                Expr::Call(CallExpr {
+                    span: DUMMY_SP,
+                    callee: Expr::New(NewExpr {
+                        span: DUMMY_SP,
+                        callee: Box::new(Expr::Ident(quote_ident!("Function"))),
+                        args: Some(vec![Lit::Str(quote_str!("return this")).as_arg()]),
+                        type_args: Default::default(),
+                    })
+                    .as_callee(),
+                    args: vec![],
+                    type_args: Default::default(),
+                }),
+            ),
+            var(
+                gcv.clone(),
+                Expr::Lit(Lit::Str(quote_str!(&*self.config.coverage_variable))),
+            ),
+            var(coverage_data.clone(), json_to_expr(data)),
+            // global[gcv] || (global[gcv] = {})
+            var(
+                coverage.clone(),
+                Expr::Ident(global.clone())
+                    .computed_member(gcv.clone())
+                    .make_bin(
+                        op!("||"),
+                        Expr::Assign(AssignExpr {
+                            span: DUMMY_SP,
+                            op: op!("="),
+                            left: PatOrExpr::Expr(Box::new(
+                                Expr::Ident(global).computed_member(gcv),
+                            )),
+                            right: Box::new(Expr::Object(ObjectLit {
+                                span: DUMMY_SP,
+                                props: vec![],
+                            })),
+                        })
+                        .wrap_with_paren(),
+                    ),
+            ),
+            // if (!coverage[path] || coverage[path].hash !== hash)
+            Stmt::If(IfStmt {
+                span: DUMMY_SP,
+                test: Box::new(
+                    Expr::Unary(UnaryExpr {
+                        span: DUMMY_SP,
+                        op: op!("!"),
+                        arg: Box::new(file_coverage.clone()),
+                    })
+                    .make_bin(
+                        op!("||"),
+                        file_coverage
+                            .clone()
+                            .make_member(hash.clone())
+                            .make_bin(op!("!=="), hash),
+                    ),
+                ),
+                cons: Box::new(Stmt::Block(BlockStmt {
+                    span: DUMMY_SP,
+                    stmts: vec![Expr::Assign(AssignExpr {
+                        span: DUMMY_SP,
+                        op: op!("="),
+                        left: PatOrExpr::Expr(Box::new(file_coverage.clone())),
+                        right: Box::new(Expr::Ident(coverage_data)),
+                    })
+                    .into_stmt()],
+                })),
+                alt: None,
+            }),
+            var(actual_coverage.clone(), file_coverage),
+            // cov_xxx = function () { return actualCoverage; };
+            Expr::Assign(AssignExpr {
+                span: DUMMY_SP,
+                op: op!("="),
+                left: PatOrExpr::Pat(Box::new(Pat::Ident(self.cov_fn.clone().into()))),
+                right: Box::new(Expr::Fn(FnExpr {
+                    ident: None,
+                    function: function(vec![Stmt::Return(ReturnStmt {
+                        span: DUMMY_SP,
+                        arg: Some(Box::new(Expr::Ident(actual_coverage.clone()))),
+                    })]),
+                })),
+            })
+            .into_stmt(),
+            Stmt::Return(ReturnStmt {
+                span: DUMMY_SP,
+                arg: Some(Box::new(Expr::Ident(actual_coverage))),
+            }),
+        ];
+
+        vec![
+            Stmt::Decl(Decl::Fn(FnDecl {
+                ident: self.cov_fn.clone(),
+                declare: false,
+                function: function(stmts),
+            })),
+            Expr::Call(CallExpr {
+                span: DUMMY_SP,
+                callee: self.cov_fn.clone().as_callee(),
+                args: vec![],
+                type_args: Default::default(),
+            })
+            .into_stmt(),
+        ]
+    }
+}
+
+impl<C> VisitMut for Coverage<C>
+where
+    C: Comments,
+{
+    noop_visit_mut_type!();
+
+    fn visit_mut_module(&mut self, n: &mut Module) {
+        if self.init(n.span) {
+            return;
+        }
+
+        n.visit_mut_children_with(self);
+
+        let stmts = self.coverage_fn();
+        prepend_stmts(&mut n.body, stmts.into_iter().map(ModuleItem::Stmt));
+    }
+
+    fn visit_mut_script(&mut self, n: &mut Script) {
+        if self.init(n.span) {
+            return;
+        }
+
+        n.visit_mut_children_with(self);
+
+        let stmts = self.coverage_fn();
+        prepend_stmts(&mut n.body, stmts.into_iter());
+    }
+
+    fn visit_mut_module_items(&mut self, n: &mut Vec<ModuleItem>) {
+        self.visit_mut_stmt_like(n)
+    }
+
+    fn visit_mut_stmts(&mut self, n: &mut Vec<Stmt>) {
+        self.visit_mut_stmt_like(n)
+    }
+
+    fn visit_mut_expr(&mut self, e: &mut Expr) {
+        if self.ignore_hint(e.span().lo) == Some(IgnoreHint::Next) {
+            return;
+        }
+
+        match e {
+            Expr::Bin(BinExpr {
+                span,
+                op: op!("&&"),
+                ..
+            })
+            | Expr::Bin(BinExpr {
+                span,
+                op: op!("||"),
+                ..
+            })
+            | Expr::Bin(BinExpr {
+                span,
+                op: op!("??"),
+                ..
+            }) => {
+                let idx = self.new_branch("binary-expr", *span, vec![]);
+                let mut locations = vec![];
+                self.instrument_logical(e, idx, &mut locations);
+
+                self.data.b.insert(idx, vec![0; locations.len()]);
+                self.data.branch_map.get_mut(&idx).unwrap().locations = locations;
+            }
+            _ => e.visit_mut_children_with(self),
+        }
+    }
+
+    fn visit_mut_cond_expr(&mut self, n: &mut CondExpr) {
+        n.test.visit_mut_with(self);
+
+        let locations = vec![self.range(n.cons.span()), self.range(n.alt.span())];
+        let idx = self.new_branch("cond-expr", n.span, locations);
+
+        for (i, e) in [&mut n.cons, &mut n.alt].iter_mut().enumerate() {
+            if self.ignore_hint(e.span().lo) == Some(IgnoreHint::Next) {
+                self.data.branch_map.get_mut(&idx).unwrap().locations[i].skip = true;
+                continue;
+            }
+
+            e.visit_mut_with(self);
+            let counter = self.counter("b", idx, Some(i));
+            e.map_with_mut(|e| Box::new(with_counter(counter, *e)));
+        }
+    }
+
+    fn visit_mut_if_stmt(&mut self, n: &mut IfStmt) {
+        let hint = self.ignore_hint(n.span.lo);
+
+        n.test.visit_mut_with(self);
+
+        let mut locations = vec![self.range(n.cons.span()), self.range(n.span)];
+        if let Some(alt) = &n.alt {
+            locations[1] = self.range(alt.span());
+        }
+        locations[0].skip = hint == Some(IgnoreHint::If);
+        locations[1].skip = hint == Some(IgnoreHint::Else);
+        let idx = self.new_branch("if", n.span, locations);
+
+        if hint != Some(IgnoreHint::If) {
+            let counter = self.counter("b", idx, Some(0)).into_stmt();
+            ensure_block(&mut n.cons);
+            n.cons.visit_mut_with(self);
+            if let Stmt::Block(b) = &mut *n.cons {
+                b.stmts.insert(0, counter);
+            }
+        }
+
+        if hint != Some(IgnoreHint::Else) {
+            let counter = self.counter("b", idx, Some(1)).into_stmt();
+            let alt = n.alt.get_or_insert_with(|| {
+                Box::new(Stmt::Block(BlockStmt {
+                    span: DUMMY_SP,
+                    stmts: vec![],
+                }))
+            });
+            ensure_block(alt);
+            alt.visit_mut_with(self);
+            if let Stmt::Block(b) = &mut **alt {
+                b.stmts.insert(0, counter);
+            }
+        }
+    }
+
+    fn visit_mut_switch_stmt(&mut self, n: &mut SwitchStmt) {
+        n.discriminant.visit_mut_with(self);
+
+        let locations = n.cases.iter().map(|case| self.range(case.span)).collect();
+        let idx = self.new_branch("switch", n.span, locations);
+
+        for (i, case) in n.cases.iter_mut().enumerate() {
+            if self.ignore_hint(case.span.lo) == Some(IgnoreHint::Next) {
+                self.data.branch_map.get_mut(&idx).unwrap().locations[i].skip = true;
+                continue;
+            }
+
+            case.visit_mut_with(self);
+            case.cons
+                .insert(0, self.counter("b", idx, Some(i)).into_stmt());
+        }
+    }
+
+    fn visit_mut_for_stmt(&mut self, n: &mut ForStmt) {
+        ensure_block(&mut n.body);
+        n.visit_mut_children_with(self);
+    }
+
+    fn visit_mut_for_in_stmt(&mut self, n: &mut ForInStmt) {
+        ensure_block(&mut n.body);
+        n.visit_mut_children_with(self);
+    }
+
+    fn visit_mut_for_of_stmt(&mut self, n: &mut ForOfStmt) {
+        ensure_block(&mut n.body);
+        n.visit_mut_children_with(self);
+    }
+
+    fn visit_mut_while_stmt(&mut self, n: &mut WhileStmt) {
+        ensure_block(&mut n.body);
+        n.visit_mut_children_with(self);
+    }
+
+    fn visit_mut_do_while_stmt(&mut self, n: &mut DoWhileStmt) {
+        ensure_block(&mut n.body);
+        n.visit_mut_children_with(self);
+    }
+
+    fn visit_mut_fn_decl(&mut self, n: &mut FnDecl) {
+        self.fn_name = Some((n.ident.sym.clone(), n.ident.span));
+        n.function.visit_mut_with(self);
+    }
+
+    fn visit_mut_fn_expr(&mut self, n: &mut FnExpr) {
+        self.fn_name = n.ident.as_ref().map(|i| (i.sym.clone(), i.span));
+        n.function.visit_mut_with(self);
+    }
+
+    fn visit_mut_function(&mut self, n: &mut Function) {
+        let name = self.fn_name.take();
+        let counter = self.new_function(name, n.span);
+
+        n.visit_mut_children_with(self);
+
+        if let Some(body) = &mut n.body {
+            prepend(&mut body.stmts, counter);
+        }
+    }
+
+    fn visit_mut_arrow_expr(&mut self, n: &mut ArrowExpr) {
+        let counter = self.new_function(None, n.span);
+
+        n.params.visit_mut_with(self);
+
+        let stmts = match &mut n.body {
+            BlockStmtOrExpr::BlockStmt(body) => {
+                body.visit_mut_with(self);
+                prepend(&mut body.stmts, counter);
+                return;
+            }
+            BlockStmtOrExpr::Expr(e) => {
+                let stmt = self.new_statement(e.span());
+                e.visit_mut_with(self);
+                vec![
+                    counter,
+                    stmt,
+                    Stmt::Return(ReturnStmt {
+                        span: DUMMY_SP,
+                        arg: Some(e.take()),
+                    }),
+                ]
+            }
+        };
+
+        n.body = BlockStmtOrExpr::BlockStmt(BlockStmt {
+            span: DUMMY_SP,
+            stmts,
+        });
+    }
+
+    fn visit_mut_class_member(&mut self, n: &mut ClassMember) {
+        if self.ignore_hint(n.span().lo) == Some(IgnoreHint::Next) {
+            return;
+        }
+
+        n.visit_mut_children_with(self);
+    }
+
+    fn visit_mut_class_method(&mut self, n: &mut ClassMethod) {
+        n.key.visit_mut_with(self);
+        self.fn_name = prop_name(&n.key);
+        n.function.visit_mut_with(self);
+    }
+
+    fn visit_mut_private_method(&mut self, n: &mut PrivateMethod) {
+        self.fn_name = Some((n.key.id.sym.clone(), n.key.span));
+        n.function.visit_mut_with(self);
+    }
+
+    fn visit_mut_constructor(&mut self, n: &mut Constructor) {
+        n.params.visit_mut_with(self);
+        self.instrument_body(
+            Some(("constructor".into(), n.key.span())),
+            n.span,
+            &mut n.body,
+        );
+    }
+
+    fn visit_mut_prop_or_spread(&mut self, n: &mut PropOrSpread) {
+        if self.ignore_hint(n.span().lo) == Some(IgnoreHint::Next) {
+            return;
+        }
+
+        n.visit_mut_children_with(self);
+    }
+
+    fn visit_mut_method_prop(&mut self, n: &mut MethodProp) {
+        n.key.visit_mut_with(self);
+        self.fn_name = prop_name(&n.key);
+        n.function.visit_mut_with(self);
+    }
+
+    fn visit_mut_getter_prop(&mut self, n: &mut GetterProp) {
+        n.key.visit_mut_with(self);
+        self.instrument_body(prop_name(&n.key), n.span, &mut n.body);
+    }
+
+    fn visit_mut_setter_prop(&mut self, n: &mut SetterProp) {
+        n.key.visit_mut_with(self);
+        n.param.visit_mut_with(self);
+        self.instrument_body(prop_name(&n.key), n.span, &mut n.body);
+    }
+}
+
+/// Statements and module items, which may get a statement counter.
+trait CountedStmt: StmtLike + Spanned {
+    /// Returns the range of the statement counter, if any.
+    fn counted_span(&self) -> Option<Span>;
+}
+
+impl CountedStmt for Stmt {
+    fn counted_span(&self) -> Option<Span> {
+        match self {
+            // Function declarations are counted when they are called.
+            Stmt::Decl(Decl::Fn(..)) | Stmt::Empty(..) => None,
+            _ => Some(self.span()),
+        }
+    }
+}
+
+impl CountedStmt for ModuleItem {
+    fn counted_span(&self) -> Option<Span> {
+        match self {
+            ModuleItem::Stmt(s) => s.counted_span(),
+            ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(ExportDecl {
+                decl: Decl::Fn(..),
+                ..
+            })) => None,
+            // `export const a = 1` is counted like `const a = 1`.
+            ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(ExportDecl { decl, .. })) => {
+                Some(decl.span())
+            }
+            ModuleItem::ModuleDecl(ModuleDecl::ExportDefaultExpr(e)) => Some(e.expr.span()),
+            ModuleItem::ModuleDecl(ModuleDecl::ExportDefaultDecl(ExportDefaultDecl {
+                decl: DefaultDecl::Class(c),
+                ..
+            })) => Some(c.class.span),
+            ModuleItem::ModuleDecl(..) => None,
+        }
+    }
+}
+
+fn prop_name(key: &PropName) -> Option<(JsWord, Span)> {
+    match key {
+        PropName::Ident(i) => Some((i.sym.clone(), i.span)),
+        PropName::Str(s) => Some((s.value.clone(), s.span)),
+        _ => None,
+    }
+}
+
+fn ensure_block(s: &mut Box<Stmt>) {
+    match **s {
+        Stmt::Block(..) => {}
+        _ => s.map_with_mut(|s| {
+            Box::new(Stmt::Block(BlockStmt {
+                span: DUMMY_SP,
+                stmts: vec![*s],
+            }))
+        }),
+    }
+}
+
+/// `(counter, e)`
+fn with_counter(counter: Expr, e: Expr) -> Expr {
+    Expr::Seq(SeqExpr {
+        span: DUMMY_SP,
+        exprs: vec![Box::new(counter), Box::new(e)],
+    })
+    .wrap_with_paren()
+}
+
+fn num(value: usize) -> Expr {
+    Expr::Lit(Lit::Num(Number {
+        span: DUMMY_SP,
+        value: value as f64,
+    }))
+}
+
+fn var(name: Ident, init: Expr) -> Stmt {
+    Stmt::Decl(Decl::Var(VarDecl {
+        span: DUMMY_SP,
+        kind: VarDeclKind::Var,
+        declare: false,
+        decls: vec![VarDeclarator {
+            span: DUMMY_SP,
+            name: Pat::Ident(name.into()),
+            init: Some(Box::new(init)),
+            definite: false,
+        }],
+    }))
+}
+
+fn function(stmts: Vec<Stmt>) -> Function {
+    Function {
+        params: vec![],
+        decorators: vec![],
+        span: DUMMY_SP,
+        body: Some(BlockStmt {
+            span: DUMMY_SP,
+            stmts,
+        }),
+        is_generator: false,
+        is_async: false,
+        type_params: Default::default(),
+        return_type: Default::default(),
+    }
+}
+
+fn json_to_expr(v: Value) -> Expr {
+    match v {
+        Value::Null => Expr::Lit(Lit::Null(Null { span: DUMMY_SP })),
+        Value::Bool(value) => Expr::Lit(Lit::Bool(Bool {
+            span: DUMMY_SP,
+            value,
+        })),
+        Value::Number(n) => Expr::Lit(Lit::Num(Number {
+            span: DUMMY_SP,
+            value: n.as_f64().unwrap_or_default(),
+        })),
+        Value::String(s) => Expr::Lit(Lit::Str(quote_str!(s))),
+        Value::Array(elems) => Expr::Array(ArrayLit {
+            span: DUMMY_SP,
+            elems: elems
+                .into_iter()
+                .map(|v| Some(json_to_expr(v).as_arg()))
+                .collect(),
+        }),
+        Value::Object(props) => Expr::Object(ObjectLit {
+            span: DUMMY_SP,
+            props: props
+                .into_iter()
+                .map(|(key, value)| {
+                    PropOrSpread::Prop(Box::new(Prop::KeyValue(KeyValueProp {
+                        key: PropName::Str(quote_str!(key)),
+                        value: Box::new(json_to_expr(value)),
+                    })))
+                })
+                .collect(),
+        }),
+    }
+}
@@ -1,2 +1,3 @@
+pub mod coverage;
 pub mod jest;
 mod util;
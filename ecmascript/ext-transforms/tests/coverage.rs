@@ -0,0 +1,170 @@
+use serde_json::Value;
+use std::{fs::read_to_string, path::PathBuf};
+use swc_common::{comments::SingleThreadedComments, sync::Lrc, FileName, SourceMap, DUMMY_SP};
+use swc_ecma_ast::*;
+use swc_ecma_codegen::{text_writer::JsWriter, Emitter};
+use swc_ecma_ext_transforms::coverage::{coverage, Config};
+use swc_ecma_parser::{lexer::Lexer, Parser, StringInput, Syntax};
+use swc_ecma_visit::{FoldWith, Node, Visit, VisitWith};
+use testing::assert_eq;
+
+/// Compares the instrumented code with `output.js` and the coverage data with
+/// `coverage.json`. `input.js.map` is used as the input source map if it
+/// exists.
+///
+/// The name of the coverage function depends on the hash of the file, so it's
+/// renamed to `cov`. `path` and `hash` of the coverage data are not compared.
+#[testing::fixture("coverage/**/input.js")]
+fn fixture(input: PathBuf) {
+    let dir = input.parent().unwrap().to_path_buf();
+    let input_source_map = read_to_string(dir.join("input.js.map")).ok().map(|s| {
+        sourcemap::SourceMap::from_slice(s.as_bytes()).expect("failed to parse input.js.map")
+    });
+
+    testing::run_test2(false, |cm, handler| {
+        let fm = cm.load_file(&input).expect("failed to load input.js");
+        let comments = SingleThreadedComments::default();
+        let lexer = Lexer::new(
+            Syntax::default(),
+            Default::default(),
+            StringInput::from(&*fm),
+            Some(&comments),
+        );
+        let module = Parser::new_from(lexer)
+            .parse_module()
+            .map_err(|err| err.into_diagnostic(&handler).emit())?;
+
+        let mut module = module.fold_with(&mut coverage(
+            cm.clone(),
+            Some(&comments),
+            input_source_map,
+            Config::default(),
+        ));
+
+        let cov_fn = module.body.iter().position(|item| match item {
+            ModuleItem::Stmt(Stmt::Decl(Decl::Fn(f))) => f.ident.sym.starts_with("cov_"),
+            _ => false,
+        });
+
+        let mut output = match cov_fn {
+            Some(idx) => {
+                // The function and the call to it.
+                let mut removed = module.body.drain(idx..idx + 2);
+                let cov_fn = match removed.next() {
+                    Some(ModuleItem::Stmt(Stmt::Decl(Decl::Fn(f)))) => f,
+                    _ => unreachable!(),
+                };
+                drop(removed);
+
+                let mut data = coverage_data(&cov_fn);
+                let data_obj = data.as_object_mut().unwrap();
+                data_obj.remove("path");
+                data_obj.remove("hash");
+
+                let expected = read_to_string(dir.join("coverage.json"))
+                    .expect("failed to read coverage.json");
+                let expected: Value =
+                    serde_json::from_str(&expected).expect("failed to parse coverage.json");
+                assert_eq!(data, expected);
+
+                print(cm.clone(), &module).replace(&*cov_fn.ident.sym, "cov")
+            }
+            None => {
+                assert!(
+                    !dir.join("coverage.json").exists(),
+                    "the file is not instrumented"
+                );
+                print(cm.clone(), &module)
+            }
+        };
+        output = output.trim().to_string();
+
+        let expected = {
+            let src = read_to_string(dir.join("output.js")).expect("failed to read output.js");
+            let fm = cm.new_source_file(FileName::Anon, src);
+            let lexer = Lexer::new(
+                Syntax::default(),
+                Default::default(),
+                StringInput::from(&*fm),
+                None,
+            );
+            let expected = Parser::new_from(lexer)
+                .parse_module()
+                .map_err(|err| err.into_diagnostic(&handler).emit())?;
+            print(cm.clone(), &expected).trim().to_string()
+        };
+
+        assert_eq!(output, expected);
+
+        Ok(())
+    })
+    .unwrap();
+}
+
+/// Returns the initializer of `coverageData` in the coverage function.
+fn coverage_data(cov_fn: &FnDecl) -> Value {
+    struct Finder(Option<Value>);
+
+    impl Visit for Finder {
+        fn visit_var_declarator(&mut self, n: &VarDeclarator, _: &dyn Node) {
+            match (&n.name, &n.init) {
+                (Pat::Ident(name), Some(init)) if &*name.id.sym == "coverageData" => {
+                    self.0 = Some(expr_to_json(init));
+                }
+                _ => {}
+            }
+        }
+    }
+
+    let mut v = Finder(None);
+    cov_fn.visit_with(&Invalid { span: DUMMY_SP }, &mut v);
+    v.0.expect("failed to find coverageData")
+}
+
+fn expr_to_json(e: &Expr) -> Value {
+    match e {
+        Expr::Lit(Lit::Null(..)) => Value::Null,
+        Expr::Lit(Lit::Bool(b)) => Value::Bool(b.value),
+        Expr::Lit(Lit::Num(n)) => Value::from(n.value as u64),
+        Expr::Lit(Lit::Str(s)) => Value::String(s.value.to_string()),
+        Expr::Array(a) => Value::Array(
+            a.elems
+                .iter()
+                .map(|elem| expr_to_json(&elem.as_ref().unwrap().expr))
+                .collect(),
+        ),
+        Expr::Object(o) => Value::Object(
+            o.props
+                .iter()
+                .map(|prop| match prop {
+                    PropOrSpread::Prop(prop) => match &**prop {
+                        Prop::KeyValue(KeyValueProp {
+                            key: PropName::Str(key),
+                            value,
+                        }) => (key.value.to_string(), expr_to_json(value)),
+                        _ => unreachable!("unexpected property: {:?}", prop),
+                    },
+                    _ => unreachable!("unexpected property: {:?}", prop),
+                })
+                .collect(),
+        ),
+        _ => unreachable!("unexpected expression: {:?}", e),
+    }
+}
+
+fn print(cm: Lrc<SourceMap>, module: &Module) -> String {
+    let mut buf = vec![];
+
+    {
+        let mut emitter = Emitter {
+            cfg: Default::default(),
+            cm: cm.clone(),
+            comments: None,
+            wr: Box::new(JsWriter::new(cm, "\n", &mut buf, None)),
+        };
+
+        emitter.emit_module(module).unwrap();
+    }
+
+    String::from_utf8(buf).unwrap()
+}
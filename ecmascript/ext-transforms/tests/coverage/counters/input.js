@@ -0,0 +1,8 @@
+const a = 1;
+function f(b) {
+  if (b) {
+    return a || b;
+  }
+  return b ? 1 : 2;
+}
+f(a);
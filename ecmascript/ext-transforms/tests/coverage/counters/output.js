@@ -0,0 +1,17 @@
+cov().s[0]++;
+const a = 1;
+function f(b) {
+    cov().f[0]++;
+    cov().s[1]++;
+    if (b) {
+        cov().b[0][0]++;
+        cov().s[2]++;
+        return (cov().b[1][0]++, a) || (cov().b[1][1]++, b);
+    } else {
+        cov().b[0][1]++;
+    }
+    cov().s[3]++;
+    return b ? (cov().b[2][0]++, 1) : (cov().b[2][1]++, 2);
+}
+cov().s[4]++;
+f(a);
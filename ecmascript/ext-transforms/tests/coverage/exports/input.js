@@ -0,0 +1,6 @@
+export const a = 1;
+export function f() {
+  return a;
+}
+export default a;
+export { a as b };
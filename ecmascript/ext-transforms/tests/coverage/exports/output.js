@@ -0,0 +1,10 @@
+cov().s[0]++;
+export const a = 1;
+export function f() {
+    cov().f[0]++;
+    cov().s[1]++;
+    return a;
+}
+cov().s[2]++;
+export default a;
+export { a as b };
@@ -0,0 +1,13 @@
+/* istanbul ignore next */
+const a = 1;
+/* istanbul ignore if */
+if (a) {
+  a;
+}
+/* istanbul ignore else */
+if (a) {
+  a;
+} else {
+  a;
+}
+const b = a || /* istanbul ignore next */ a;
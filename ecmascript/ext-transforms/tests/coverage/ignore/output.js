@@ -0,0 +1,17 @@
+const a = 1;
+cov().s[0]++;
+if (a) {
+    a;
+} else {
+    cov().b[0][1]++;
+}
+cov().s[1]++;
+if (a) {
+    cov().b[1][0]++;
+    cov().s[2]++;
+    a;
+} else {
+    a;
+}
+cov().s[3]++;
+const b = (cov().b[2][0]++, a) || a;
@@ -0,0 +1,3 @@
+/* istanbul ignore file */
+const a = 1;
+a++;
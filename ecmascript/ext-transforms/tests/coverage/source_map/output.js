@@ -0,0 +1,4 @@
+cov().s[0]++;
+var a = 1;
+cov().s[1]++;
+a++;
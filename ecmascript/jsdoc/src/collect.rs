@@ -0,0 +1,285 @@
+use crate::{ast::JsDoc, parse};
+use serde::Serialize;
+use swc_atoms::JsWord;
+use swc_common::{
+    comments::{CommentKind, Comments, CommentsExt},
+    BytePos, Span,
+};
+use swc_ecma_ast::*;
+
+/// Kind of a [DocumentedItem].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ItemKind {
+    Function,
+    Class,
+    Variable,
+    Interface,
+    TypeAlias,
+    Enum,
+    Constructor,
+    Method,
+    Getter,
+    Setter,
+    Property,
+}
+
+/// A declaration with a jsdoc comment.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DocumentedItem {
+    pub kind: ItemKind,
+    /// Name path of the item, like `foo`, `Foo#bar` for an instance member
+    /// and `Foo.bar` for a static member.
+    ///
+    /// Default exports without a name are named `default`.
+    pub name: JsWord,
+    /// Span of the declaration.
+    pub span: Span,
+    pub doc: JsDoc,
+}
+
+/// Collects jsdoc comments of top-level declarations and class members of
+/// `module`.
+///
+/// A jsdoc comment is the last `/** */` comment right before a declaration.
+/// Declarations without one are not included.
+pub fn collect_docs<C>(module: &Module, comments: C) -> Vec<DocumentedItem>
+where
+    C: Comments,
+{
+    let mut c = Collector {
+        comments,
+        items: vec![],
+    };
+
+    for item in &module.body {
+        match item {
+            ModuleItem::ModuleDecl(decl) => c.module_decl(decl),
+            ModuleItem::Stmt(stmt) => c.stmt(stmt),
+        }
+    }
+
+    c.items
+}
+
+/// [collect_docs] for scripts.
+pub fn collect_script_docs<C>(script: &Script, comments: C) -> Vec<DocumentedItem>
+where
+    C: Comments,
+{
+    let mut c = Collector {
+        comments,
+        items: vec![],
+    };
+
+    for stmt in &script.body {
+        c.stmt(stmt);
+    }
+
+    c.items
+}
+
+struct Collector<C>
+where
+    C: Comments,
+{
+    comments: C,
+    items: Vec<DocumentedItem>,
+}
+
+impl<C> Collector<C>
+where
+    C: Comments,
+{
+    fn doc_at(&self, pos: BytePos) -> Option<JsDoc> {
+        self.comments.with_leading(pos, |comments| {
+            let cmt = comments
+                .iter()
+                .rev()
+                .find(|cmt| cmt.kind == CommentKind::Block && cmt.text.starts_with('*'))?;
+
+            parse(cmt.into()).ok().map(|(_, doc)| doc)
+        })
+    }
+
+    fn add(&mut self, kind: ItemKind, name: JsWord, span: Span, pos: BytePos) {
+        if let Some(doc) = self.doc_at(pos) {
+            self.items.push(DocumentedItem {
+                kind,
+                name,
+                span,
+                doc,
+            });
+        }
+    }
+
+    fn module_decl(&mut self, decl: &ModuleDecl) {
+        match decl {
+            ModuleDecl::ExportDecl(export) => self.decl(&export.decl, Some(export.span.lo)),
+            ModuleDecl::ExportDefaultDecl(export) => match &export.decl {
+                DefaultDecl::Class(class) => {
+                    let name = class
+                        .ident
+                        .as_ref()
+                        .map(|i| i.sym.clone())
+                        .unwrap_or_else(|| "default".into());
+                    self.class(name, &class.class, Some(export.span.lo))
+                }
+                DefaultDecl::Fn(f) => {
+                    let name = f
+                        .ident
+                        .as_ref()
+                        .map(|i| i.sym.clone())
+                        .unwrap_or_else(|| "default".into());
+                    self.add(ItemKind::Function, name, export.span, export.span.lo)
+                }
+                DefaultDecl::TsInterfaceDecl(i) => self.add(
+                    ItemKind::Interface,
+                    i.id.sym.clone(),
+                    i.span,
+                    export.span.lo,
+                ),
+            },
+            _ => {}
+        }
+    }
+
+    fn stmt(&mut self, stmt: &Stmt) {
+        if let Stmt::Decl(decl) = stmt {
+            self.decl(decl, None)
+        }
+    }
+
+    /// `export_pos` is the start of `export` keyword, if the declaration is
+    /// exported.
+    fn decl(&mut self, decl: &Decl, export_pos: Option<BytePos>) {
+        match decl {
+            Decl::Class(c) => self.class(c.ident.sym.clone(), &c.class, export_pos),
+            Decl::Fn(f) => self.add(
+                ItemKind::Function,
+                f.ident.sym.clone(),
+                f.function.span,
+                export_pos.unwrap_or(f.function.span.lo),
+            ),
+            Decl::Var(var) => {
+                let pos = export_pos.unwrap_or(var.span.lo);
+                for decl in &var.decls {
+                    if let Pat::Ident(i) = &decl.name {
+                        self.add(ItemKind::Variable, i.id.sym.clone(), decl.span, pos);
+                    }
+                }
+            }
+            Decl::TsInterface(i) => self.add(
+                ItemKind::Interface,
+                i.id.sym.clone(),
+                i.span,
+                export_pos.unwrap_or(i.span.lo),
+            ),
+            Decl::TsTypeAlias(a) => self.add(
+                ItemKind::TypeAlias,
+                a.id.sym.clone(),
+                a.span,
+                export_pos.unwrap_or(a.span.lo),
+            ),
+            Decl::TsEnum(e) => self.add(
+                ItemKind::Enum,
+                e.id.sym.clone(),
+                e.span,
+                export_pos.unwrap_or(e.span.lo),
+            ),
+            Decl::TsModule(_) => {}
+        }
+    }
+
+    fn class(&mut self, name: JsWord, class: &Class, export_pos: Option<BytePos>) {
+        let pos = export_pos.unwrap_or_else(|| start_of(class.span, &class.decorators));
+        self.add(ItemKind::Class, name.clone(), class.span, pos);
+
+        for member in &class.body {
+            let (kind, key, is_static, span, decorators) = match member {
+                ClassMember::Constructor(c) => (
+                    ItemKind::Constructor,
+                    Some("constructor".into()),
+                    false,
+                    c.span,
+                    &[][..],
+                ),
+                ClassMember::Method(m) => (
+                    method_kind(m.kind),
+                    prop_name(&m.key),
+                    m.is_static,
+                    m.span,
+                    &m.function.decorators[..],
+                ),
+                ClassMember::PrivateMethod(m) => (
+                    method_kind(m.kind),
+                    Some(format!("#{}", m.key.id.sym).into()),
+                    m.is_static,
+                    m.span,
+                    &m.function.decorators[..],
+                ),
+                ClassMember::ClassProp(p) => (
+                    ItemKind::Property,
+                    match &*p.key {
+                        Expr::Ident(i) if !p.computed => Some(i.sym.clone()),
+                        Expr::Lit(Lit::Str(s)) => Some(s.value.clone()),
+                        _ => None,
+                    },
+                    p.is_static,
+                    p.span,
+                    &p.decorators[..],
+                ),
+                ClassMember::PrivateProp(p) => (
+                    ItemKind::Property,
+                    Some(format!("#{}", p.key.id.sym).into()),
+                    p.is_static,
+                    p.span,
+                    &p.decorators[..],
+                ),
+                ClassMember::TsIndexSignature(_) | ClassMember::Empty(_) => continue,
+            };
+
+            // Members with computed keys cannot be referenced by a name path.
+            let key = match key {
+                Some(key) => key,
+                None => continue,
+            };
+            let sep = if is_static { '.' } else { '#' };
+
+            self.add(
+                kind,
+                format!("{}{}{}", name, sep, key).into(),
+                span,
+                start_of(span, decorators),
+            );
+        }
+    }
+}
+
+/// Comments before decorators are attached to the first decorator.
+fn start_of(span: Span, decorators: &[Decorator]) -> BytePos {
+    decorators
+        .iter()
+        .map(|d| d.span.lo)
+        .chain(Some(span.lo))
+        .min()
+        .unwrap()
+}
+
+fn method_kind(kind: MethodKind) -> ItemKind {
+    match kind {
+        MethodKind::Method => ItemKind::Method,
+        MethodKind::Getter => ItemKind::Getter,
+        MethodKind::Setter => ItemKind::Setter,
+    }
+}
+
+fn prop_name(key: &PropName) -> Option<JsWord> {
+    match key {
+        PropName::Ident(i) => Some(i.sym.clone()),
+        PropName::Str(s) => Some(s.value.clone()),
+        PropName::Num(n) => Some(n.value.to_string().into()),
+        PropName::Computed(_) | PropName::BigInt(_) => None,
+    }
+}
@@ -19,6 +19,12 @@ impl<'a, 'b> From<&'a Comment> for Input<'a> {
     }
 }
 
+impl<'a> From<&'a Text> for Input<'a> {
+    fn from(t: &'a Text) -> Self {
+        Self::new(t.span.lo, t.span.hi, &t.value)
+    }
+}
+
 impl<'i> Input<'i> {
     pub const fn empty() -> Self {
         Self::new(BytePos(0), BytePos(0), "")
@@ -1,4 +1,8 @@
-pub use self::input::Input;
+pub use self::{
+    collect::{collect_docs, collect_script_docs, DocumentedItem, ItemKind},
+    input::Input,
+    ts_type::{parse_ts_type, parse_type_expr},
+};
 use crate::ast::*;
 use nom::{
     bytes::complete::{tag, take_while},
@@ -8,7 +12,9 @@ use nom::{
 use swc_common::{Span, Spanned, SyntaxContext};
 
 pub mod ast;
+mod collect;
 mod input;
+mod ts_type;
 
 pub fn parse(i: Input) -> IResult<Input, JsDoc> {
     let i = skip(i);
@@ -0,0 +1,634 @@
+//! Parser for jsdoc (closure compiler) type expressions, which produces
+//! typescript types.
+//!
+//! See https://github.com/google/closure-compiler/wiki/Types-in-the-Closure-Type-System
+
+use crate::Input;
+use nom::{error::ErrorKind, IResult, Slice};
+use swc_atoms::{js_word, JsWord};
+use swc_common::{BytePos, Span, Spanned};
+use swc_ecma_ast::*;
+
+/// Parses a type expression surrounded by braces, like `{Array<string>|null}`
+/// in `@param {Array<string>|null} x`.
+///
+/// Optional types (`{string=}`) are converted to a union with `undefined`,
+/// and variadic types (`{...number}`) to an array type.
+pub fn parse_type_expr(i: Input) -> IResult<Input, Box<TsType>> {
+    let i = expect(i, "{")?;
+    let (i, ty) = parse_ts_type(i)?;
+    let i = expect(i, "}")?;
+    Ok((i, ty))
+}
+
+/// Parses a type expression without braces.
+pub fn parse_ts_type(i: Input) -> IResult<Input, Box<TsType>> {
+    let lo = pos(i);
+    let (i, ty) = union(i)?;
+
+    match eat(i, "=") {
+        Some(i) => {
+            let span = span(lo, i);
+            Ok((
+                i,
+                union_of(
+                    span,
+                    vec![ty, keyword(span, TsKeywordTypeKind::TsUndefinedKeyword)],
+                ),
+            ))
+        }
+        None => Ok((i, ty)),
+    }
+}
+
+fn union(i: Input) -> IResult<Input, Box<TsType>> {
+    let lo = pos(i);
+    let (mut i, first) = prefix(i)?;
+    let mut types = vec![first];
+
+    while let Some(rest) = eat(i, "|") {
+        let (rest, ty) = prefix(rest)?;
+        types.push(ty);
+        i = rest;
+    }
+
+    if types.len() == 1 {
+        return Ok((i, types.pop().unwrap()));
+    }
+
+    Ok((i, union_of(span(lo, i), types)))
+}
+
+/// `?T`, `!T`, `...T` and postfix operators.
+fn prefix(i: Input) -> IResult<Input, Box<TsType>> {
+    let i = skip_trivia(i);
+    let lo = pos(i);
+
+    if let Some(rest) = eat(i, "...") {
+        let (i, ty) = prefix(rest)?;
+        return Ok((i, array_of(span(lo, i), ty)));
+    }
+
+    if let Some(rest) = eat(i, "!") {
+        return prefix(rest);
+    }
+
+    if let Some(rest) = eat(i, "?") {
+        // `?` alone is the unknown type.
+        if !starts_type(rest) {
+            return postfix(
+                rest,
+                lo,
+                keyword(span(lo, rest), TsKeywordTypeKind::TsAnyKeyword),
+            );
+        }
+
+        let (i, ty) = prefix(rest)?;
+        return Ok((i, nullable(span(lo, i), ty)));
+    }
+
+    let (i, ty) = primary(i)?;
+    postfix(i, lo, ty)
+}
+
+/// `T[]`, `T?` and `T!`
+fn postfix(mut i: Input, lo: BytePos, mut ty: Box<TsType>) -> IResult<Input, Box<TsType>> {
+    loop {
+        if let Some(rest) = eat(i, "[]") {
+            i = rest;
+            ty = array_of(span(lo, i), ty);
+        } else if let Some(rest) = eat(i, "?") {
+            i = rest;
+            ty = nullable(span(lo, i), ty);
+        } else if let Some(rest) = eat(i, "!") {
+            i = rest;
+        } else {
+            return Ok((i, ty));
+        }
+    }
+}
+
+fn primary(i: Input) -> IResult<Input, Box<TsType>> {
+    let i = skip_trivia(i);
+    let lo = pos(i);
+
+    if let Some(rest) = eat(i, "*") {
+        return Ok((
+            rest,
+            keyword(span(lo, rest), TsKeywordTypeKind::TsAnyKeyword),
+        ));
+    }
+
+    if let Some(rest) = eat(i, "(") {
+        let (rest, ty) = union(rest)?;
+        let rest = expect(rest, ")")?;
+        return Ok((
+            rest,
+            Box::new(TsType::TsParenthesizedType(TsParenthesizedType {
+                span: span(lo, rest),
+                type_ann: ty,
+            })),
+        ));
+    }
+
+    if let Some(rest) = eat(i, "{") {
+        return record(rest, lo);
+    }
+
+    if i.starts_with('\'') || i.starts_with('"') {
+        return string_lit(i);
+    }
+
+    if i.starts_with('-') || i.starts_with(|c: char| c.is_ascii_digit()) {
+        return number_lit(i);
+    }
+
+    let (rest, first) = ident(i)?;
+    if &*first.sym == "function" && eat(rest, "(").is_some() {
+        return function(rest, lo);
+    }
+
+    // Name path, like `foo.Bar`
+    let mut name = TsEntityName::Ident(first);
+    let mut i = rest;
+    while let Some(rest) = eat(i, ".") {
+        // `Array.<string>`
+        if eat(rest, "<").is_some() {
+            i = rest;
+            break;
+        }
+
+        let (rest, right) = ident(rest)?;
+        name = TsEntityName::TsQualifiedName(Box::new(TsQualifiedName { left: name, right }));
+        i = rest;
+    }
+
+    let (i, type_params) = match eat(i, "<") {
+        Some(rest) => {
+            let (rest, params) = type_args(rest, lo)?;
+            (rest, Some(params))
+        }
+        None => (i, None),
+    };
+
+    let span = span(lo, i);
+    let ty = match (name, type_params) {
+        (TsEntityName::Ident(name), None) => match keyword_kind(&name.sym) {
+            Some(kind) => keyword(span, kind),
+            None => match &*name.sym {
+                "true" | "false" => Box::new(TsType::TsLitType(TsLitType {
+                    span,
+                    lit: TsLit::Bool(Bool {
+                        span,
+                        value: &*name.sym == "true",
+                    }),
+                })),
+                _ => type_ref(span, TsEntityName::Ident(name), None),
+            },
+        },
+        // `Object<K, V>` is a map.
+        (TsEntityName::Ident(name), Some(type_params))
+            if &*name.sym == "Object" && type_params.params.len() == 2 =>
+        {
+            type_ref(
+                span,
+                TsEntityName::Ident(Ident::new("Record".into(), name.span)),
+                Some(type_params),
+            )
+        }
+        (name, type_params) => type_ref(span, name, type_params),
+    };
+
+    Ok((i, ty))
+}
+
+/// Parses type arguments after `<`.
+fn type_args(mut i: Input, lo: BytePos) -> IResult<Input, TsTypeParamInstantiation> {
+    let mut params = vec![];
+    loop {
+        let (rest, ty) = union(i)?;
+        params.push(ty);
+        i = rest;
+
+        if let Some(rest) = eat(i, ",") {
+            i = rest;
+            continue;
+        }
+
+        let i = expect(i, ">")?;
+        return Ok((
+            i,
+            TsTypeParamInstantiation {
+                span: span(lo, i),
+                params,
+            },
+        ));
+    }
+}
+
+/// Parses a record type after `{`.
+fn record(mut i: Input, lo: BytePos) -> IResult<Input, Box<TsType>> {
+    let mut members = vec![];
+
+    loop {
+        if let Some(rest) = eat(i, "}") {
+            return Ok((
+                rest,
+                Box::new(TsType::TsTypeLit(TsTypeLit {
+                    span: span(lo, rest),
+                    members,
+                })),
+            ));
+        }
+
+        let key_lo = pos(skip_trivia(i));
+        let (rest, key) = if eat(i, "'").is_some() || eat(i, "\"").is_some() {
+            let (rest, s) = string(i)?;
+            (rest, Box::new(Expr::Lit(Lit::Str(s))))
+        } else {
+            let (rest, key) = ident(i)?;
+            (rest, Box::new(Expr::Ident(key)))
+        };
+        i = rest;
+
+        let (rest, type_ann, optional) = match eat(i, ":") {
+            Some(rest) => {
+                let (rest, ty) = union(rest)?;
+                match eat(rest, "=") {
+                    Some(rest) => (rest, ty, true),
+                    None => (rest, ty, false),
+                }
+            }
+            None => (
+                i,
+                keyword(span(key_lo, i), TsKeywordTypeKind::TsAnyKeyword),
+                false,
+            ),
+        };
+        i = rest;
+
+        members.push(TsTypeElement::TsPropertySignature(TsPropertySignature {
+            span: span(key_lo, i),
+            readonly: false,
+            key,
+            computed: false,
+            optional,
+            init: None,
+            params: vec![],
+            type_ann: Some(TsTypeAnn {
+                span: type_ann.span(),
+                type_ann,
+            }),
+            type_params: None,
+        }));
+
+        match eat(i, ",") {
+            Some(rest) => i = rest,
+            None => {
+                let rest = expect(i, "}")?;
+                return Ok((
+                    rest,
+                    Box::new(TsType::TsTypeLit(TsTypeLit {
+                        span: span(lo, rest),
+                        members,
+                    })),
+                ));
+            }
+        }
+    }
+}
+
+/// Parses `function(string, number=, ...*): boolean` after `function`.
+fn function(i: Input, lo: BytePos) -> IResult<Input, Box<TsType>> {
+    let mut i = expect(i, "(")?;
+    let mut params = vec![];
+    let mut new_type = None;
+
+    if eat(i, ")").is_none() {
+        loop {
+            let param_lo = pos(skip_trivia(i));
+
+            if let Some(rest) = eat_word(i, "new").and_then(|rest| eat(rest, ":")) {
+                let (rest, ty) = union(rest)?;
+                new_type = Some(ty);
+                i = rest;
+            } else if let Some(rest) = eat_word(i, "this").and_then(|rest| eat(rest, ":")) {
+                let (rest, ty) = union(rest)?;
+                i = rest;
+                params.push(TsFnParam::Ident(binding(
+                    span(param_lo, i),
+                    js_word!("this"),
+                    ty,
+                    false,
+                )));
+            } else if let Some(rest) = eat(i, "...") {
+                let (rest, ty) = if starts_type(rest) {
+                    union(rest)?
+                } else {
+                    (
+                        rest,
+                        keyword(span(param_lo, rest), TsKeywordTypeKind::TsAnyKeyword),
+                    )
+                };
+                i = rest;
+                let span = span(param_lo, i);
+                let arg = Ident::new("args".into(), span);
+                params.push(TsFnParam::Rest(RestPat {
+                    span,
+                    dot3_token: span,
+                    arg: Box::new(Pat::Ident(arg.into())),
+                    type_ann: Some(TsTypeAnn {
+                        span,
+                        type_ann: array_of(span, ty),
+                    }),
+                }));
+            } else {
+                let (rest, ty) = union(i)?;
+                let (rest, optional) = match eat(rest, "=") {
+                    Some(rest) => (rest, true),
+                    None => (rest, false),
+                };
+                i = rest;
+                let name = format!("arg{}", params.len());
+                params.push(TsFnParam::Ident(binding(
+                    span(param_lo, i),
+                    name.into(),
+                    ty,
+                    optional,
+                )));
+            }
+
+            match eat(i, ",") {
+                Some(rest) => i = rest,
+                None => {
+                    i = expect(i, ")")?;
+                    break;
+                }
+            }
+        }
+    } else {
+        i = eat(i, ")").unwrap();
+    }
+
+    let (i, ret) = match eat(i, ":") {
+        Some(rest) => prefix(rest)?,
+        None => (i, keyword(span(lo, i), TsKeywordTypeKind::TsAnyKeyword)),
+    };
+
+    let span = span(lo, i);
+    let ty = match new_type {
+        Some(new_type) => TsType::from(TsConstructorType {
+            span,
+            params,
+            type_params: None,
+            type_ann: TsTypeAnn {
+                span: new_type.span(),
+                type_ann: new_type,
+            },
+            is_abstract: false,
+        }),
+        None => TsType::from(TsFnType {
+            span,
+            params,
+            type_params: None,
+            type_ann: TsTypeAnn {
+                span: ret.span(),
+                type_ann: ret,
+            },
+        }),
+    };
+
+    Ok((i, Box::new(ty)))
+}
+
+fn string_lit(i: Input) -> IResult<Input, Box<TsType>> {
+    let (i, s) = string(i)?;
+    Ok((
+        i,
+        Box::new(TsType::TsLitType(TsLitType {
+            span: s.span,
+            lit: TsLit::Str(s),
+        })),
+    ))
+}
+
+fn string(i: Input) -> IResult<Input, Str> {
+    let i = skip_trivia(i);
+    let lo = pos(i);
+    let quote = match i.chars().next() {
+        Some(c @ '\'') | Some(c @ '"') => c,
+        _ => return Err(nom::Err::Error((i, ErrorKind::Char))),
+    };
+
+    let mut escaped = false;
+    let mut value = String::new();
+    for (idx, c) in i.char_indices().skip(1) {
+        if escaped {
+            value.push(c);
+            escaped = false;
+            continue;
+        }
+
+        match c {
+            '\\' => escaped = true,
+            _ if c == quote => {
+                let rest = i.slice(idx + 1..);
+                return Ok((
+                    rest,
+                    Str {
+                        span: span(lo, rest),
+                        value: value.into(),
+                        has_escape: false,
+                        kind: Default::default(),
+                    },
+                ));
+            }
+            _ => value.push(c),
+        }
+    }
+
+    Err(nom::Err::Error((i, ErrorKind::Char)))
+}
+
+fn number_lit(i: Input) -> IResult<Input, Box<TsType>> {
+    let lo = pos(i);
+    let len = i
+        .char_indices()
+        .find(|&(idx, c)| !(c.is_ascii_digit() || c == '.' || (idx == 0 && c == '-')))
+        .map(|(idx, _)| idx)
+        .unwrap_or_else(|| i.len());
+
+    let value = match i[..len].parse::<f64>() {
+        Ok(v) => v,
+        Err(..) => return Err(nom::Err::Error((i, ErrorKind::Digit))),
+    };
+    let rest = i.slice(len..);
+    let span = span(lo, rest);
+
+    Ok((
+        rest,
+        Box::new(TsType::TsLitType(TsLitType {
+            span,
+            lit: TsLit::Number(Number { span, value }),
+        })),
+    ))
+}
+
+fn ident(i: Input) -> IResult<Input, Ident> {
+    let i = skip_trivia(i);
+    let len = i
+        .char_indices()
+        .find(|&(_, c)| !is_ident_char(c))
+        .map(|(idx, _)| idx)
+        .unwrap_or_else(|| i.len());
+
+    if len == 0 || i.starts_with(|c: char| c.is_ascii_digit()) {
+        return Err(nom::Err::Error((i, ErrorKind::AlphaNumeric)));
+    }
+
+    let rest = i.slice(len..);
+    Ok((rest, Ident::new(i[..len].into(), span(pos(i), rest))))
+}
+
+fn is_ident_char(c: char) -> bool {
+    c == '_' || c == '$' || c.is_alphanumeric()
+}
+
+fn keyword_kind(sym: &str) -> Option<TsKeywordTypeKind> {
+    Some(match sym {
+        "any" => TsKeywordTypeKind::TsAnyKeyword,
+        "unknown" => TsKeywordTypeKind::TsUnknownKeyword,
+        "number" => TsKeywordTypeKind::TsNumberKeyword,
+        "object" => TsKeywordTypeKind::TsObjectKeyword,
+        "boolean" => TsKeywordTypeKind::TsBooleanKeyword,
+        "bigint" => TsKeywordTypeKind::TsBigIntKeyword,
+        "string" => TsKeywordTypeKind::TsStringKeyword,
+        "symbol" => TsKeywordTypeKind::TsSymbolKeyword,
+        "void" => TsKeywordTypeKind::TsVoidKeyword,
+        "undefined" => TsKeywordTypeKind::TsUndefinedKeyword,
+        "null" => TsKeywordTypeKind::TsNullKeyword,
+        "never" => TsKeywordTypeKind::TsNeverKeyword,
+        _ => return None,
+    })
+}
+
+/// Returns true if `i` starts with a type, instead of a token which can follow
+/// a type.
+fn starts_type(i: Input) -> bool {
+    let i = skip_trivia(i);
+    match i.chars().next() {
+        None => false,
+        Some(c) => !"|,>)}=]:".contains(c),
+    }
+}
+
+/// Skips whitespaces and `*` at the start of a line.
+fn skip_trivia(i: Input) -> Input {
+    let mut at_line_start = false;
+    for (idx, c) in i.char_indices() {
+        match c {
+            '\n' | '\r' => at_line_start = true,
+            ' ' | '\t' => {}
+            '*' if at_line_start => at_line_start = false,
+            _ => return i.slice(idx..),
+        }
+    }
+
+    i.slice(i.len()..)
+}
+
+fn eat<'i>(i: Input<'i>, s: &str) -> Option<Input<'i>> {
+    let i = skip_trivia(i);
+    if i.starts_with(s) {
+        Some(i.slice(s.len()..))
+    } else {
+        None
+    }
+}
+
+/// Like [eat], but `word` should not be followed by an identifier character.
+fn eat_word<'i>(i: Input<'i>, word: &str) -> Option<Input<'i>> {
+    let rest = eat(i, word)?;
+    if rest.starts_with(is_ident_char) {
+        None
+    } else {
+        Some(rest)
+    }
+}
+
+fn expect<'i>(i: Input<'i>, s: &str) -> Result<Input<'i>, nom::Err<(Input<'i>, ErrorKind)>> {
+    eat(i, s).ok_or_else(|| nom::Err::Error((i, ErrorKind::Tag)))
+}
+
+fn pos(i: Input) -> BytePos {
+    i.span().lo
+}
+
+fn span(lo: BytePos, rest: Input) -> Span {
+    Span::new(lo, pos(rest), Default::default())
+}
+
+fn keyword(span: Span, kind: TsKeywordTypeKind) -> Box<TsType> {
+    Box::new(TsType::TsKeywordType(TsKeywordType { span, kind }))
+}
+
+fn type_ref(
+    span: Span,
+    type_name: TsEntityName,
+    type_params: Option<TsTypeParamInstantiation>,
+) -> Box<TsType> {
+    Box::new(TsType::TsTypeRef(TsTypeRef {
+        span,
+        type_name,
+        type_params,
+    }))
+}
+
+fn binding(span: Span, name: JsWord, ty: Box<TsType>, optional: bool) -> BindingIdent {
+    BindingIdent {
+        id: Ident {
+            optional,
+            ..Ident::new(name, span)
+        },
+        type_ann: Some(TsTypeAnn {
+            span: ty.span(),
+            type_ann: ty,
+        }),
+    }
+}
+
+fn nullable(span: Span, ty: Box<TsType>) -> Box<TsType> {
+    union_of(
+        span,
+        vec![ty, keyword(span, TsKeywordTypeKind::TsNullKeyword)],
+    )
+}
+
+fn union_of(span: Span, types: Vec<Box<TsType>>) -> Box<TsType> {
+    Box::new(TsType::from(TsUnionType {
+        span,
+        types: types
+            .into_iter()
+            .map(|ty| match *ty {
+                TsType::TsFnOrConstructorType(..) => paren(ty),
+                _ => ty,
+            })
+            .collect(),
+    }))
+}
+
+fn array_of(span: Span, elem: Box<TsType>) -> Box<TsType> {
+    let elem_type = match *elem {
+        TsType::TsFnOrConstructorType(..) | TsType::TsUnionOrIntersectionType(..) => paren(elem),
+        _ => elem,
+    };
+
+    Box::new(TsType::TsArrayType(TsArrayType { span, elem_type }))
+}
+
+fn paren(ty: Box<TsType>) -> Box<TsType> {
+    Box::new(TsType::TsParenthesizedType(TsParenthesizedType {
+        span: ty.span(),
+        type_ann: ty,
+    }))
+}
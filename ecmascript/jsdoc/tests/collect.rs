@@ -0,0 +1,119 @@
+use jsdoc::{collect_docs, DocumentedItem, ItemKind};
+use swc_common::{comments::SingleThreadedComments, FileName};
+use swc_ecma_parser::{lexer::Lexer, Parser, StringInput, Syntax, TsConfig};
+
+fn collect(src: &str) -> Vec<DocumentedItem> {
+    testing::run_test(false, |cm, _| {
+        let fm = cm.new_source_file(FileName::Anon, src.into());
+        let comments = SingleThreadedComments::default();
+
+        let lexer = Lexer::new(
+            Syntax::Typescript(TsConfig {
+                decorators: true,
+                ..Default::default()
+            }),
+            Default::default(),
+            StringInput::from(&*fm),
+            Some(&comments),
+        );
+        let module = Parser::new_from(lexer).parse_module().unwrap();
+
+        Ok(collect_docs(&module, &comments))
+    })
+    .unwrap()
+}
+
+fn names(items: &[DocumentedItem]) -> Vec<(ItemKind, &str)> {
+    items.iter().map(|item| (item.kind, &*item.name)).collect()
+}
+
+#[test]
+fn declarations() {
+    let items = collect(
+        "
+/** Adds two numbers. */
+function add(a, b) {}
+
+/**
+ * @param {string} s
+ */
+export const trim = (s) => s.trim(), other = 1;
+
+// Not a jsdoc.
+let ignored;
+
+/* Not a jsdoc either. */
+var ignored2;
+
+/** Options. */
+export interface Options {}
+
+/** Alias. */
+type Alias = string;
+
+/** Default. */
+export default function () {}
+",
+    );
+
+    assert_eq!(
+        names(&items),
+        vec![
+            (ItemKind::Function, "add"),
+            (ItemKind::Variable, "trim"),
+            (ItemKind::Variable, "other"),
+            (ItemKind::Interface, "Options"),
+            (ItemKind::TypeAlias, "Alias"),
+            (ItemKind::Function, "default"),
+        ]
+    );
+    assert_eq!(&*items[0].doc.description.value, "Adds two numbers.");
+    assert_eq!(items[1].doc.tags.len(), 1);
+}
+
+#[test]
+fn class_members() {
+    let items = collect(
+        "
+/** A class. */
+export class Foo {
+    /** Creates a Foo. */
+    constructor() {}
+
+    /** Instance method. */
+    bar() {}
+
+    /** Static method. */
+    static baz() {}
+
+    /** Getter. */
+    get qux() { return 1 }
+
+    /** Property. */
+    @dec
+    prop = 1;
+
+    /** Private. */
+    #secret = 2;
+
+    /** Computed keys are skipped. */
+    [key]() {}
+
+    undocumented() {}
+}
+",
+    );
+
+    assert_eq!(
+        names(&items),
+        vec![
+            (ItemKind::Class, "Foo"),
+            (ItemKind::Constructor, "Foo#constructor"),
+            (ItemKind::Method, "Foo#bar"),
+            (ItemKind::Method, "Foo.baz"),
+            (ItemKind::Getter, "Foo#qux"),
+            (ItemKind::Property, "Foo#prop"),
+            (ItemKind::Property, "Foo##secret"),
+        ]
+    );
+}
@@ -0,0 +1,143 @@
+use jsdoc::{parse_ts_type, parse_type_expr, Input};
+use swc_common::BytePos;
+use swc_ecma_ast::*;
+
+fn parse(s: &str) -> Box<TsType> {
+    let (rest, ty) = parse_ts_type(Input::new(BytePos(1), BytePos(1 + s.len() as u32), s))
+        .unwrap_or_else(|err| panic!("failed to parse `{}`: {:?}", s, err));
+    assert!(rest.is_empty(), "`{}` was not parsed: {}", s, &*rest);
+    ty
+}
+
+fn union_types(ty: &TsType) -> &[Box<TsType>] {
+    match ty {
+        TsType::TsUnionOrIntersectionType(TsUnionOrIntersectionType::TsUnionType(u)) => &u.types,
+        _ => panic!("not a union: {:?}", ty),
+    }
+}
+
+fn is_keyword(ty: &TsType, kind: TsKeywordTypeKind) -> bool {
+    match ty {
+        TsType::TsKeywordType(k) => k.kind == kind,
+        _ => false,
+    }
+}
+
+fn type_ref_name(ty: &TsType) -> &str {
+    match ty {
+        TsType::TsTypeRef(TsTypeRef {
+            type_name: TsEntityName::Ident(i),
+            ..
+        }) => &i.sym,
+        _ => panic!("not a type reference: {:?}", ty),
+    }
+}
+
+#[test]
+fn keywords() {
+    assert!(is_keyword(
+        &parse("string"),
+        TsKeywordTypeKind::TsStringKeyword
+    ));
+    assert!(is_keyword(&parse("*"), TsKeywordTypeKind::TsAnyKeyword));
+    assert!(is_keyword(&parse("?"), TsKeywordTypeKind::TsAnyKeyword));
+    assert_eq!(type_ref_name(&parse("Element")), "Element");
+}
+
+#[test]
+fn union_with_generics() {
+    let ty = parse("Array<string>|null");
+    let types = union_types(&ty);
+    assert_eq!(types.len(), 2);
+    assert_eq!(type_ref_name(&types[0]), "Array");
+    assert!(is_keyword(&types[1], TsKeywordTypeKind::TsNullKeyword));
+
+    match &*parse("Array.<number>") {
+        TsType::TsTypeRef(TsTypeRef {
+            type_params: Some(params),
+            ..
+        }) => assert!(is_keyword(
+            &params.params[0],
+            TsKeywordTypeKind::TsNumberKeyword
+        )),
+        ty => panic!("{:?}", ty),
+    }
+
+    assert_eq!(type_ref_name(&parse("Object<string, number>")), "Record");
+}
+
+#[test]
+fn nullable_and_optional() {
+    let ty = parse("?string");
+    let types = union_types(&ty);
+    assert!(is_keyword(&types[0], TsKeywordTypeKind::TsStringKeyword));
+    assert!(is_keyword(&types[1], TsKeywordTypeKind::TsNullKeyword));
+
+    assert_eq!(type_ref_name(&parse("!Object")), "Object");
+
+    let ty = parse("number=");
+    let types = union_types(&ty);
+    assert!(is_keyword(&types[1], TsKeywordTypeKind::TsUndefinedKeyword));
+}
+
+#[test]
+fn variadic() {
+    match &*parse("...number") {
+        TsType::TsArrayType(a) => {
+            assert!(is_keyword(&a.elem_type, TsKeywordTypeKind::TsNumberKeyword))
+        }
+        ty => panic!("{:?}", ty),
+    }
+}
+
+#[test]
+fn record() {
+    match &*parse("{a: number, 'b': string=, c}") {
+        TsType::TsTypeLit(lit) => {
+            assert_eq!(lit.members.len(), 3);
+            match &lit.members[1] {
+                TsTypeElement::TsPropertySignature(p) => assert!(p.optional),
+                m => panic!("{:?}", m),
+            }
+        }
+        ty => panic!("{:?}", ty),
+    }
+}
+
+#[test]
+fn function() {
+    match &*parse("function(this:Foo, string, number=, ...*): boolean") {
+        TsType::TsFnOrConstructorType(TsFnOrConstructorType::TsFnType(f)) => {
+            assert_eq!(f.params.len(), 4);
+            match &f.params[2] {
+                TsFnParam::Ident(i) => assert!(i.id.optional),
+                p => panic!("{:?}", p),
+            }
+            assert!(matches!(f.params[3], TsFnParam::Rest(..)));
+            assert!(is_keyword(
+                &f.type_ann.type_ann,
+                TsKeywordTypeKind::TsBooleanKeyword
+            ));
+        }
+        ty => panic!("{:?}", ty),
+    }
+
+    assert!(matches!(
+        *parse("function(new:Foo, string)"),
+        TsType::TsFnOrConstructorType(TsFnOrConstructorType::TsConstructorType(..))
+    ));
+}
+
+#[test]
+fn braces() {
+    let s = "{(string|number)[]} rest";
+    let (rest, ty) =
+        parse_type_expr(Input::new(BytePos(1), BytePos(1 + s.len() as u32), s)).unwrap();
+    assert_eq!(&*rest, " rest");
+    match &*ty {
+        TsType::TsArrayType(a) => {
+            assert!(matches!(*a.elem_type, TsType::TsParenthesizedType(..)))
+        }
+        ty => panic!("{:?}", ty),
+    }
+}
@@ -0,0 +1,68 @@
+use serde::{Deserialize, Serialize};
+use swc_atoms::JsWord;
+use swc_common::{errors::DiagnosticId, Span};
+use swc_ecma_utils::HANDLER;
+
+/// Severity of a lint rule.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum LintRuleReaction {
+    #[serde(rename = "off")]
+    Off,
+    #[serde(rename = "warn")]
+    Warning,
+    #[serde(rename = "error")]
+    Error,
+}
+
+impl Default for LintRuleReaction {
+    fn default() -> Self {
+        LintRuleReaction::Off
+    }
+}
+
+impl LintRuleReaction {
+    /// Reports a diagnostic using [HANDLER].
+    pub(crate) fn emit(self, rule: &str, span: Span, msg: &str) {
+        HANDLER.with(|handler| {
+            let mut builder = match self {
+                LintRuleReaction::Off => return,
+                LintRuleReaction::Warning => handler.struct_span_warn(span, msg),
+                LintRuleReaction::Error => handler.struct_span_err(span, msg),
+            };
+
+            builder.code(DiagnosticId::Lint(rule.into())).emit();
+        })
+    }
+}
+
+/// Names of rules are same as the ones of eslint.
+///
+/// Rules which are not set are disabled. `None` is distinct from
+/// [LintRuleReaction::Off] so that configs can be merged rule by rule.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "kebab-case")]
+pub struct LintConfig {
+    #[serde(default)]
+    pub no_unused_vars: Option<LintRuleReaction>,
+
+    #[serde(default)]
+    pub no_dupe_keys: Option<LintRuleReaction>,
+
+    #[serde(default)]
+    pub no_undef: Option<LintRuleReaction>,
+
+    #[serde(default)]
+    pub no_unreachable: Option<LintRuleReaction>,
+
+    /// Reports bindings declared multiple times in a scope.
+    #[serde(default)]
+    pub no_redeclare: Option<LintRuleReaction>,
+
+    #[serde(default)]
+    pub no_const_assign: Option<LintRuleReaction>,
+
+    /// Global variables used by `no-undef`, in addition to the builtins of
+    /// the ecmascript.
+    #[serde(default)]
+    pub globals: Vec<JsWord>,
+}
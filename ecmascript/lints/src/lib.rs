@@ -0,0 +1,10 @@
+//! Lint rules for the ecmascript.
+//!
+//! Rules depend on the syntax contexts applied by the resolver, so the
+//! resolver should be applied before running them.
+
+pub use self::rule::{lint_to_fold, Rule};
+
+pub mod config;
+pub mod rule;
+pub mod rules;
@@ -0,0 +1,70 @@
+use std::fmt::Debug;
+use swc_ecma_ast::*;
+use swc_ecma_visit::{noop_fold_type, Fold};
+
+/// A lint rule.
+///
+/// Rules report diagnostics using [swc_ecma_utils::HANDLER].
+pub trait Rule: Debug + Send + Sync {
+    fn lint_module(&mut self, program: &Module);
+    fn lint_script(&mut self, program: &Script);
+}
+
+impl<R> Rule for Box<R>
+where
+    R: ?Sized + Rule,
+{
+    fn lint_module(&mut self, program: &Module) {
+        (**self).lint_module(program)
+    }
+
+    fn lint_script(&mut self, program: &Script) {
+        (**self).lint_script(program)
+    }
+}
+
+impl<R> Rule for Vec<R>
+where
+    R: Rule,
+{
+    fn lint_module(&mut self, program: &Module) {
+        for rule in self {
+            rule.lint_module(program);
+        }
+    }
+
+    fn lint_script(&mut self, program: &Script) {
+        for rule in self {
+            rule.lint_script(program);
+        }
+    }
+}
+
+/// Creates a pass which runs `rule` without modifying the program.
+pub fn lint_to_fold<R>(rule: R) -> impl Fold
+where
+    R: Rule,
+{
+    LintFolder(rule)
+}
+
+struct LintFolder<R>(R)
+where
+    R: Rule;
+
+impl<R> Fold for LintFolder<R>
+where
+    R: Rule,
+{
+    noop_fold_type!();
+
+    fn fold_module(&mut self, program: Module) -> Module {
+        self.0.lint_module(&program);
+        program
+    }
+
+    fn fold_script(&mut self, program: Script) -> Script {
+        self.0.lint_script(&program);
+        program
+    }
+}
@@ -0,0 +1,49 @@
+use crate::{
+    config::{LintConfig, LintRuleReaction},
+    rule::Rule,
+};
+use swc_common::{Mark, SyntaxContext};
+
+mod no_const_assign;
+mod no_dupe_keys;
+mod no_redeclare;
+mod no_undef;
+mod no_unreachable;
+mod no_unused_vars;
+
+/// Creates rules enabled by `config`.
+///
+/// `top_level_mark` should be the mark passed to the resolver.
+pub fn all(config: &LintConfig, top_level_mark: Mark) -> Vec<Box<dyn Rule>> {
+    let top_level_ctxt = SyntaxContext::empty().apply_mark(top_level_mark);
+    let mut rules: Vec<Box<dyn Rule>> = vec![];
+
+    if let Some(reaction) = enabled(config.no_unused_vars) {
+        rules.push(Box::new(no_unused_vars::NoUnusedVars::new(reaction)));
+    }
+    if let Some(reaction) = enabled(config.no_dupe_keys) {
+        rules.push(Box::new(no_dupe_keys::NoDupeKeys::new(reaction)));
+    }
+    if let Some(reaction) = enabled(config.no_undef) {
+        rules.push(Box::new(no_undef::NoUndef::new(
+            reaction,
+            top_level_ctxt,
+            config.globals.clone(),
+        )));
+    }
+    if let Some(reaction) = enabled(config.no_unreachable) {
+        rules.push(Box::new(no_unreachable::NoUnreachable::new(reaction)));
+    }
+    if let Some(reaction) = enabled(config.no_redeclare) {
+        rules.push(Box::new(no_redeclare::NoRedeclare::new(reaction)));
+    }
+    if let Some(reaction) = enabled(config.no_const_assign) {
+        rules.push(Box::new(no_const_assign::NoConstAssign::new(reaction)));
+    }
+
+    rules
+}
+
+fn enabled(reaction: Option<LintRuleReaction>) -> Option<LintRuleReaction> {
+    reaction.filter(|reaction| *reaction != LintRuleReaction::Off)
+}
@@ -0,0 +1,52 @@
+use crate::{config::LintRuleReaction, rule::Rule};
+use swc_ecma_ast::*;
+use swc_ecma_utils::scope::{analyze_scopes, BindingKind, ScopeAnalyzer};
+use swc_ecma_visit::VisitWith;
+
+const RULE_NAME: &str = "no-const-assign";
+
+/// Reports assignments to `const` variables.
+#[derive(Debug)]
+pub(super) struct NoConstAssign {
+    reaction: LintRuleReaction,
+}
+
+impl NoConstAssign {
+    pub fn new(reaction: LintRuleReaction) -> Self {
+        Self { reaction }
+    }
+
+    fn check<N>(&self, node: &N)
+    where
+        N: VisitWith<ScopeAnalyzer>,
+    {
+        let analysis = analyze_scopes(node);
+        let mut assigned = analysis
+            .bindings()
+            .filter(|binding| binding.kind == BindingKind::Const)
+            .flat_map(|binding| {
+                binding
+                    .references
+                    .iter()
+                    .filter(|r| r.kind.is_write())
+                    .map(move |r| (r.span, &binding.id))
+            })
+            .collect::<Vec<_>>();
+        assigned.sort_by_key(|(span, _)| span.lo);
+
+        for (span, id) in assigned {
+            self.reaction
+                .emit(RULE_NAME, span, &format!("'{}' is constant", id.0));
+        }
+    }
+}
+
+impl Rule for NoConstAssign {
+    fn lint_module(&mut self, program: &Module) {
+        self.check(program);
+    }
+
+    fn lint_script(&mut self, program: &Script) {
+        self.check(program);
+    }
+}
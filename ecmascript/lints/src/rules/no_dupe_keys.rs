@@ -0,0 +1,102 @@
+use crate::{config::LintRuleReaction, rule::Rule};
+use std::collections::HashMap;
+use swc_atoms::JsWord;
+use swc_common::{Spanned, DUMMY_SP};
+use swc_ecma_ast::*;
+use swc_ecma_visit::{noop_visit_type, Node, Visit, VisitWith};
+
+const RULE_NAME: &str = "no-dupe-keys";
+
+/// Reports duplicate keys in object literals.
+///
+/// A getter and a setter with the same key are allowed.
+#[derive(Debug)]
+pub(super) struct NoDupeKeys {
+    reaction: LintRuleReaction,
+}
+
+impl NoDupeKeys {
+    pub fn new(reaction: LintRuleReaction) -> Self {
+        Self { reaction }
+    }
+}
+
+impl Rule for NoDupeKeys {
+    fn lint_module(&mut self, program: &Module) {
+        program.visit_with(&Invalid { span: DUMMY_SP }, self);
+    }
+
+    fn lint_script(&mut self, program: &Script) {
+        program.visit_with(&Invalid { span: DUMMY_SP }, self);
+    }
+}
+
+#[derive(Default)]
+struct Seen {
+    init: bool,
+    getter: bool,
+    setter: bool,
+}
+
+fn prop_name(key: &PropName) -> Option<JsWord> {
+    match key {
+        PropName::Ident(i) => Some(i.sym.clone()),
+        PropName::Str(s) => Some(s.value.clone()),
+        PropName::Num(n) => Some(n.value.to_string().into()),
+        PropName::Computed(..) | PropName::BigInt(..) => None,
+    }
+}
+
+impl Visit for NoDupeKeys {
+    noop_visit_type!();
+
+    fn visit_object_lit(&mut self, obj: &ObjectLit, _: &dyn Node) {
+        let mut seen = HashMap::<JsWord, Seen>::default();
+
+        for prop in &obj.props {
+            let prop = match prop {
+                PropOrSpread::Prop(prop) => &**prop,
+                PropOrSpread::Spread(..) => continue,
+            };
+
+            let (key, span) = match prop {
+                Prop::Shorthand(i) => (Some(i.sym.clone()), i.span),
+                Prop::KeyValue(KeyValueProp { key, .. })
+                | Prop::Getter(GetterProp { key, .. })
+                | Prop::Setter(SetterProp { key, .. })
+                | Prop::Method(MethodProp { key, .. }) => (prop_name(key), key.span()),
+                Prop::Assign(..) => continue,
+            };
+            let key = match key {
+                Some(v) => v,
+                None => continue,
+            };
+
+            let s = seen.entry(key.clone()).or_default();
+            let duplicate = match prop {
+                Prop::Getter(..) => {
+                    let dup = s.init || s.getter;
+                    s.getter = true;
+                    dup
+                }
+                Prop::Setter(..) => {
+                    let dup = s.init || s.setter;
+                    s.setter = true;
+                    dup
+                }
+                _ => {
+                    let dup = s.init || s.getter || s.setter;
+                    s.init = true;
+                    dup
+                }
+            };
+
+            if duplicate {
+                self.reaction
+                    .emit(RULE_NAME, span, &format!("Duplicate key '{}'", key));
+            }
+        }
+
+        obj.visit_children_with(self);
+    }
+}
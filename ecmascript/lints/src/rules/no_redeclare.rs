@@ -0,0 +1,65 @@
+use crate::{config::LintRuleReaction, rule::Rule};
+use swc_ecma_ast::*;
+use swc_ecma_utils::scope::{analyze_scopes, BindingKind, ScopeAnalyzer};
+use swc_ecma_visit::VisitWith;
+
+const RULE_NAME: &str = "no-redeclare";
+
+/// Reports bindings declared multiple times in a scope.
+///
+/// Two bindings are in the same scope if the resolver gave them the same
+/// syntax context. Redeclaration is allowed only if both of them are
+/// `var`s, parameters or functions.
+#[derive(Debug)]
+pub(super) struct NoRedeclare {
+    reaction: LintRuleReaction,
+}
+
+impl NoRedeclare {
+    pub fn new(reaction: LintRuleReaction) -> Self {
+        Self { reaction }
+    }
+
+    fn check<N>(&self, node: &N)
+    where
+        N: VisitWith<ScopeAnalyzer>,
+    {
+        let analysis = analyze_scopes(node);
+        let mut redeclared = vec![];
+
+        for binding in analysis.bindings() {
+            let mut decls = binding.decls.iter().filter(|decl| match decl.kind {
+                BindingKind::CatchParam | BindingKind::ExprName => false,
+                _ => true,
+            });
+
+            let first = match decls.next() {
+                Some(first) => first,
+                None => continue,
+            };
+
+            for decl in decls {
+                if decl.kind.is_lexical() || first.kind.is_lexical() {
+                    redeclared.push((decl.span, &binding.id));
+                }
+            }
+        }
+
+        redeclared.sort_by_key(|(span, _)| span.lo);
+
+        for (span, id) in redeclared {
+            self.reaction
+                .emit(RULE_NAME, span, &format!("'{}' is already defined", id.0));
+        }
+    }
+}
+
+impl Rule for NoRedeclare {
+    fn lint_module(&mut self, program: &Module) {
+        self.check(program);
+    }
+
+    fn lint_script(&mut self, program: &Script) {
+        self.check(program);
+    }
+}
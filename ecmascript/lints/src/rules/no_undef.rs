@@ -0,0 +1,162 @@
+use crate::{config::LintRuleReaction, rule::Rule};
+use std::collections::HashSet;
+use swc_atoms::JsWord;
+use swc_common::{Span, SyntaxContext, DUMMY_SP};
+use swc_ecma_ast::*;
+use swc_ecma_utils::scope::{analyze_scopes, ScopeAnalyzer};
+use swc_ecma_visit::{noop_visit_type, Node, Visit, VisitWith};
+
+const RULE_NAME: &str = "no-undef";
+
+/// Global variables defined by the ecmascript specification.
+const BUILTINS: &[&str] = &[
+    "AggregateError",
+    "Array",
+    "ArrayBuffer",
+    "Atomics",
+    "BigInt",
+    "BigInt64Array",
+    "BigUint64Array",
+    "Boolean",
+    "DataView",
+    "Date",
+    "Error",
+    "EvalError",
+    "FinalizationRegistry",
+    "Float32Array",
+    "Float64Array",
+    "Function",
+    "Infinity",
+    "Int16Array",
+    "Int32Array",
+    "Int8Array",
+    "Intl",
+    "JSON",
+    "Map",
+    "Math",
+    "NaN",
+    "Number",
+    "Object",
+    "Promise",
+    "Proxy",
+    "RangeError",
+    "ReferenceError",
+    "Reflect",
+    "RegExp",
+    "Set",
+    "SharedArrayBuffer",
+    "String",
+    "Symbol",
+    "SyntaxError",
+    "TypeError",
+    "URIError",
+    "Uint16Array",
+    "Uint32Array",
+    "Uint8Array",
+    "Uint8ClampedArray",
+    "WeakMap",
+    "WeakRef",
+    "WeakSet",
+    "arguments",
+    "decodeURI",
+    "decodeURIComponent",
+    "encodeURI",
+    "encodeURIComponent",
+    "escape",
+    "eval",
+    "globalThis",
+    "isFinite",
+    "isNaN",
+    "parseFloat",
+    "parseInt",
+    "undefined",
+    "unescape",
+];
+
+/// Reports references to variables which are not declared, except for the
+/// builtins and globals from the config.
+///
+/// The resolver marks unresolved references with the top-level mark, so a
+/// top-level reference without a top-level declaration is undefined.
+/// `typeof foo` is allowed.
+#[derive(Debug)]
+pub(super) struct NoUndef {
+    reaction: LintRuleReaction,
+    top_level_ctxt: SyntaxContext,
+    globals: HashSet<JsWord>,
+}
+
+impl NoUndef {
+    pub fn new(
+        reaction: LintRuleReaction,
+        top_level_ctxt: SyntaxContext,
+        globals: Vec<JsWord>,
+    ) -> Self {
+        Self {
+            reaction,
+            top_level_ctxt,
+            globals: BUILTINS
+                .iter()
+                .map(|&s| JsWord::from(s))
+                .chain(globals)
+                .collect(),
+        }
+    }
+
+    fn check<N>(&self, node: &N)
+    where
+        N: VisitWith<ScopeAnalyzer> + VisitWith<TypeofFinder>,
+    {
+        let typeof_args = {
+            let mut v = TypeofFinder::default();
+            node.visit_with(&Invalid { span: DUMMY_SP }, &mut v);
+            v.spans
+        };
+
+        let analysis = analyze_scopes(node);
+        let mut refs = analysis.unresolved().iter().collect::<Vec<_>>();
+        refs.sort_by_key(|(_, r)| r.span.lo);
+
+        for (id, r) in refs {
+            if id.1 != self.top_level_ctxt
+                || self.globals.contains(&id.0)
+                || typeof_args.contains(&r.span)
+            {
+                continue;
+            }
+
+            self.reaction
+                .emit(RULE_NAME, r.span, &format!("'{}' is not defined", id.0));
+        }
+    }
+}
+
+impl Rule for NoUndef {
+    fn lint_module(&mut self, program: &Module) {
+        self.check(program);
+    }
+
+    fn lint_script(&mut self, program: &Script) {
+        self.check(program);
+    }
+}
+
+/// Collects spans of identifiers used like `typeof foo`.
+#[derive(Default)]
+pub(super) struct TypeofFinder {
+    spans: HashSet<Span>,
+}
+
+impl Visit for TypeofFinder {
+    noop_visit_type!();
+
+    fn visit_unary_expr(&mut self, e: &UnaryExpr, _: &dyn Node) {
+        if e.op == op!("typeof") {
+            if let Expr::Ident(i) = &*e.arg {
+                self.spans.insert(i.span);
+            }
+        }
+
+        e.visit_children_with(self);
+    }
+}
@@ -0,0 +1,105 @@
+use crate::{config::LintRuleReaction, rule::Rule};
+use swc_common::{Spanned, DUMMY_SP};
+use swc_ecma_ast::*;
+use swc_ecma_utils::StmtLike;
+use swc_ecma_visit::{noop_visit_type, Node, Visit, VisitWith};
+
+const RULE_NAME: &str = "no-unreachable";
+
+/// Reports statements after `return`, `throw`, `break` and `continue`.
+///
+/// Function declarations and `var` declarations without an initializer are
+/// allowed because they are hoisted.
+#[derive(Debug)]
+pub(super) struct NoUnreachable {
+    reaction: LintRuleReaction,
+}
+
+impl NoUnreachable {
+    pub fn new(reaction: LintRuleReaction) -> Self {
+        Self { reaction }
+    }
+
+    fn check_stmts<T>(&self, stmts: &[T])
+    where
+        T: StmtLike,
+    {
+        let mut terminated = false;
+
+        for stmt in stmts {
+            let stmt = match stmt.as_stmt() {
+                Some(v) => v,
+                None => continue,
+            };
+
+            if terminated && !is_hoisted(stmt) {
+                self.reaction
+                    .emit(RULE_NAME, stmt.span(), "Unreachable code");
+                return;
+            }
+
+            terminated |= terminates(stmt);
+        }
+    }
+}
+
+impl Rule for NoUnreachable {
+    fn lint_module(&mut self, program: &Module) {
+        program.visit_with(&Invalid { span: DUMMY_SP }, self);
+    }
+
+    fn lint_script(&mut self, program: &Script) {
+        program.visit_with(&Invalid { span: DUMMY_SP }, self);
+    }
+}
+
+impl Visit for NoUnreachable {
+    noop_visit_type!();
+
+    fn visit_module_items(&mut self, items: &[ModuleItem], _: &dyn Node) {
+        self.check_stmts(items);
+        for item in items {
+            item.visit_with(&Invalid { span: DUMMY_SP }, self);
+        }
+    }
+
+    fn visit_stmts(&mut self, stmts: &[Stmt], _: &dyn Node) {
+        self.check_stmts(stmts);
+        for stmt in stmts {
+            stmt.visit_with(&Invalid { span: DUMMY_SP }, self);
+        }
+    }
+}
+
+/// Returns true if statements after `stmt` are never executed.
+fn terminates(stmt: &Stmt) -> bool {
+    match stmt {
+        Stmt::Return(..) | Stmt::Throw(..) | Stmt::Break(..) | Stmt::Continue(..) => true,
+        Stmt::Block(b) => b.stmts.iter().any(terminates),
+        Stmt::If(IfStmt {
+            cons,
+            alt: Some(alt),
+            ..
+        }) => terminates(cons) && terminates(alt),
+        Stmt::Try(t) => {
+            t.finalizer
+                .as_ref()
+                .map_or(false, |f| f.stmts.iter().any(terminates))
+                || (t.block.stmts.iter().any(terminates)
+                    && t.handler
+                        .as_ref()
+                        .map_or(true, |h| h.body.stmts.iter().any(terminates)))
+        }
+        _ => false,
+    }
+}
+
+fn is_hoisted(stmt: &Stmt) -> bool {
+    match stmt {
+        Stmt::Decl(Decl::Fn(..)) | Stmt::Empty(..) => true,
+        Stmt::Decl(Decl::Var(v)) => {
+            v.kind == VarDeclKind::Var && v.decls.iter().all(|decl| decl.init.is_none())
+        }
+        _ => false,
+    }
+}
@@ -0,0 +1,56 @@
+use crate::{config::LintRuleReaction, rule::Rule};
+use swc_ecma_ast::*;
+use swc_ecma_utils::scope::{analyze_scopes, BindingKind, ScopeAnalyzer};
+use swc_ecma_visit::VisitWith;
+
+const RULE_NAME: &str = "no-unused-vars";
+
+/// Reports variables, functions, classes and imports which are never read.
+///
+/// Parameters are not checked.
+#[derive(Debug)]
+pub(super) struct NoUnusedVars {
+    reaction: LintRuleReaction,
+}
+
+impl NoUnusedVars {
+    pub fn new(reaction: LintRuleReaction) -> Self {
+        Self { reaction }
+    }
+
+    fn check<N>(&self, node: &N)
+    where
+        N: VisitWith<ScopeAnalyzer>,
+    {
+        let analysis = analyze_scopes(node);
+        let mut bindings = analysis.bindings().collect::<Vec<_>>();
+        bindings.sort_by_key(|binding| binding.decls[0].span.lo);
+
+        for binding in bindings {
+            match binding.kind {
+                BindingKind::Param | BindingKind::CatchParam | BindingKind::ExprName => continue,
+                _ => {}
+            }
+
+            if binding.exported || binding.is_read() {
+                continue;
+            }
+
+            self.reaction.emit(
+                RULE_NAME,
+                binding.decls[0].span,
+                &format!("'{}' is defined but never used", binding.id.0),
+            );
+        }
+    }
+}
+
+impl Rule for NoUnusedVars {
+    fn lint_module(&mut self, program: &Module) {
+        self.check(program);
+    }
+
+    fn lint_script(&mut self, program: &Script) {
+        self.check(program);
+    }
+}
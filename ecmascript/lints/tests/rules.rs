@@ -0,0 +1,261 @@
+use swc_common::{errors::Level, FileName, Mark};
+use swc_ecma_ast::*;
+use swc_ecma_lints::{
+    config::{LintConfig, LintRuleReaction},
+    lint_to_fold, rules,
+};
+use swc_ecma_parser::{lexer::Lexer, Parser, StringInput, Syntax};
+use swc_ecma_transforms_base::resolver::resolver_with_mark;
+use swc_ecma_utils::HANDLER;
+use swc_ecma_visit::FoldWith;
+use testing::Tester;
+
+/// Returns messages reported while linting `src`.
+fn lint(config: LintConfig, src: &str) -> Vec<(Level, String)> {
+    let errors = Tester::new()
+        .errors(|cm, handler| {
+            let fm = cm.new_source_file(FileName::Anon, src.into());
+            let lexer = Lexer::new(
+                Syntax::default(),
+                Default::default(),
+                StringInput::from(&*fm),
+                None,
+            );
+            let module = Parser::new_from(lexer)
+                .parse_module()
+                .map_err(|err| err.into_diagnostic(&handler).emit())?;
+
+            let top_level_mark = Mark::fresh(Mark::root());
+            let module = module.fold_with(&mut resolver_with_mark(top_level_mark));
+
+            HANDLER.set(&handler, || {
+                module.fold_with(&mut lint_to_fold(rules::all(&config, top_level_mark)))
+            });
+
+            Err::<Module, _>(())
+        })
+        .unwrap_err();
+
+    errors
+        .into_iter()
+        .map(|err| (err.level, err.message()))
+        .collect()
+}
+
+fn errors(config: LintConfig, src: &str) -> Vec<String> {
+    lint(config, src)
+        .into_iter()
+        .map(|(level, msg)| {
+            assert_eq!(level, Level::Error);
+            msg
+        })
+        .collect()
+}
+
+#[test]
+fn no_unused_vars() {
+    let config = LintConfig {
+        no_unused_vars: Some(LintRuleReaction::Error),
+        ..Default::default()
+    };
+
+    assert_eq!(
+        errors(
+            config,
+            "import { a, b } from 'foo';
+            const c = 1;
+            let d = 2;
+            d = 3;
+            export const e = b;
+            function f(unused) {}
+            export { c };"
+        ),
+        vec![
+            "'a' is defined but never used",
+            "'d' is defined but never used",
+            "'f' is defined but never used",
+        ]
+    );
+}
+
+#[test]
+fn no_unused_vars_shadowed() {
+    let config = LintConfig {
+        no_unused_vars: Some(LintRuleReaction::Error),
+        ..Default::default()
+    };
+
+    assert_eq!(
+        errors(
+            config,
+            "const a = 1;
+            export function f() {
+                const a = 2;
+                return a;
+            }"
+        ),
+        vec!["'a' is defined but never used"]
+    );
+}
+
+#[test]
+fn no_dupe_keys() {
+    let config = LintConfig {
+        no_dupe_keys: Some(LintRuleReaction::Error),
+        ..Default::default()
+    };
+
+    assert_eq!(
+        errors(
+            config,
+            "export const a = {
+                a: 1,
+                'a': 2,
+                1: 3,
+                get b() {},
+                set b(v) {},
+                c,
+                [c]: 4,
+            };"
+        ),
+        vec!["Duplicate key 'a'"]
+    );
+}
+
+#[test]
+fn no_undef() {
+    let config = LintConfig {
+        no_undef: Some(LintRuleReaction::Error),
+        globals: vec!["window".into()],
+        ..Default::default()
+    };
+
+    assert_eq!(
+        errors(
+            config,
+            "const a = foo;
+            window.foo = Object.keys(a);
+            if (typeof bar === 'undefined') {}
+            baz = 1;
+            function f() { return f; }
+            export class A {
+                qux = 1;
+            }"
+        ),
+        vec!["'foo' is not defined", "'baz' is not defined"]
+    );
+}
+
+#[test]
+fn no_unreachable() {
+    let config = LintConfig {
+        no_unreachable: Some(LintRuleReaction::Error),
+        ..Default::default()
+    };
+
+    assert_eq!(
+        errors(
+            config,
+            "export function f(a) {
+                if (a) {
+                    return 1;
+                } else {
+                    throw new Error();
+                }
+                var b;
+                function g() {}
+                a++;
+            }
+            export function g(a) {
+                while (a) {
+                    break;
+                }
+                return a;
+            }"
+        ),
+        vec!["Unreachable code"]
+    );
+}
+
+#[test]
+fn no_redeclare() {
+    let config = LintConfig {
+        no_redeclare: Some(LintRuleReaction::Error),
+        ..Default::default()
+    };
+
+    assert_eq!(
+        errors(
+            config,
+            "var a;
+            var a;
+            let b;
+            {
+                let b;
+            }
+            export function f(c) {
+                var c;
+            }
+            let d;
+            var d;
+            class e {}
+            function e() {}"
+        ),
+        vec!["'d' is already defined", "'e' is already defined"]
+    );
+}
+
+#[test]
+fn no_const_assign() {
+    let config = LintConfig {
+        no_const_assign: Some(LintRuleReaction::Error),
+        ..Default::default()
+    };
+
+    assert_eq!(
+        errors(
+            config,
+            "const a = 1;
+            a = 2;
+            a++;
+            export function f() {
+                let a = 1;
+                a = 2;
+            }
+            for (const b of []) {}
+            const { c } = {};
+            [c] = [];"
+        ),
+        vec!["'a' is constant", "'a' is constant", "'c' is constant"]
+    );
+}
+
+#[test]
+fn warning() {
+    let config = LintConfig {
+        no_undef: Some(LintRuleReaction::Warning),
+        ..Default::default()
+    };
+
+    assert_eq!(
+        lint(config, "foo();"),
+        vec![(Level::Warning, "'foo' is not defined".to_string())]
+    );
+}
+
+#[test]
+fn config() {
+    let config: LintConfig = serde_json::from_str(
+        r#"{
+            "no-unused-vars": "warn",
+            "no-undef": "error",
+            "globals": ["window"]
+        }"#,
+    )
+    .unwrap();
+
+    assert_eq!(config.no_unused_vars, Some(LintRuleReaction::Warning));
+    assert_eq!(config.no_undef, Some(LintRuleReaction::Error));
+    assert_eq!(config.no_dupe_keys, None);
+    assert_eq!(config.globals, vec!["window"]);
+}
@@ -276,7 +276,7 @@ impl Optimizer<'_> {
         };
 
         let op = match op {
-            BinaryOp::In | BinaryOp::InstanceOf => return,
+            BinaryOp::In | BinaryOp::InstanceOf | BinaryOp::Pipeline => return,
 
             BinaryOp::EqEq | BinaryOp::NotEq | BinaryOp::EqEqEq | BinaryOp::NotEqEq => {
                 // TODO(kdy1): Check if this is optimizable.
@@ -586,6 +586,24 @@ impl Optimizer<'_> {
                 return Some(e.take())
             }
 
+            // Calls to `pure_funcs` can be removed, but side effects of arguments are
+            // preserved.
+            Expr::Call(CallExpr {
+                span,
+                callee: ExprOrSuper::Expr(callee),
+                args,
+                ..
+            }) if self.is_pure_fn(&callee) && args.iter().all(|arg| arg.spread.is_none()) => {
+                log::trace!("ignore_return_value: Dropping a call to a pure function");
+                self.changed = true;
+
+                let mut seq = Expr::Seq(SeqExpr {
+                    span: *span,
+                    exprs: args.take().into_iter().map(|arg| arg.expr).collect(),
+                });
+                return self.ignore_return_value(&mut seq);
+            }
+
             // Pure calls can be removed
             Expr::Call(CallExpr {
                 callee: ExprOrSuper::Expr(callee),
@@ -619,6 +637,24 @@ impl Optimizer<'_> {
             | Expr::PrivateName(_)
             | Expr::Update(_) => return Some(e.take()),
 
+            // Property accesses can be removed if getters are pure.
+            Expr::Member(MemberExpr {
+                span,
+                obj: ExprOrSuper::Expr(obj),
+                prop,
+                computed,
+            }) if self.is_pure_member_access(&obj, &prop, *computed) => {
+                log::trace!("ignore_return_value: Dropping a pure property access");
+                self.changed = true;
+
+                let mut exprs = vec![obj.take()];
+                if *computed {
+                    exprs.push(prop.take());
+                }
+                let mut seq = Expr::Seq(SeqExpr { span: *span, exprs });
+                return self.ignore_return_value(&mut seq);
+            }
+
             // We drop `f.g` in
             //
             // function f() {
@@ -1,11 +1,14 @@
 use super::Ctx;
 use super::Optimizer;
+use crate::option::PureGetterOption;
 use std::ops::Deref;
 use std::ops::DerefMut;
 use swc_common::comments::Comment;
 use swc_common::comments::CommentKind;
+use swc_common::EqIgnoreSpan;
 use swc_common::Mark;
 use swc_common::Span;
+use swc_ecma_ast::*;
 
 impl<'b> Optimizer<'b> {
     /// Check for `/** @const */`.
@@ -70,6 +73,27 @@ impl<'b> Optimizer<'b> {
         })
     }
 
+    /// Returns `true` if `callee` is listed in `pure_funcs`.
+    pub(super) fn is_pure_fn(&self, callee: &Expr) -> bool {
+        self.options
+            .pure_funcs
+            .iter()
+            .any(|f| (**f).eq_ignore_span(callee))
+    }
+
+    /// Returns `true` if reading `obj.prop` cannot have a side effect, based
+    /// on `pure_getters`.
+    pub(super) fn is_pure_member_access(&self, obj: &Expr, prop: &Expr, computed: bool) -> bool {
+        match &self.options.pure_getters {
+            PureGetterOption::Bool(v) => *v,
+            PureGetterOption::Strict => !may_be_nullish(obj),
+            PureGetterOption::Str(names) => match prop {
+                Expr::Ident(prop) if !computed => names.contains(&prop.sym),
+                _ => false,
+            },
+        }
+    }
+
     #[allow(unused)]
     pub(super) fn is_done(&mut self, span: Span) -> bool {
         let mut ctxt = span.ctxt;
@@ -123,3 +147,19 @@ impl Drop for WithCtx<'_, '_> {
         self.reducer.ctx = self.orig_ctx;
     }
 }
+
+/// Returns `false` if `e` is known to be neither `null` nor `undefined`.
+fn may_be_nullish(e: &Expr) -> bool {
+    match e {
+        Expr::Paren(e) => may_be_nullish(&e.expr),
+        Expr::Lit(Lit::Null(..)) => true,
+        Expr::Lit(..)
+        | Expr::Array(..)
+        | Expr::Object(..)
+        | Expr::Fn(..)
+        | Expr::Arrow(..)
+        | Expr::Class(..)
+        | Expr::Tpl(..) => false,
+        _ => true,
+    }
+}
@@ -100,11 +100,20 @@ pub fn optimize(
         // TODO: base54.reset();
 
         let char_freq_info = compute_char_freq(&m);
-        m.visit_mut_with(&mut name_mangler(mangle.clone(), char_freq_info));
+        m.visit_mut_with(&mut name_mangler(
+            mangle.clone(),
+            char_freq_info,
+            extra.top_level_mark,
+            options.name_cache.as_ref().map(|c| &c.vars),
+        ));
     }
 
     if let Some(property_mangle_options) = options.mangle.as_ref().and_then(|o| o.props.as_ref()) {
-        mangle_properties(&mut m, property_mangle_options.clone());
+        mangle_properties(
+            &mut m,
+            property_mangle_options.clone(),
+            options.name_cache.as_ref().map(|c| &c.props),
+        );
     }
 
     if let Some(ref mut t) = timings {
@@ -1,8 +1,11 @@
 use fxhash::FxHashMap;
 use regex::Regex;
 use serde::Deserialize;
+use serde::Deserializer;
 use serde::Serialize;
+use serde::Serializer;
 use swc_atoms::JsWord;
+use swc_common::sync::Lock;
 use swc_common::Mark;
 use swc_ecma_ast::EsVersion;
 use swc_ecma_ast::Expr;
@@ -30,6 +33,70 @@ pub struct MinifyOptions {
     pub wrap: bool,
     #[serde(default)]
     pub enclose: bool,
+    #[serde(default)]
+    pub name_cache: Option<NameCache>,
+}
+
+/// Equivalent of `nameCache` of terser.
+///
+/// Names assigned by the manglers are stored back into the cache, so passing
+/// the same cache to multiple calls to [crate::optimize] results in consistent
+/// names across outputs.
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NameCache {
+    /// Top-level variables.
+    #[serde(default)]
+    pub vars: NameCacheEntry,
+    /// Properties.
+    #[serde(default)]
+    pub props: NameCacheEntry,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct NameCacheEntry {
+    /// Original name to mangled name.
+    ///
+    /// Keys are prefixed with `$` when serialized, like terser.
+    #[serde(default, rename = "props", with = "name_cache_names")]
+    pub names: Lock<FxHashMap<JsWord, JsWord>>,
+}
+
+mod name_cache_names {
+    use fxhash::FxHashMap;
+    use serde::Deserialize;
+    use serde::Deserializer;
+    use serde::Serialize;
+    use serde::Serializer;
+    use std::collections::BTreeMap;
+    use swc_atoms::JsWord;
+    use swc_common::sync::Lock;
+
+    pub fn serialize<S>(v: &Lock<FxHashMap<JsWord, JsWord>>, s: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let v = v.lock();
+        let map = v
+            .iter()
+            .map(|(k, v)| (format!("${}", k), &**v))
+            .collect::<BTreeMap<_, _>>();
+
+        map.serialize(s)
+    }
+
+    pub fn deserialize<'de, D>(d: D) -> Result<Lock<FxHashMap<JsWord, JsWord>>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let map = FxHashMap::<String, JsWord>::deserialize(d)?;
+
+        Ok(Lock::new(
+            map.into_iter()
+                .map(|(k, v)| (k.strip_prefix('$').unwrap_or(&k).into(), v))
+                .collect(),
+        ))
+    }
 }
 
 #[derive(Debug, Clone, Copy, Serialize, Deserialize)]
@@ -73,6 +140,68 @@ pub struct ManglePropertiesOptions {
     pub regex: Option<Regex>,
 }
 
+/// Controls whether property accesses are assumed to be side-effect free.
+///
+/// This is `true`, `false`, `"strict"`, a comma-separated string of property
+/// names or an array of property names.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PureGetterOption {
+    /// `true` assumes all property accesses are pure.
+    Bool(bool),
+    /// Property accesses are pure if the object cannot be `null` or
+    /// `undefined`.
+    Strict,
+    /// Accesses to properties with these names are pure.
+    Str(Vec<JsWord>),
+}
+
+impl Default for PureGetterOption {
+    fn default() -> Self {
+        PureGetterOption::Strict
+    }
+}
+
+impl Serialize for PureGetterOption {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            PureGetterOption::Bool(v) => v.serialize(serializer),
+            PureGetterOption::Strict => "strict".serialize(serializer),
+            PureGetterOption::Str(names) => names.serialize(serializer),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for PureGetterOption {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum Repr {
+            Bool(bool),
+            Str(String),
+            Names(Vec<JsWord>),
+        }
+
+        Ok(match Repr::deserialize(deserializer)? {
+            Repr::Bool(v) => PureGetterOption::Bool(v),
+            Repr::Str(s) if s == "strict" => PureGetterOption::Strict,
+            Repr::Str(s) => PureGetterOption::Str(
+                s.split(',')
+                    .map(|v| v.trim())
+                    .filter(|v| !v.is_empty())
+                    .map(JsWord::from)
+                    .collect(),
+            ),
+            Repr::Names(names) => PureGetterOption::Str(names),
+        })
+    }
+}
+
 /// https://terser.org/docs/api-reference.html#compress-options
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -211,8 +340,18 @@ pub struct CompressOptions {
     #[serde(alias = "properties")]
     pub props: bool,
 
-    // pure_getters  : !false_by_default && "strict",
-    // pure_funcs    : null,
+    #[serde(default)]
+    #[serde(alias = "pure_getters")]
+    pub pure_getters: PureGetterOption,
+
+    /// Calls to these functions are dropped if the return value is not used.
+    /// Side effects of arguments are preserved.
+    ///
+    /// All expressions should have dummy span. Use [swc_ecma_utils::drop_span]
+    /// to remove spans.
+    #[serde(skip)]
+    pub pure_funcs: Vec<Box<Expr>>,
+
     #[serde(default)]
     #[serde(alias = "reduce_funcs")]
     pub reduce_fns: bool,
@@ -1,6 +1,7 @@
 //! Compatibility for terser config.
 
 use super::CompressOptions;
+use super::PureGetterOption;
 use super::TopLevelOptions;
 use fxhash::FxHashMap;
 use serde::Deserialize;
@@ -12,6 +13,7 @@ use swc_common::FileName;
 use swc_common::SourceMap;
 use swc_common::DUMMY_SP;
 use swc_ecma_ast::*;
+use swc_ecma_parser::error::Error;
 use swc_ecma_parser::lexer::Lexer;
 use swc_ecma_parser::Parser;
 use swc_ecma_utils::drop_span;
@@ -24,22 +26,6 @@ pub enum TerserEcmaVersion {
     Str(String),
 }
 
-#[derive(Debug, Clone, Deserialize)]
-#[serde(deny_unknown_fields)]
-#[serde(untagged)]
-pub enum TerserPureGetterOption {
-    Bool(bool),
-    #[serde(rename = "strict")]
-    Strict,
-    Str(String),
-}
-
-impl Default for TerserPureGetterOption {
-    fn default() -> Self {
-        TerserPureGetterOption::Strict
-    }
-}
-
 #[derive(Debug, Clone, Copy, Deserialize)]
 #[serde(deny_unknown_fields)]
 #[serde(untagged)]
@@ -172,7 +158,7 @@ pub struct TerserCompressorOptions {
     pub properties: Option<bool>,
 
     #[serde(default)]
-    pub pure_getters: TerserPureGetterOption,
+    pub pure_getters: Option<PureGetterOption>,
 
     #[serde(default)]
     pub pure_funcs: Vec<String>,
@@ -245,8 +231,9 @@ fn ecma_default() -> TerserEcmaVersion {
 }
 
 impl TerserCompressorOptions {
-    pub fn into_config(self, cm: Lrc<SourceMap>) -> CompressOptions {
-        CompressOptions {
+    /// Returns an error if an item of `pure_funcs` is not a valid expression.
+    pub fn into_config(self, cm: Lrc<SourceMap>) -> Result<CompressOptions, Error> {
+        Ok(CompressOptions {
             arguments: self.arguments,
             arrows: self.arrows.unwrap_or(self.defaults),
             bools: self.booleans.unwrap_or(self.defaults),
@@ -336,6 +323,28 @@ impl TerserCompressorOptions {
             negate_iife: self.negate_iife.unwrap_or(self.defaults),
             passes: self.passes,
             props: self.properties.unwrap_or(self.defaults),
+            pure_getters: self.pure_getters.unwrap_or(if self.defaults {
+                PureGetterOption::Strict
+            } else {
+                PureGetterOption::Bool(false)
+            }),
+            pure_funcs: self
+                .pure_funcs
+                .into_iter()
+                .map(|f| {
+                    let fm = cm.new_source_file(FileName::Anon, f.clone());
+
+                    let lexer = Lexer::new(
+                        Default::default(),
+                        Default::default(),
+                        SourceFileInput::from(&*fm),
+                        None,
+                    );
+                    let mut parser = Parser::new_from(lexer);
+
+                    parser.parse_expr().map(drop_span)
+                })
+                .collect::<Result<_, _>>()?,
             reduce_fns: self.reduce_funcs,
             reduce_vars: self.reduce_vars,
             sequences: self
@@ -367,7 +376,7 @@ impl TerserCompressorOptions {
             unsafe_regexp: self.unsafe_regexp,
             unsafe_undefined: self.unsafe_undefined,
             unused: self.unused.unwrap_or(self.defaults),
-        }
+        })
     }
 }
 
@@ -3,10 +3,12 @@ use super::compute_char_freq::CharFreqInfo;
 use crate::analyzer::analyze;
 use crate::analyzer::ProgramData;
 use crate::option::MangleOptions;
+use crate::option::NameCacheEntry;
 use crate::util::base54::base54;
 use fxhash::FxHashMap;
 use fxhash::FxHashSet;
 use swc_atoms::JsWord;
+use swc_common::Mark;
 use swc_common::SyntaxContext;
 use swc_ecma_ast::*;
 use swc_ecma_utils::ident::IdentLike;
@@ -17,24 +19,38 @@ use swc_ecma_visit::VisitMutWith;
 
 mod preserver;
 
-pub fn name_mangler(options: MangleOptions, _char_freq_info: CharFreqInfo) -> impl VisitMut {
+/// `cache` is used for top-level bindings, which are identified using
+/// `top_level_mark`.
+pub fn name_mangler<'a>(
+    options: MangleOptions,
+    _char_freq_info: CharFreqInfo,
+    top_level_mark: Mark,
+    cache: Option<&'a NameCacheEntry>,
+) -> impl 'a + VisitMut {
     Mangler {
         options,
+        top_level_ctxt: SyntaxContext::empty().apply_mark(top_level_mark),
+        cache,
         ..Default::default()
     }
 }
 
 #[derive(Debug, Default)]
-struct Mangler {
+struct Mangler<'a> {
     options: MangleOptions,
     n: usize,
     preserved: FxHashSet<Id>,
     preserved_symbols: FxHashSet<JsWord>,
     renamed: FxHashMap<Id, JsWord>,
     data: Option<ProgramData>,
+
+    top_level_ctxt: SyntaxContext,
+    cache: Option<&'a NameCacheEntry>,
+    /// Mangled names stored in `cache`.
+    cached_symbols: FxHashSet<JsWord>,
 }
 
-impl Mangler {
+impl Mangler<'_> {
     fn rename(&mut self, i: &mut Ident) {
         if self.preserved.contains(&i.to_id()) {
             return;
@@ -46,28 +62,59 @@ impl Mangler {
             }
         }
 
+        let id = i.to_id();
+        let cache = if i.span.ctxt == self.top_level_ctxt {
+            self.cache
+        } else {
+            None
+        };
+
         i.span.ctxt = SyntaxContext::empty();
-        if let Some(v) = self.renamed.get(&i.to_id()) {
+        if let Some(v) = self.renamed.get(&id) {
             i.sym = v.clone();
             return;
         }
 
+        if let Some(cache) = cache {
+            if let Some(v) = cache.names.lock().get(&id.0).cloned() {
+                // The cached name may be used by an identifier we preserve.
+                if !self.preserved_symbols.contains(&v) {
+                    self.renamed.insert(id, v.clone());
+                    i.sym = v;
+                    return;
+                }
+            }
+        }
+
         loop {
             let sym: JsWord = base54(self.n).into();
             self.n += 1;
-            if self.preserved_symbols.contains(&sym) {
+            if self.preserved_symbols.contains(&sym) || self.cached_symbols.contains(&sym) {
                 continue;
             }
 
-            self.renamed.insert(i.to_id(), sym.clone());
+            if let Some(cache) = cache {
+                cache
+                    .names
+                    .lock()
+                    .entry(id.0.clone())
+                    .or_insert_with(|| sym.clone());
+            }
+            self.renamed.insert(id, sym.clone());
 
             i.sym = sym.clone();
             break;
         }
     }
+
+    fn init(&mut self) {
+        if let Some(cache) = self.cache {
+            self.cached_symbols = cache.names.lock().values().cloned().collect();
+        }
+    }
 }
 
-impl VisitMut for Mangler {
+impl VisitMut for Mangler<'_> {
     noop_visit_mut_type!();
 
     fn visit_mut_module(&mut self, n: &mut Module) {
@@ -75,6 +122,7 @@ impl VisitMut for Mangler {
         self.data = Some(data);
         self.preserved = idents_to_preserve(self.options.clone(), n);
         self.preserved_symbols = self.preserved.iter().map(|v| v.0.clone()).collect();
+        self.init();
         n.visit_mut_children_with(self);
     }
 
@@ -83,6 +131,7 @@ impl VisitMut for Mangler {
         self.data = Some(data);
         self.preserved = idents_to_preserve(self.options.clone(), n);
         self.preserved_symbols = self.preserved.iter().map(|v| v.0.clone()).collect();
+        self.init();
         n.visit_mut_children_with(self);
     }
 
@@ -1,6 +1,7 @@
 use crate::analyzer::analyze;
 use crate::analyzer::ProgramData;
 use crate::option::ManglePropertiesOptions;
+use crate::option::NameCacheEntry;
 use crate::util::base54::base54;
 use once_cell::sync::Lazy;
 use std::collections::{HashMap, HashSet};
@@ -38,6 +39,8 @@ struct ManglePropertiesState {
     // Cache of already mangled names
     cache: HashMap<JsWord, JsWord>,
     private_cache: HashMap<JsWord, JsWord>,
+    // Values of `cache`
+    mangled: HashSet<JsWord>,
 
     // Numbers to pass to base54()
     n: usize,
@@ -93,10 +96,16 @@ impl ManglePropertiesState {
             if let Some(cached) = self.cache.get(name) {
                 Some(cached.clone())
             } else {
-                let n = self.n;
-                self.n += 1;
-                let mangled_name: JsWord = base54(n).into();
+                let mangled_name: JsWord = loop {
+                    let n = self.n;
+                    self.n += 1;
+                    let mangled_name: JsWord = base54(n).into();
+                    if !self.mangled.contains(&mangled_name) {
+                        break mangled_name;
+                    }
+                };
                 self.cache.insert(name.clone(), mangled_name.clone());
+                self.mangled.insert(mangled_name.clone());
                 Some(mangled_name)
             }
         } else {
@@ -122,12 +131,28 @@ impl ManglePropertiesState {
     }
 }
 
-pub fn mangle_properties<'a>(m: &mut Module, options: ManglePropertiesOptions) {
+/// If `name_cache` is given, it's used to seed the mangled names and updated
+/// with newly assigned names.
+pub fn mangle_properties<'a>(
+    m: &mut Module,
+    options: ManglePropertiesOptions,
+    name_cache: Option<&NameCacheEntry>,
+) {
     let mut state = ManglePropertiesState {
         options,
         ..Default::default()
     };
 
+    if let Some(name_cache) = name_cache {
+        state.cache = name_cache
+            .names
+            .lock()
+            .iter()
+            .map(|(k, v)| (k.clone(), v.clone()))
+            .collect();
+        state.mangled = state.cache.values().cloned().collect();
+    }
+
     let data = analyze(&*m);
     m.visit_mut_with(&mut PropertyCollector {
         state: &mut state,
@@ -135,6 +160,10 @@ pub fn mangle_properties<'a>(m: &mut Module, options: ManglePropertiesOptions) {
     });
 
     m.visit_mut_with(&mut Mangler { state: &mut state });
+
+    if let Some(name_cache) = name_cache {
+        name_cache.names.lock().extend(state.cache);
+    }
 }
 
 // Step 1 -- collect candidates to mangle
@@ -24,6 +24,7 @@ use swc_ecma_minifier::option::CompressOptions;
 use swc_ecma_minifier::option::ExtraOptions;
 use swc_ecma_minifier::option::MangleOptions;
 use swc_ecma_minifier::option::MinifyOptions;
+use swc_ecma_minifier::option::PureGetterOption;
 use swc_ecma_parser::lexer::input::SourceFileInput;
 use swc_ecma_parser::lexer::Lexer;
 use swc_ecma_parser::Parser;
@@ -82,7 +83,11 @@ fn parse_compressor_config(cm: Lrc<SourceMap>, s: &str) -> (bool, CompressOption
     let c: TerserCompressorOptions =
         serde_json::from_str(s).expect("failed to deserialize value into a compressor config");
 
-    (c.module, c.into_config(cm))
+    (
+        c.module,
+        c.into_config(cm)
+            .expect("failed to convert the compressor config"),
+    )
 }
 
 /// Tests ported from terser.
@@ -92,6 +97,52 @@ fn fixture(input: PathBuf) {
         return;
     }
 
+    run_fixture(&input)
+}
+
+/// Tests for options which are not covered by tests of terser.
+#[testing::fixture("compress/**/input.js")]
+fn compress_fixture(input: PathBuf) {
+    run_fixture(&input)
+}
+
+#[test]
+fn invalid_pure_funcs() {
+    let c: TerserCompressorOptions = serde_json::from_str(r#"{ "pure_funcs": ["a +"] }"#)
+        .expect("failed to deserialize value into a compressor config");
+
+    assert!(c.into_config(Default::default()).is_err());
+}
+
+#[test]
+fn pure_getters_option() {
+    let pure_getters = |config: &str| {
+        let c: TerserCompressorOptions = serde_json::from_str(config)
+            .expect("failed to deserialize value into a compressor config");
+
+        c.into_config(Default::default()).unwrap().pure_getters
+    };
+
+    assert_eq!(
+        pure_getters(r#"{ "pure_getters": true }"#),
+        PureGetterOption::Bool(true)
+    );
+    assert_eq!(
+        pure_getters(r#"{ "pure_getters": "strict" }"#),
+        PureGetterOption::Strict
+    );
+    assert_eq!(
+        pure_getters(r#"{ "pure_getters": "foo, bar" }"#),
+        PureGetterOption::Str(vec!["foo".into(), "bar".into()])
+    );
+    assert_eq!(pure_getters("{}"), PureGetterOption::Bool(false));
+    assert_eq!(
+        pure_getters(r#"{ "defaults": true }"#),
+        PureGetterOption::Strict
+    );
+}
+
+fn run_fixture(input: &Path) {
     let dir = input.parent().unwrap();
     let config = dir.join("config.json");
     let config = read_to_string(&config).expect("failed to read config.json");
@@ -113,7 +164,7 @@ fn fixture(input: PathBuf) {
         let mangle: Option<TestMangleOptions> =
             mangle.map(|s| serde_json::from_str(&s).expect("failed to deserialize mangle.json"));
 
-        let fm = cm.load_file(&input).expect("failed to load input.js");
+        let fm = cm.load_file(input).expect("failed to load input.js");
         let comments = SingleThreadedComments::default();
 
         eprintln!("---- {} -----\n{}", Color::Green.paint("Input"), fm.src);
@@ -0,0 +1,6 @@
+function f(a, b) {
+    console.log(a(), b);
+    Math.floor(b);
+    Math.ceil(b);
+    console.error(b);
+}
@@ -0,0 +1,5 @@
+function f(a, b) {
+    a();
+    Math.ceil(b);
+    console.error(b);
+}
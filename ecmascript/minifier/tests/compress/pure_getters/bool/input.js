@@ -0,0 +1,5 @@
+function f() {
+    ({}).foo;
+    null.foo;
+    [].length;
+}
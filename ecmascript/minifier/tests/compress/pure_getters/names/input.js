@@ -0,0 +1,7 @@
+function f(a) {
+    a.foo;
+    a.bar;
+    a.baz;
+    a["foo"];
+    a[b()].foo;
+}
@@ -0,0 +1,5 @@
+function f(a) {
+    a.baz;
+    a["foo"];
+    a[b()];
+}
@@ -0,0 +1,7 @@
+function f(a) {
+    a.foo;
+    null.foo;
+    ({}).foo;
+    [].length;
+    a[b()];
+}
@@ -0,0 +1,5 @@
+function f(a) {
+    a.foo;
+    null.foo;
+    a[b()];
+}
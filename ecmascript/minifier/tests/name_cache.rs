@@ -0,0 +1,192 @@
+use swc_atoms::JsWord;
+use swc_common::FileName;
+use swc_common::Mark;
+use swc_ecma_ast::*;
+use swc_ecma_minifier::optimize;
+use swc_ecma_minifier::option::ExtraOptions;
+use swc_ecma_minifier::option::MangleOptions;
+use swc_ecma_minifier::option::ManglePropertiesOptions;
+use swc_ecma_minifier::option::MinifyOptions;
+use swc_ecma_minifier::option::NameCache;
+use swc_ecma_parser::lexer::input::SourceFileInput;
+use swc_ecma_parser::lexer::Lexer;
+use swc_ecma_parser::Parser;
+use swc_ecma_transforms::resolver_with_mark;
+use swc_ecma_visit::FoldWith;
+
+fn mangle_props(src: &str, name_cache: NameCache) -> (Module, NameCache) {
+    mangle(
+        src,
+        MangleOptions {
+            props: Some(ManglePropertiesOptions::default()),
+            ..Default::default()
+        },
+        name_cache,
+    )
+}
+
+fn mangle(src: &str, mangle: MangleOptions, name_cache: NameCache) -> (Module, NameCache) {
+    testing::run_test2(false, |cm, _| {
+        let fm = cm.new_source_file(FileName::Anon, src.into());
+        let top_level_mark = Mark::fresh(Mark::root());
+
+        let lexer = Lexer::new(
+            Default::default(),
+            Default::default(),
+            SourceFileInput::from(&*fm),
+            None,
+        );
+        let module = Parser::new_from(lexer)
+            .parse_module()
+            .unwrap()
+            .fold_with(&mut resolver_with_mark(top_level_mark));
+
+        let options = MinifyOptions {
+            mangle: Some(mangle),
+            name_cache: Some(name_cache),
+            ..Default::default()
+        };
+        let module = optimize(
+            module,
+            None,
+            None,
+            &options,
+            &ExtraOptions { top_level_mark },
+        );
+
+        Ok((module, options.name_cache.unwrap()))
+    })
+    .unwrap()
+}
+
+fn key_of_first_prop(m: &Module) -> String {
+    match &m.body[0] {
+        ModuleItem::Stmt(Stmt::Decl(Decl::Var(var))) => match var.decls[0].init.as_deref() {
+            Some(Expr::Object(obj)) => match &obj.props[0] {
+                PropOrSpread::Prop(prop) => match &**prop {
+                    Prop::KeyValue(KeyValueProp {
+                        key: PropName::Ident(key),
+                        ..
+                    }) => key.sym.to_string(),
+                    _ => unreachable!(),
+                },
+                _ => unreachable!(),
+            },
+            _ => unreachable!(),
+        },
+        _ => unreachable!(),
+    }
+}
+
+#[test]
+fn props_are_consistent_across_runs() {
+    let (first, cache) = mangle_props(
+        "var first = { sharedProp: 1 }; first.sharedProp;",
+        Default::default(),
+    );
+    let shared = key_of_first_prop(&first);
+    assert_ne!(shared, "sharedProp");
+
+    let (second, cache) = mangle_props(
+        "var second = { sharedProp: 1, otherProp: 2 }; second.sharedProp; second.otherProp;",
+        cache,
+    );
+    assert_eq!(key_of_first_prop(&second), shared);
+
+    let names = cache.props.names.lock();
+    assert_eq!(
+        names.get(&JsWord::from("sharedProp")).map(|v| &**v),
+        Some(&*shared)
+    );
+    assert_ne!(
+        names.get(&JsWord::from("otherProp")),
+        names.get(&JsWord::from("sharedProp"))
+    );
+}
+
+fn mangle_top_level(src: &str, name_cache: NameCache) -> (Module, NameCache) {
+    mangle(
+        src,
+        MangleOptions {
+            top_level: true,
+            ..Default::default()
+        },
+        name_cache,
+    )
+}
+
+/// Returns the name of the `n`th var declaration of `m`.
+fn var_name(m: &Module, n: usize) -> String {
+    let var = match &m.body[n] {
+        ModuleItem::Stmt(Stmt::Decl(Decl::Var(var))) => var,
+        ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(ExportDecl {
+            decl: Decl::Var(var),
+            ..
+        })) => var,
+        _ => unreachable!(),
+    };
+    match &var.decls[0].name {
+        Pat::Ident(i) => i.id.sym.to_string(),
+        _ => unreachable!(),
+    }
+}
+
+#[test]
+fn vars_are_consistent_across_runs() {
+    let (first, cache) = mangle_top_level("var foo = 1; foo;", Default::default());
+    let cached = var_name(&first, 0);
+    assert_ne!(cached, "foo");
+
+    let (second, _) = mangle_top_level("var bar = 1; var foo = 2; bar; foo;", cache);
+    assert_eq!(var_name(&second, 1), cached);
+    assert_ne!(var_name(&second, 0), cached);
+}
+
+#[test]
+fn cached_var_does_not_collide_with_preserved_name() {
+    let (first, cache) = mangle_top_level("var foo = 1; foo;", Default::default());
+    let cached = var_name(&first, 0);
+
+    let (second, cache) = mangle_top_level(
+        &format!("export var {} = 1; var foo = 2; foo;", cached),
+        cache,
+    );
+    assert_eq!(var_name(&second, 0), cached);
+    let renamed = var_name(&second, 1);
+    assert_ne!(renamed, cached);
+    assert_ne!(renamed, "foo");
+
+    // Other outputs keep using the first name.
+    assert_eq!(
+        cache
+            .vars
+            .names
+            .lock()
+            .get(&JsWord::from("foo"))
+            .map(|v| v.to_string()),
+        Some(cached)
+    );
+}
+
+#[test]
+fn serialize_like_terser() {
+    let cache: NameCache = serde_json::from_str(
+        r#"{ "vars": { "props": {} }, "props": { "props": { "$foo": "a" } } }"#,
+    )
+    .unwrap();
+    assert_eq!(
+        cache
+            .props
+            .names
+            .lock()
+            .get(&JsWord::from("foo"))
+            .map(|v| &**v),
+        Some("a")
+    );
+
+    let json = serde_json::to_string(&cache).unwrap();
+    assert_eq!(
+        json,
+        r#"{"vars":{"props":{}},"props":{"props":{"$foo":"a"}}}"#
+    );
+}
@@ -158,6 +158,10 @@ pub enum SyntaxError {
     ClassProperty,
     ReadOnlyMethod,
     GeneratorConstructor,
+    AccessorMethod,
+    PipelineBodyWithoutTopic,
+    TopicRefOutsidePipeline,
+    FsharpPipelineNotSupported,
     TsBindingPatCannotBeOptional,
 
     TrailingCommaInsideImport,
@@ -448,6 +452,18 @@ impl SyntaxError {
             SyntaxError::RestPatInSetter => "Rest pattern is not allowed in setter".into(),
 
             SyntaxError::GeneratorConstructor => "A constructor cannot be generator".into(),
+            SyntaxError::AccessorMethod => {
+                "'accessor' modifier can only appear on a property declaration".into()
+            }
+            SyntaxError::PipelineBodyWithoutTopic => {
+                "Pipeline is in topic style but does not use topic reference".into()
+            }
+            SyntaxError::TopicRefOutsidePipeline => {
+                "Topic reference is used outside of the body of a pipeline".into()
+            }
+            SyntaxError::FsharpPipelineNotSupported => {
+                "F#-style pipeline is not supported yet".into()
+            }
 
             SyntaxError::TS1003 => "Expected an identifier".into(),
             SyntaxError::TS1005 => "Expected a semicolon".into(),
@@ -0,0 +1,333 @@
+//! Incremental reparsing for editors.
+//!
+//! [reparse_module] reparses only the top-level items (or the members of a
+//! top-level class) affected by an edit, and reuses other items after
+//! shifting their spans.
+
+use crate::{error::Error, lexer::Lexer, JscTarget, PResult, Parser, StringInput, Syntax};
+use swc_common::{sync::Lrc, BytePos, SourceFile, SourceMap, Span, Spanned};
+use swc_ecma_ast::*;
+use swc_ecma_visit::{VisitMut, VisitMutWith};
+
+/// Replaces `start..end` of the previous source with `text`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TextEdit {
+    /// Byte offset from the start of the previous file.
+    pub start: u32,
+    /// Byte offset from the start of the previous file.
+    pub end: u32,
+    pub text: String,
+}
+
+/// Returned by [reparse_module] if the range of an edit is out of the file or
+/// is not on char boundaries.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InvalidEdit(pub TextEdit);
+
+#[derive(Debug)]
+pub struct Reparsed {
+    /// The edited file, which is added to the [SourceMap].
+    pub fm: Lrc<SourceFile>,
+    /// Same as the result of parsing `fm` using [Parser::parse_module].
+    pub module: PResult<Module>,
+    /// Recoverable errors in the reparsed ranges.
+    pub errors: Vec<Error>,
+}
+
+/// Applies `edit` to `fm` and parses the result, reusing unaffected items of
+/// `module`, which should be parsed from `fm` using [Parser::parse_module].
+pub fn reparse_module(
+    cm: &SourceMap,
+    syntax: Syntax,
+    target: JscTarget,
+    fm: &SourceFile,
+    module: &Module,
+    edit: &TextEdit,
+) -> Result<Reparsed, InvalidEdit> {
+    let (start, end) = (edit.start as usize, edit.end as usize);
+    if start > end || !fm.src.is_char_boundary(start) || !fm.src.is_char_boundary(end) {
+        return Err(InvalidEdit(edit.clone()));
+    }
+
+    let src = format!("{}{}{}", &fm.src[..start], edit.text, &fm.src[end..]);
+    let new_fm = cm.new_source_file(fm.name.clone(), src);
+
+    let shift = Shift {
+        start: fm.start_pos + BytePos(edit.start),
+        end: fm.start_pos + BytePos(edit.end),
+        before: new_fm.start_pos.0 as i64 - fm.start_pos.0 as i64,
+        after: new_fm.start_pos.0 as i64 - fm.start_pos.0 as i64 + edit.text.len() as i64
+            - (end - start) as i64,
+    };
+
+    let mut reparser = Reparser {
+        syntax,
+        target,
+        fm: &new_fm,
+        shift: &shift,
+        errors: vec![],
+    };
+    let module = match reparser.reparse_class_body(module) {
+        Some(module) => Ok(module),
+        None => {
+            reparser.errors.clear();
+            reparser.reparse_items(module)
+        }
+    };
+
+    let errors = reparser.errors;
+    Ok(Reparsed {
+        fm: new_fm,
+        module,
+        errors,
+    })
+}
+
+struct Reparser<'a> {
+    syntax: Syntax,
+    target: JscTarget,
+    /// The edited file.
+    fm: &'a SourceFile,
+    shift: &'a Shift,
+    errors: Vec<Error>,
+}
+
+impl Reparser<'_> {
+    fn parse<F, T>(&mut self, start: BytePos, op: F) -> PResult<T>
+    where
+        F: FnOnce(&mut Parser<Lexer<StringInput>>) -> PResult<T>,
+    {
+        let input = StringInput::new(
+            &self.fm.src[(start - self.fm.start_pos).0 as usize..],
+            start,
+            self.fm.end_pos,
+        );
+        let mut parser = Parser::new_from(Lexer::new(self.syntax, self.target, input, None));
+
+        let res = op(&mut parser);
+        self.errors.extend(parser.take_errors());
+        res
+    }
+
+    fn parse_module(&mut self) -> PResult<Module> {
+        self.errors.clear();
+        let mut parser = Parser::new_from(Lexer::new(
+            self.syntax,
+            self.target,
+            StringInput::from(self.fm),
+            None,
+        ));
+        let res = parser.parse_module();
+        self.errors.extend(parser.take_errors());
+        res
+    }
+
+    /// Reparses the top-level items affected by the edit.
+    fn reparse_items(&mut self, module: &Module) -> PResult<Module> {
+        let shift = self.shift;
+
+        // The item before the edit is reparsed too, because the edit may
+        // continue it. e.g. `a` + `\n.b`
+        let first = match module
+            .body
+            .iter()
+            .position(|item| item.span().hi >= shift.start)
+        {
+            Some(0) => return self.parse_module(),
+            Some(idx) => idx - 1,
+            None if module.body.is_empty() => return self.parse_module(),
+            None => module.body.len() - 1,
+        };
+        let starts = resync_points(&module.body, first, shift);
+
+        let mut module = module.clone();
+        module.visit_mut_with(&mut ShiftSpans { shift });
+
+        let mut resync = None;
+        let items = self.parse(module.body[first].span().lo, |p| {
+            p.parse_module_items_until(&mut stop_at(&starts, &mut resync))
+        });
+        let items = match items {
+            Ok(items) => items,
+            // Reparse the whole file to get the same error as a fresh parse.
+            Err(..) => return self.parse_module(),
+        };
+
+        let rest = match resync {
+            Some(idx) => module.body.split_off(idx),
+            None => vec![],
+        };
+        module.body.truncate(first);
+        module.body.extend(items);
+        module.body.extend(rest);
+
+        if let Some(last) = module.body.last() {
+            module.span.hi = last.span().hi;
+        }
+        Ok(module)
+    }
+
+    /// Reparses the members of a top-level class if the edit is in its body.
+    ///
+    /// Returns [None] if the edit is not in a class body, or if it changes the
+    /// structure of the class.
+    fn reparse_class_body(&mut self, module: &Module) -> Option<Module> {
+        let shift = self.shift;
+
+        let idx = module.body.iter().position(|item| {
+            let span = item.span();
+            span.lo < shift.start && shift.end < span.hi
+        })?;
+        let class = top_level_class(&module.body[idx])?;
+
+        // `}` of the class body.
+        let rbrace = class.span.hi - BytePos(1);
+        if shift.end > rbrace {
+            return None;
+        }
+        let first = class
+            .body
+            .iter()
+            .position(|member| member.span().hi >= shift.start)
+            .unwrap_or(class.body.len());
+        // This ensures that the edit is after `{`.
+        let first = first.checked_sub(1)?;
+
+        let starts = resync_points(&class.body, first, shift);
+
+        let mut item = module.body[idx].clone();
+        item.visit_mut_with(&mut ShiftSpans { shift });
+
+        let class = top_level_class_mut(&mut item)?;
+        let mut resync = None;
+        let start = class.body[first].span().lo;
+        let has_super_class = class.super_class.is_some();
+        let (members, end) = self
+            .parse(start, |p| {
+                p.parse_class_members_in_body(has_super_class, &mut stop_at(&starts, &mut resync))
+            })
+            .ok()?;
+
+        let rest = match resync {
+            Some(idx) => class.body.split_off(idx),
+            // The class body should end at the same position.
+            None if end == Some(shift.lo(rbrace)) => vec![],
+            None => return None,
+        };
+        class.body.truncate(first);
+        class.body.extend(members);
+        class.body.extend(rest);
+
+        let mut module = module.clone();
+        module.visit_mut_with(&mut ShiftSpans { shift });
+        module.body[idx] = item;
+        Some(module)
+    }
+}
+
+/// Returns the new start positions of items after the edit, with their
+/// indices.
+fn resync_points<T: Spanned>(items: &[T], first: usize, shift: &Shift) -> Vec<(BytePos, usize)> {
+    items
+        .iter()
+        .enumerate()
+        .skip(first + 1)
+        .filter(|(_, item)| item.span().lo >= shift.end)
+        .map(|(idx, item)| (shift.lo(item.span().lo), idx))
+        .collect()
+}
+
+/// Returns a callback which stops the parser at one of `starts`, storing the
+/// index of the item in `resync`.
+fn stop_at<'a>(
+    starts: &'a [(BytePos, usize)],
+    resync: &'a mut Option<usize>,
+) -> impl 'a + FnMut(BytePos) -> bool {
+    move |pos| match starts.binary_search_by_key(&pos, |v| v.0) {
+        Ok(idx) => {
+            *resync = Some(starts[idx].1);
+            true
+        }
+        Err(..) => false,
+    }
+}
+
+fn top_level_class(item: &ModuleItem) -> Option<&Class> {
+    match item {
+        ModuleItem::Stmt(Stmt::Decl(Decl::Class(c)))
+        | ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(ExportDecl {
+            decl: Decl::Class(c),
+            ..
+        })) if !c.declare => Some(&c.class),
+        ModuleItem::ModuleDecl(ModuleDecl::ExportDefaultDecl(ExportDefaultDecl {
+            decl: DefaultDecl::Class(c),
+            ..
+        })) => Some(&c.class),
+        _ => None,
+    }
+}
+
+fn top_level_class_mut(item: &mut ModuleItem) -> Option<&mut Class> {
+    match item {
+        ModuleItem::Stmt(Stmt::Decl(Decl::Class(c)))
+        | ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(ExportDecl {
+            decl: Decl::Class(c),
+            ..
+        })) => Some(&mut c.class),
+        ModuleItem::ModuleDecl(ModuleDecl::ExportDefaultDecl(ExportDefaultDecl {
+            decl: DefaultDecl::Class(c),
+            ..
+        })) => Some(&mut c.class),
+        _ => None,
+    }
+}
+
+/// Maps positions in the previous file to the edited file.
+struct Shift {
+    /// Start of the edit in the previous file.
+    start: BytePos,
+    /// End of the edit in the previous file.
+    end: BytePos,
+    /// Offset of positions before the edit.
+    before: i64,
+    /// Offset of positions after the edit.
+    after: i64,
+}
+
+impl Shift {
+    /// Maps the start of a span.
+    fn lo(&self, pos: BytePos) -> BytePos {
+        if pos >= self.end {
+            offset(pos, self.after)
+        } else {
+            offset(pos, self.before)
+        }
+    }
+
+    /// Maps the end of a span.
+    fn hi(&self, pos: BytePos) -> BytePos {
+        if pos <= self.start {
+            offset(pos, self.before)
+        } else {
+            offset(pos, self.after)
+        }
+    }
+}
+
+fn offset(pos: BytePos, offset: i64) -> BytePos {
+    BytePos((pos.0 as i64 + offset) as u32)
+}
+
+struct ShiftSpans<'a> {
+    shift: &'a Shift,
+}
+
+impl VisitMut for ShiftSpans<'_> {
+    fn visit_mut_span(&mut self, span: &mut Span) {
+        if span.is_dummy() {
+            return;
+        }
+
+        *span = Span::new(self.shift.lo(span.lo), self.shift.hi(span.hi), span.ctxt);
+    }
+}
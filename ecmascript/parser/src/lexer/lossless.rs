@@ -0,0 +1,199 @@
+//! Lossless token stream for syntax highlighters and formatters.
+
+use super::Lexer;
+use crate::{
+    error::Error,
+    token::{Token, Word},
+    JscTarget, StringInput, Syntax, Tokens,
+};
+use serde::{Deserialize, Serialize};
+use std::iter::Peekable;
+use swc_common::{
+    comments::{Comment, CommentKind, SingleThreadedComments},
+    BytePos, SourceFile, Span,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum RawTokenKind {
+    Whitespace,
+    LineComment,
+    BlockComment,
+    Shebang,
+    /// Keywords, including `null`, `true` and `false`.
+    Keyword,
+    Ident,
+    /// Punctuators and operators, including `` ` ``, `${` and jsx tag
+    /// delimiters.
+    Punct,
+    Str,
+    Num,
+    BigInt,
+    Regex,
+    /// A quasi of a template literal.
+    Template,
+    JsxName,
+    JsxText,
+    /// Text which the lexer failed to tokenize.
+    Error,
+    /// Text which is not covered by any token or trivia.
+    Unknown,
+}
+
+impl<'a> From<&'a Token> for RawTokenKind {
+    fn from(token: &Token) -> Self {
+        match token {
+            Token::Word(Word::Ident(..)) => RawTokenKind::Ident,
+            Token::Word(..) => RawTokenKind::Keyword,
+            Token::Template { .. } => RawTokenKind::Template,
+            Token::Str { .. } => RawTokenKind::Str,
+            Token::Regex(..) => RawTokenKind::Regex,
+            Token::Num(..) => RawTokenKind::Num,
+            Token::BigInt(..) => RawTokenKind::BigInt,
+            Token::JSXName { .. } => RawTokenKind::JsxName,
+            Token::JSXText { .. } => RawTokenKind::JsxText,
+            Token::Shebang(..) => RawTokenKind::Shebang,
+            Token::Error(..) => RawTokenKind::Error,
+            _ => RawTokenKind::Punct,
+        }
+    }
+}
+
+/// A token or trivia with its source text.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RawToken {
+    pub kind: RawTokenKind,
+    /// Byte offset from the start of the file.
+    pub start: u32,
+    /// Byte offset from the start of the file.
+    pub end: u32,
+    pub text: String,
+}
+
+/// Splits `fm` into tokens, whitespaces and comments.
+///
+/// Concatenating `text` of the returned tokens produces the original source.
+/// Note that the lexer runs without the parser, so some tokens may be
+/// classified differently from the parser (e.g. `<` in typescript generics).
+///
+/// Returns errors reported by the lexer as the second element.
+pub fn tokenize(syntax: Syntax, target: JscTarget, fm: &SourceFile) -> (Vec<RawToken>, Vec<Error>) {
+    let comments = SingleThreadedComments::default();
+    let (tokens, mut errors) = {
+        let mut lexer = Lexer::new(syntax, target, StringInput::from(fm), Some(&comments));
+        let tokens = lexer.by_ref().collect::<Vec<_>>();
+        (tokens, lexer.take_errors())
+    };
+
+    let (leading, trailing) = comments.take_all();
+    let mut comments = leading
+        .borrow()
+        .values()
+        .chain(trailing.borrow().values())
+        .flatten()
+        .cloned()
+        .collect::<Vec<_>>();
+    comments.sort_by_key(|c| c.span.lo);
+    comments.dedup_by_key(|c| c.span.lo);
+
+    let mut builder = Builder {
+        fm,
+        pos: fm.start_pos,
+        comments: comments.into_iter().peekable(),
+        tokens: vec![],
+    };
+    for t in tokens {
+        let kind = RawTokenKind::from(&t.token);
+        if let Token::Error(err) = t.token {
+            errors.push(err);
+        }
+        builder.push(kind, t.span);
+    }
+    builder.push_trivia(fm.end_pos);
+
+    (builder.tokens, errors)
+}
+
+/// Converts `start` and `end` of `tokens` returned by [tokenize] from byte
+/// offsets to offsets in UTF-16 code units, which are used by javascript
+/// strings.
+pub fn to_utf16_offsets(tokens: &mut [RawToken]) {
+    let mut pos = 0;
+    for t in tokens {
+        t.start = pos;
+        pos += t.text.encode_utf16().count() as u32;
+        t.end = pos;
+    }
+}
+
+struct Builder<'a, I: Iterator<Item = Comment>> {
+    fm: &'a SourceFile,
+    /// End of the last token.
+    pos: BytePos,
+    comments: Peekable<I>,
+    tokens: Vec<RawToken>,
+}
+
+impl<I: Iterator<Item = Comment>> Builder<'_, I> {
+    fn push(&mut self, kind: RawTokenKind, span: Span) {
+        // Spans of error tokens may overlap with other tokens.
+        if span.lo < self.pos || span.hi < span.lo || span.hi > self.fm.end_pos {
+            return;
+        }
+
+        self.push_trivia(span.lo);
+        self.push_raw(kind, span.lo, span.hi);
+    }
+
+    /// Pushes comments and whitespaces before `end`.
+    fn push_trivia(&mut self, end: BytePos) {
+        while self.pos < end {
+            match self.comments.peek() {
+                Some(c) if c.span.lo < self.pos => {
+                    self.comments.next();
+                }
+                Some(c) if c.span.hi <= end => {
+                    let c = self.comments.next().unwrap();
+                    self.push_gap(c.span.lo);
+                    let kind = match c.kind {
+                        CommentKind::Line => RawTokenKind::LineComment,
+                        CommentKind::Block => RawTokenKind::BlockComment,
+                    };
+                    self.push_raw(kind, c.span.lo, c.span.hi);
+                }
+                _ => self.push_gap(end),
+            }
+        }
+    }
+
+    /// Pushes text which is not a token nor a comment.
+    fn push_gap(&mut self, end: BytePos) {
+        if self.pos >= end {
+            return;
+        }
+
+        let kind = if self.slice(self.pos, end).chars().all(char::is_whitespace) {
+            RawTokenKind::Whitespace
+        } else {
+            RawTokenKind::Unknown
+        };
+        self.push_raw(kind, self.pos, end);
+    }
+
+    fn push_raw(&mut self, kind: RawTokenKind, lo: BytePos, hi: BytePos) {
+        let text = self.slice(lo, hi).to_string();
+        self.tokens.push(RawToken {
+            kind,
+            start: (lo - self.fm.start_pos).0,
+            end: (hi - self.fm.start_pos).0,
+            text,
+        });
+        self.pos = hi;
+    }
+
+    fn slice(&self, lo: BytePos, hi: BytePos) -> &str {
+        let start = self.fm.start_pos;
+        &self.fm.src[(lo - start).0 as usize..(hi - start).0 as usize]
+    }
+}
@@ -22,6 +22,7 @@ use swc_ecma_ast::op;
 
 pub mod input;
 mod jsx;
+pub mod lossless;
 mod number;
 mod state;
 #[cfg(test)]
@@ -333,6 +334,15 @@ impl<'a, I: Input> Lexer<'a, I> {
                     })));
                 }
 
+                // '|>'
+                if c == '|'
+                    && self.input.cur() == Some('>')
+                    && self.syntax.pipeline_operator().is_some()
+                {
+                    self.input.bump();
+                    return Ok(Some(BinOp(Pipeline)));
+                }
+
                 // '||', '&&'
                 if self.input.cur() == Some(c) {
                     self.input.bump();
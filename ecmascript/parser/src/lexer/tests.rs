@@ -1322,3 +1322,67 @@ fn normalize_tpl_carriage_return() {
         lex_tokens(Syntax::default(), "`\n`")
     );
 }
+
+#[test]
+fn lossless_tokens() {
+    use super::lossless::{tokenize, RawTokenKind::*};
+
+    let src = "#!/usr/bin/env node\n/* a */ foo(`x${1n}y`) // b\n";
+    ::testing::run_test(false, |cm, _| {
+        let fm = cm.new_source_file(swc_common::FileName::Anon, src.into());
+        let (tokens, errors) = tokenize(Syntax::default(), Default::default(), &fm);
+        assert_eq!(errors, vec![]);
+
+        assert_eq!(tokens.iter().map(|t| &*t.text).collect::<String>(), src);
+        assert_eq!(
+            tokens
+                .iter()
+                .map(|t| (t.kind, &*t.text))
+                .collect::<Vec<_>>(),
+            vec![
+                (Shebang, "#!/usr/bin/env node"),
+                (Whitespace, "\n"),
+                (BlockComment, "/* a */"),
+                (Whitespace, " "),
+                (Ident, "foo"),
+                (Punct, "("),
+                (Punct, "`"),
+                (Template, "x"),
+                (Punct, "${"),
+                (BigInt, "1n"),
+                (Punct, "}"),
+                (Template, "y"),
+                (Punct, "`"),
+                (Punct, ")"),
+                (Whitespace, " "),
+                (LineComment, "// b"),
+                (Whitespace, "\n"),
+            ]
+        );
+        assert_eq!((tokens[4].start, tokens[4].end), (28, 31));
+
+        Ok(())
+    })
+    .unwrap();
+}
+
+#[test]
+fn lossless_tokens_utf16_offsets() {
+    use super::lossless::{to_utf16_offsets, tokenize};
+
+    let src = "'한글' + \"😀\"";
+    ::testing::run_test(false, |cm, _| {
+        let fm = cm.new_source_file(swc_common::FileName::Anon, src.into());
+        let (mut tokens, _) = tokenize(Syntax::default(), Default::default(), &fm);
+        assert_eq!((tokens[4].start, tokens[4].end), (11, 17));
+
+        to_utf16_offsets(&mut tokens);
+        assert_eq!(
+            tokens.iter().map(|t| (t.start, t.end)).collect::<Vec<_>>(),
+            vec![(0, 4), (4, 5), (5, 6), (6, 7), (7, 11)]
+        );
+
+        Ok(())
+    })
+    .unwrap();
+}
@@ -30,6 +30,11 @@
 //! }
 //! ```
 //!
+//! For editor tooling, `Parser::parse_module_recovering` always returns a
+//! module. Malformed statements, expressions and class members are replaced
+//! with placeholder nodes, and errors can be obtained using
+//! `Parser::take_errors`.
+//!
 //! # Example (lexer)
 //!
 //! See `lexer.rs` in examples directory.
@@ -99,10 +104,12 @@ pub use self::{
 use serde::{Deserialize, Serialize};
 use swc_common::Span;
 pub use swc_ecma_ast::EsVersion as JscTarget;
+pub use swc_ecma_ast::TopicToken;
 
 #[macro_use]
 mod macros;
 pub mod error;
+pub mod incremental;
 pub mod lexer;
 mod parser;
 pub mod token;
@@ -204,6 +211,41 @@ impl Syntax {
         true
     }
 
+    pub fn pipeline_operator(self) -> Option<PipelineOperator> {
+        match self {
+            Syntax::Es(EsConfig {
+                pipeline_operator, ..
+            }) => pipeline_operator,
+            _ => None,
+        }
+    }
+
+    /// Topic token of the hack-style pipeline operator.
+    pub fn topic_token(self) -> Option<TopicToken> {
+        match self.pipeline_operator() {
+            Some(PipelineOperator::Hack { topic_token }) => Some(topic_token),
+            _ => None,
+        }
+    }
+
+    /// `accessor` class members of the 2022-03 version of the decorators
+    /// proposal.
+    ///
+    /// Stage 3.
+    pub fn auto_accessors(self) -> bool {
+        match self {
+            Syntax::Es(EsConfig {
+                auto_accessors: true,
+                ..
+            })
+            | Syntax::Typescript(TsConfig {
+                auto_accessors: true,
+                ..
+            }) => true,
+            _ => false,
+        }
+    }
+
     pub fn decorators_before_export(self) -> bool {
         match self {
             Syntax::Es(EsConfig {
@@ -303,6 +345,11 @@ pub struct TsConfig {
     /// Stage 3.
     #[serde(default)]
     pub import_assertions: bool,
+
+    /// `accessor` class members, which are enabled by the 2022-03 version of
+    /// decorators.
+    #[serde(skip, default)]
+    pub auto_accessors: bool,
 }
 
 #[derive(Debug, Clone, Copy, Default, Serialize, Deserialize)]
@@ -371,6 +418,32 @@ pub struct EsConfig {
     /// Stage 3.
     #[serde(default)]
     pub import_assertions: bool,
+
+    /// Enables the pipeline operator (`|>`), following the given proposal.
+    ///
+    /// Stage 2.
+    #[serde(default)]
+    pub pipeline_operator: Option<PipelineOperator>,
+
+    /// `accessor` class members, which are enabled by the 2022-03 version of
+    /// decorators.
+    #[serde(skip, default)]
+    pub auto_accessors: bool,
+}
+
+/// Proposal of the pipeline operator to follow.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "proposal", rename_all = "camelCase")]
+pub enum PipelineOperator {
+    /// `x |> f` calls `f` with `x`.
+    Minimal,
+    /// F#-style pipeline, which is not supported yet. Pipelines are reported
+    /// as an error.
+    Fsharp,
+    /// `x |> f(%)` evaluates the right hand side with the topic token
+    /// referring to `x`.
+    #[serde(rename_all = "camelCase")]
+    Hack { topic_token: TopicToken },
 }
 
 /// Syntactic context.
@@ -412,6 +485,9 @@ pub struct Context {
 
     /// If true, `:` should not be treated as a type annotation.
     in_case_cond: bool,
+
+    /// If true, the topic token of hack-style pipeline is allowed.
+    in_pipeline_body: bool,
 }
 
 #[cfg(test)]
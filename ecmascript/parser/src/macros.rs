@@ -41,6 +41,9 @@ macro_rules! tok {
     ('|') => {
         crate::token::Token::BinOp(crate::token::BinOpToken::BitOr)
     };
+    ("|>") => {
+        crate::token::Token::BinOp(crate::token::BinOpToken::Pipeline)
+    };
 
     (',') => {
         crate::token::Token::Comma
@@ -353,6 +356,9 @@ macro_rules! tok {
     ("override") => {
         crate::token::Token::Word(crate::token::Word::Ident(swc_atoms::js_word!("override")))
     };
+    ("accessor") => {
+        crate::token::Token::Word(crate::token::Word::Ident(swc_atoms::js_word!("accessor")))
+    };
     ("undefined") => {
         crate::token::Token::Word(crate::token::Word::Ident(swc_atoms::js_word!("undefined")))
     };
@@ -276,8 +276,46 @@ impl<'a, I: Tokens> Parser<I> {
     }
 
     fn parse_class_body(&mut self) -> PResult<Vec<ClassMember>> {
+        self.parse_class_members_until(&mut |_| false)
+    }
+
+    /// Parses members of a class body starting in the middle of it.
+    ///
+    /// This stops before `}` or if `stop` returns true for the start of the
+    /// next member. Returns the position of `}` as the second element if the
+    /// parser stopped before it.
+    pub(crate) fn parse_class_members_in_body(
+        &mut self,
+        has_super_class: bool,
+        stop: &mut dyn FnMut(BytePos) -> bool,
+    ) -> PResult<(Vec<ClassMember>, Option<BytePos>)> {
+        let ctx = Context {
+            module: true,
+            strict: true,
+            has_super_class,
+            ..self.ctx()
+        };
+        let members = self.with_ctx(ctx).parse_class_members_until(stop)?;
+        let rbrace = if is!(self, '}') {
+            Some(cur_pos!(self))
+        } else {
+            None
+        };
+
+        Ok((members, rbrace))
+    }
+
+    fn parse_class_members_until(
+        &mut self,
+        stop: &mut dyn FnMut(BytePos) -> bool,
+    ) -> PResult<Vec<ClassMember>> {
         let mut elems = vec![];
-        while !eof!(self) && !is!(self, '}') {
+        while {
+            if self.should_recover() {
+                self.skip_error_tokens();
+            }
+            !eof!(self) && !is!(self, '}') && !stop(cur_pos!(self))
+        } {
             if eat_exact!(self, ';') {
                 let span = self.input.prev_span();
                 elems.push(ClassMember::Empty(EmptyStmt {
@@ -286,7 +324,19 @@ impl<'a, I: Tokens> Parser<I> {
                 continue;
             }
 
-            elems.push(self.parse_class_member()?);
+            let start = cur_pos!(self);
+            let ctx = self.ctx();
+            match self.parse_class_member() {
+                Ok(member) => elems.push(member),
+                Err(err) if self.should_recover() => {
+                    self.recover_from(err);
+                    self.set_ctx(ctx);
+
+                    self.skip_to_boundary(start);
+                    elems.push(self.invalid_class_member(start));
+                }
+                Err(err) => return Err(err),
+            }
         }
         Ok(elems)
     }
@@ -359,6 +409,7 @@ impl<'a, I: Tokens> Parser<I> {
                     false,
                     false,
                     false,
+                    false,
                 );
             } else if accessibility.is_none() {
                 self.emit_err(self.input.prev_span(), SyntaxError::TS1031);
@@ -417,6 +468,7 @@ impl<'a, I: Tokens> Parser<I> {
                     declare,
                     false,
                     false,
+                    false,
                 );
             } else {
                 // TODO: error if static contains escape
@@ -522,6 +574,43 @@ impl<'a, I: Tokens> Parser<I> {
             }
         }
 
+        // `accessor` is a modifier only if a property name follows it on the same line.
+        let is_accessor = self.input.syntax().auto_accessors()
+            && is!(self, "accessor")
+            && !self.input.has_linebreak_between_cur_and_peeked()
+            && match self.input.peek() {
+                Some(Token::Word(..))
+                | Some(Token::Str { .. })
+                | Some(Token::Num(..))
+                | Some(Token::BigInt(..))
+                | Some(Token::LBracket)
+                | Some(Token::Hash) => true,
+                _ => false,
+            };
+        if is_accessor {
+            assert_and_bump!(self, "accessor");
+
+            let key = self.parse_class_prop_name()?;
+            let is_optional = self.input.syntax().typescript() && eat!(self, '?');
+            if self.is_class_method()? {
+                syntax_error!(self, span!(self, start), SyntaxError::AccessorMethod);
+            }
+
+            return self.make_property(
+                start,
+                decorators,
+                accessibility,
+                key,
+                is_static,
+                is_optional,
+                readonly.is_some(),
+                declare,
+                is_abstract,
+                is_override,
+                true,
+            );
+        }
+
         if eat!(self, '*') {
             // generator method
             let key = self.parse_class_prop_name()?;
@@ -706,6 +795,7 @@ impl<'a, I: Tokens> Parser<I> {
                 declare,
                 is_abstract,
                 is_override,
+                false,
             );
         }
 
@@ -845,6 +935,7 @@ impl<'a, I: Tokens> Parser<I> {
         declare: bool,
         is_abstract: bool,
         is_override: bool,
+        is_accessor: bool,
     ) -> PResult<ClassMember> {
         if !self.input.syntax().class_props() {
             syntax_error!(self, span!(self, start), SyntaxError::ClassProperty)
@@ -896,6 +987,7 @@ impl<'a, I: Tokens> Parser<I> {
                     definite,
                     type_ann,
                     computed: false,
+                    is_accessor,
                 }
                 .into(),
                 Either::Right(key) => ClassProp {
@@ -922,6 +1014,7 @@ impl<'a, I: Tokens> Parser<I> {
                     declare,
                     definite,
                     type_ann,
+                    is_accessor,
                 }
                 .into(),
             })
@@ -1436,4 +1529,33 @@ mod tests {
             }))
         );
     }
+
+    #[test]
+    fn accessor() {
+        let class = match *test_parser(
+            "(class { accessor foo = 1; accessor #bar; accessor; accessor() {} })",
+            Syntax::Typescript(crate::TsConfig {
+                auto_accessors: true,
+                ..Default::default()
+            }),
+            |p| p.parse_expr(),
+        ) {
+            Expr::Paren(ParenExpr { expr, .. }) => match *expr {
+                Expr::Class(c) => c.class,
+                _ => unreachable!(),
+            },
+            _ => unreachable!(),
+        };
+
+        let accessors: Vec<_> = class
+            .body
+            .iter()
+            .map(|member| match member {
+                ClassMember::ClassProp(p) => Some(p.is_accessor),
+                ClassMember::PrivateProp(p) => Some(p.is_accessor),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(accessors, vec![Some(true), Some(true), Some(false), None]);
+    }
 }
@@ -1,5 +1,8 @@
 use super::{pat::PatType, util::ExprExt, *};
-use crate::{lexer::TokenContext, token::AssignOpToken};
+use crate::{
+    lexer::TokenContext,
+    token::{AssignOpToken, BinOpToken},
+};
 use either::Either;
 use swc_atoms::js_word;
 use swc_common::{ast_node, Spanned};
@@ -220,6 +223,25 @@ impl<'a, I: Tokens> Parser<I> {
             .map(|s| s == start)
             .unwrap_or(false);
 
+        if let Some(token) = self.input.syntax().topic_token() {
+            let is_topic = match (token, self.input.cur()) {
+                (TopicToken::Percent, Some(Token::BinOp(BinOpToken::Mod)))
+                | (TopicToken::Caret, Some(Token::BinOp(BinOpToken::BitXor))) => true,
+                _ => false,
+            };
+
+            if is_topic {
+                self.input.bump();
+                let span = span!(self, start);
+                if !self.ctx().in_pipeline_body {
+                    self.emit_err(span, SyntaxError::TopicRefOutsidePipeline);
+                }
+                self.state.topic_ref_count += 1;
+
+                return Ok(Box::new(Expr::TopicRef(TopicRef { span, token })));
+            }
+        }
+
         match self.input.cur() {
             Some(tok) => match tok {
                 tok!("this") => {
@@ -1,7 +1,8 @@
 //! Parser for unary operations and binary operations.
 use super::*;
-use crate::token::Keyword;
+use crate::{token::Keyword, PipelineOperator};
 use log::trace;
+use std::mem::replace;
 use swc_common::Spanned;
 
 impl<'a, I: Tokens> Parser<I> {
@@ -44,6 +45,66 @@ impl<'a, I: Tokens> Parser<I> {
     ///
     /// `parseExprOp`
     pub(in crate::parser) fn parse_bin_op_recursively(
+        &mut self,
+        left: Box<Expr>,
+        min_prec: u8,
+    ) -> PResult<Box<Expr>> {
+        let mut left = self.parse_bin_op_recursively_without_pipeline(left, min_prec)?;
+
+        // `|>` has lower precedence than any other binary operator and is left
+        // associative.
+        if min_prec == 0 {
+            while is!(self, "|>") {
+                left = self.parse_pipeline(left)?;
+            }
+        }
+
+        Ok(left)
+    }
+
+    /// Parses the right hand side of `|>`.
+    fn parse_pipeline(&mut self, left: Box<Expr>) -> PResult<Box<Expr>> {
+        trace_cur!(self, parse_pipeline);
+
+        let op_span = self.input.cur_span();
+        assert_and_bump!(self, "|>");
+
+        if self.input.syntax().pipeline_operator() == Some(PipelineOperator::Fsharp) {
+            self.emit_err(op_span, SyntaxError::FsharpPipelineNotSupported);
+        }
+
+        let is_hack = self.input.syntax().topic_token().is_some();
+        let prev_topic_ref_count = replace(&mut self.state.topic_ref_count, 0);
+
+        let right = if is_hack {
+            // The body of a hack-style pipeline is an assignment expression.
+            let ctx = Context {
+                in_pipeline_body: true,
+                ..self.ctx()
+            };
+            self.with_ctx(ctx).parse_assignment_expr()?
+        } else {
+            let right = self.parse_unary_expr()?;
+            self.parse_bin_op_recursively_without_pipeline(right, 0)?
+        };
+
+        let topic_ref_count = replace(&mut self.state.topic_ref_count, prev_topic_ref_count);
+        if is_hack && topic_ref_count == 0 {
+            self.emit_err(
+                Span::new(op_span.lo(), right.span().hi(), Default::default()),
+                SyntaxError::PipelineBodyWithoutTopic,
+            );
+        }
+
+        Ok(Box::new(Expr::Bin(BinExpr {
+            span: Span::new(left.span().lo(), right.span().hi(), Default::default()),
+            op: op!("|>"),
+            left,
+            right,
+        })))
+    }
+
+    fn parse_bin_op_recursively_without_pipeline(
         &mut self,
         mut left: Box<Expr>,
         mut min_prec: u8,
@@ -231,6 +292,12 @@ impl<'a, I: Tokens> Parser<I> {
         trace_cur!(self, parse_unary_expr);
         let start = cur_pos!(self);
 
+        if self.should_recover() {
+            if let Some(expr) = self.recover_missing_expr() {
+                return Ok(expr);
+            }
+        }
+
         if !self.input.syntax().jsx() && self.input.syntax().typescript() && eat!(self, '<') {
             if eat!(self, "const") {
                 expect!(self, '>');
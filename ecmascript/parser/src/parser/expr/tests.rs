@@ -487,3 +487,76 @@ fn bench_member_expr_es(b: &mut Bencher) {
         Ok(())
     });
 }
+
+#[test]
+fn pipeline_hack() {
+    assert_eq_ignore_span!(
+        test_parser(
+            "a |> % + 1",
+            Syntax::Es(EsConfig {
+                pipeline_operator: Some(crate::PipelineOperator::Hack {
+                    topic_token: crate::TopicToken::Percent,
+                }),
+                ..Default::default()
+            }),
+            |p| p.parse_expr()
+        ),
+        Box::new(Expr::Bin(BinExpr {
+            span,
+            op: op!("|>"),
+            left: Box::new(Expr::Ident(Ident::new("a".into(), span))),
+            right: Box::new(Expr::Bin(BinExpr {
+                span,
+                op: op!(bin, "+"),
+                left: Box::new(Expr::TopicRef(TopicRef {
+                    span,
+                    token: TopicToken::Percent,
+                })),
+                right: Box::new(Expr::Lit(Lit::Num(Number { span, value: 1.0 }))),
+            })),
+        }))
+    );
+}
+
+#[test]
+fn pipeline_hack_assignment_body() {
+    assert_eq_ignore_span!(
+        test_parser(
+            "a |> b = %",
+            Syntax::Es(EsConfig {
+                pipeline_operator: Some(crate::PipelineOperator::Hack {
+                    topic_token: crate::TopicToken::Percent,
+                }),
+                ..Default::default()
+            }),
+            |p| p.parse_expr()
+        ),
+        Box::new(Expr::Bin(BinExpr {
+            span,
+            op: op!("|>"),
+            left: Box::new(Expr::Ident(Ident::new("a".into(), span))),
+            right: Box::new(Expr::Assign(AssignExpr {
+                span,
+                op: op!("="),
+                left: PatOrExpr::Pat(Box::new(Pat::Ident(Ident::new("b".into(), span).into()))),
+                right: Box::new(Expr::TopicRef(TopicRef {
+                    span,
+                    token: TopicToken::Percent,
+                })),
+            })),
+        }))
+    );
+}
+
+#[test]
+#[should_panic(expected = "F#-style pipeline is not supported")]
+fn pipeline_fsharp() {
+    test_parser(
+        "a |> f",
+        Syntax::Es(EsConfig {
+            pipeline_operator: Some(crate::PipelineOperator::Fsharp),
+            ..Default::default()
+        }),
+        |p| p.parse_expr(),
+    );
+}
@@ -27,6 +27,7 @@ pub mod input;
 mod jsx;
 mod object;
 mod pat;
+mod recovery;
 mod stmt;
 #[cfg(test)]
 mod tests;
@@ -41,6 +42,8 @@ pub type PResult<T> = Result<T, Error>;
 pub struct Parser<I: Tokens> {
     /// [false] while backtracking
     emit_err: bool,
+    /// [true] while parsing in the fault-tolerant mode.
+    recover: bool,
     state: State,
     input: Buffer<I>,
 }
@@ -50,6 +53,8 @@ struct State {
     labels: Vec<JsWord>,
     /// Start position of an assignment expression.
     potential_arrow_start: Option<BytePos>,
+    /// Number of topic references in the current body of hack-style pipeline.
+    topic_ref_count: usize,
 }
 
 impl<'a, I: Input> Parser<Lexer<'a, I>> {
@@ -62,6 +67,7 @@ impl<I: Tokens> Parser<I> {
     pub fn new_from(input: I) -> Self {
         Parser {
             emit_err: true,
+            recover: false,
             state: Default::default(),
             input: Buffer::new(input),
         }
@@ -184,6 +190,34 @@ impl<I: Tokens> Parser<I> {
         })
     }
 
+    /// Parses a module without giving up on syntax errors.
+    ///
+    /// This is designed for editor tooling. Malformed statements and
+    /// expressions are replaced with [Expr::Invalid], and malformed class
+    /// members are replaced with [ClassMember::Empty]. The parser continues
+    /// from the next statement boundary, and all errors can be obtained using
+    /// [Parser::take_errors].
+    pub fn parse_module_recovering(&mut self) -> Module {
+        let start = cur_pos!(self);
+        let old_recover = self.recover;
+        self.recover = true;
+
+        let module = match self.parse_module() {
+            Ok(module) => module,
+            Err(err) => {
+                self.recover_from(err);
+                Module {
+                    span: span!(self, start),
+                    body: vec![],
+                    shebang: None,
+                }
+            }
+        };
+
+        self.recover = old_recover;
+        module
+    }
+
     fn parse_shebang(&mut self) -> PResult<Option<JsWord>> {
         match cur!(self, false) {
             Ok(&Token::Shebang(..)) => match bump!(self) {
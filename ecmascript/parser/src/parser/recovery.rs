@@ -0,0 +1,114 @@
+//! Error recovery used by [Parser::parse_module_recovering].
+use super::*;
+use crate::error::SyntaxError;
+
+impl<'a, I: Tokens> Parser<I> {
+    /// Returns true if a syntax error should be recorded instead of aborting
+    /// the parsing.
+    ///
+    /// Note that errors are never recovered while backtracking.
+    pub(super) fn should_recover(&self) -> bool {
+        self.recover && self.emit_err
+    }
+
+    /// Records an error which is recovered from.
+    pub(super) fn recover_from(&self, err: Error) {
+        self.input_ref().add_error(err);
+    }
+
+    /// Records and skips error tokens emitted by the lexer.
+    pub(super) fn skip_error_tokens(&mut self) {
+        while let Some(&Token::Error(..)) = self.input.cur() {
+            match self.input.bump() {
+                Token::Error(err) => self.recover_from(err),
+                _ => unreachable!(),
+            }
+        }
+    }
+
+    /// Skips tokens until the start of the next statement or class member.
+    ///
+    /// `start` is the start of the statement which failed to parse. This
+    /// stops after `;`, or before `}` or a token on a new line if at least one
+    /// token is consumed since `start`. Brackets are skipped as a whole.
+    pub(super) fn skip_to_boundary(&mut self, start: BytePos) {
+        let mut depth = 0usize;
+
+        loop {
+            self.skip_error_tokens();
+
+            let progressed = self.input.last_pos() > start;
+            let had_line_break = self.input.had_line_break_before_cur();
+            let (is_semi, is_open, is_close, is_rbrace) = match self.input.cur() {
+                Some(cur) => (
+                    *cur == tok!(';'),
+                    *cur == tok!('{')
+                        || *cur == tok!('(')
+                        || *cur == tok!('[')
+                        || *cur == tok!("${"),
+                    *cur == tok!('}') || *cur == tok!(')') || *cur == tok!(']'),
+                    *cur == tok!('}'),
+                ),
+                None => return,
+            };
+
+            if depth == 0 {
+                if is_semi {
+                    self.input.bump();
+                    return;
+                }
+                if progressed && (is_rbrace || had_line_break) {
+                    return;
+                }
+            }
+
+            if is_open {
+                depth += 1;
+            } else if is_close {
+                depth = depth.saturating_sub(1);
+            }
+            self.input.bump();
+        }
+    }
+
+    /// Creates a placeholder for a statement which failed to parse.
+    pub(super) fn invalid_stmt(&mut self, start: BytePos) -> Stmt {
+        let span = span!(self, start);
+
+        Stmt::Expr(ExprStmt {
+            span,
+            expr: Box::new(Expr::Invalid(Invalid { span })),
+        })
+    }
+
+    /// Creates a placeholder for a class member which failed to parse.
+    pub(super) fn invalid_class_member(&mut self, start: BytePos) -> ClassMember {
+        ClassMember::Empty(EmptyStmt {
+            span: span!(self, start),
+        })
+    }
+
+    /// Returns an invalid expression if the current token can only follow an
+    /// expression, like `;` in `a = ;`.
+    pub(super) fn recover_missing_expr(&mut self) -> Option<Box<Expr>> {
+        let pos = cur_pos!(self);
+        let missing = match self.input.cur() {
+            Some(cur) => {
+                *cur == tok!(';')
+                    || *cur == tok!(',')
+                    || *cur == tok!(')')
+                    || *cur == tok!(']')
+                    || *cur == tok!('}')
+            }
+            None => true,
+        };
+        if !missing {
+            return None;
+        }
+
+        let span = Span::new(pos, pos, Default::default());
+        self.recover_from(make_error!(self, span, SyntaxError::TS1109));
+
+        Some(Box::new(Expr::Invalid(Invalid { span })))
+    }
+}
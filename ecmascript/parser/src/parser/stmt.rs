@@ -22,10 +22,43 @@ impl<'a, I: Tokens> Parser<I> {
 
         let mut stmts = vec![];
         while {
+            if self.should_recover() {
+                self.skip_error_tokens();
+            }
             let c = cur!(self, false).ok();
             c != end
         } {
-            let stmt = self.parse_stmt_like(true, top_level)?;
+            if self.should_recover() && self.input.cur().is_none() {
+                // Unclosed block
+                self.recover_from(make_error!(self, self.input.cur_span(), SyntaxError::Eof));
+                self.set_ctx(old_ctx);
+                return Ok(stmts);
+            }
+
+            let start = cur_pos!(self);
+            let ctx = self.ctx();
+            let labels = self.state.labels.len();
+            let stmt = match self.parse_stmt_like(true, top_level) {
+                Ok(stmt) if !self.should_recover() || cur_pos!(self) != start => stmt,
+                res => {
+                    // `res` is `Ok` if the statement is an invalid expression which
+                    // consumed no token, like `}` at the top level.
+                    match res {
+                        Err(err) if self.should_recover() => self.recover_from(err),
+                        Err(err) => return Err(err),
+                        Ok(..) => {}
+                    }
+                    self.set_ctx(ctx);
+                    self.state.labels.truncate(labels);
+
+                    self.skip_to_boundary(start);
+                    stmts.push(self.invalid_stmt(start).into());
+                    if self.input.cur().is_none() {
+                        break;
+                    }
+                    continue;
+                }
+            };
             if allow_directives {
                 allow_directives = false;
                 if stmt.is_use_strict() {
@@ -48,7 +81,7 @@ impl<'a, I: Tokens> Parser<I> {
             stmts.push(stmt);
         }
 
-        if end.is_some() {
+        if end.is_some() && self.input.cur().is_some() {
             bump!(self);
         }
 
@@ -57,6 +90,26 @@ impl<'a, I: Tokens> Parser<I> {
         Ok(stmts)
     }
 
+    /// Parses module items until the input ends or `stop` returns true for the
+    /// start of the next item.
+    pub(crate) fn parse_module_items_until(
+        &mut self,
+        stop: &mut dyn FnMut(BytePos) -> bool,
+    ) -> PResult<Vec<ModuleItem>> {
+        let ctx = Context {
+            module: true,
+            strict: true,
+            ..self.ctx()
+        };
+        self.set_ctx(ctx);
+
+        let mut items = vec![];
+        while !eof!(self) && !stop(cur_pos!(self)) {
+            items.push(self.parse_stmt_like(true, true)?);
+        }
+        Ok(items)
+    }
+
     pub fn parse_stmt(&mut self, top_level: bool) -> PResult<Stmt> {
         trace_cur!(self, parse_stmt);
         self.parse_stmt_like(false, top_level)
@@ -68,3 +68,131 @@ fn parse_program_module_error_01() {
         ",
     );
 }
+
+/// Parses `src` using [Parser::parse_module_recovering] and returns messages
+/// of the recovered errors.
+fn recovering(src: &'static str) -> (Module, Vec<String>) {
+    test_parser(src, Default::default(), |p| {
+        let module = p.parse_module_recovering();
+        let errors = p
+            .take_errors()
+            .into_iter()
+            .map(|err| err.kind().msg().to_string())
+            .collect();
+
+        Ok((module, errors))
+    })
+}
+
+fn is_invalid_stmt(item: &ModuleItem) -> bool {
+    match item {
+        ModuleItem::Stmt(Stmt::Expr(ExprStmt { expr, .. })) => match **expr {
+            Expr::Invalid(..) => true,
+            _ => false,
+        },
+        _ => false,
+    }
+}
+
+#[test]
+fn recovery_stmt() {
+    let (module, errors) = recovering(
+        "
+        const a = 1;
+        let b = +;
+        foo(;
+        const c = 2;
+        ",
+    );
+
+    assert_eq!(module.body.len(), 4);
+    assert!(!is_invalid_stmt(&module.body[1]));
+    assert!(is_invalid_stmt(&module.body[2]));
+    assert!(!is_invalid_stmt(&module.body[3]));
+
+    assert_eq!(errors.len(), 3);
+    assert_eq!(errors[0], "Expression expected");
+    assert_eq!(errors[1], "Expression expected");
+}
+
+#[test]
+fn recovery_unmatched_brace() {
+    let (module, errors) = recovering(
+        "
+        a;
+        }
+        b;
+        ",
+    );
+
+    assert_eq!(module.body.len(), 3);
+    assert!(is_invalid_stmt(&module.body[1]));
+    assert_eq!(errors.len(), 1);
+}
+
+#[test]
+fn recovery_nested_block() {
+    let (module, errors) = recovering(
+        "
+        function f() {
+            let x = ;
+            if (x {
+            }
+        }
+        const y = 1;
+        ",
+    );
+
+    assert_eq!(module.body.len(), 2);
+    match &module.body[0] {
+        ModuleItem::Stmt(Stmt::Decl(Decl::Fn(f))) => {
+            let body = &f.function.body.as_ref().unwrap().stmts;
+            assert_eq!(body.len(), 2);
+            assert!(is_invalid_stmt(&ModuleItem::Stmt(body[1].clone())));
+        }
+        _ => panic!("expected a function declaration"),
+    }
+    assert_eq!(errors.len(), 2);
+}
+
+#[test]
+fn recovery_unclosed_block() {
+    let (module, errors) = recovering(
+        "
+        function f() {
+            foo();
+        ",
+    );
+
+    assert_eq!(module.body.len(), 1);
+    assert_eq!(errors, vec!["Unexpected eof"]);
+}
+
+#[test]
+fn recovery_class_member() {
+    let (module, errors) = recovering(
+        "
+        class A {
+            a = 1;
+            b(;
+            c() {}
+        }
+        ",
+    );
+
+    match &module.body[0] {
+        ModuleItem::Stmt(Stmt::Decl(Decl::Class(c))) => {
+            assert_eq!(c.class.body.len(), 3);
+            match c.class.body[1] {
+                ClassMember::Empty(..) => {}
+                _ => panic!("expected an empty class member"),
+            }
+            match c.class.body[2] {
+                ClassMember::Method(..) => {}
+                _ => panic!("expected a method"),
+            }
+        }
+        _ => panic!("expected a class declaration"),
+    }
+    assert_eq!(errors.len(), 1);
+}
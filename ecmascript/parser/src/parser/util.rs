@@ -314,6 +314,8 @@ pub(super) trait ExprExt {
 
             Expr::TsConstAssertion(..) => false,
 
+            Expr::TopicRef(..) => false,
+
             Expr::Invalid(..) => false,
         }
     }
@@ -202,6 +202,9 @@ pub enum BinOpToken {
 
     /// `??`
     NullishCoalescing,
+
+    /// `|>`
+    Pipeline,
 }
 
 impl BinOpToken {
@@ -544,6 +547,7 @@ impl From<BinOpToken> for BinaryOp {
             BinOpToken::LogicalAnd => LogicalAnd,
             BinOpToken::Exp => Exp,
             BinOpToken::NullishCoalescing => NullishCoalescing,
+            BinOpToken::Pipeline => Pipeline,
         }
     }
 }
@@ -49,6 +49,8 @@ fn test(input: PathBuf) {
                     import_meta: true,
                     top_level_await: true,
                     import_assertions: true,
+                    pipeline_operator: None,
+                    auto_accessors: false,
                 }),
                 "ts" | "tsx" => Syntax::Typescript(TsConfig {
                     tsx: ext == "tsx",
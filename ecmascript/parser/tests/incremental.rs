@@ -0,0 +1,182 @@
+use swc_common::{FileName, SourceFile};
+use swc_ecma_ast::Module;
+use swc_ecma_parser::{
+    incremental::{reparse_module, InvalidEdit, TextEdit},
+    lexer::Lexer,
+    PResult, Parser, StringInput, Syntax,
+};
+
+fn parse(fm: &SourceFile) -> PResult<Module> {
+    let lexer = Lexer::new(
+        Syntax::default(),
+        Default::default(),
+        StringInput::from(fm),
+        None,
+    );
+    Parser::new_from(lexer).parse_module()
+}
+
+/// Asserts that reparsing `src` after replacing `from` with `to` gives the same
+/// result as a fresh parse.
+#[track_caller]
+fn check(src: &str, from: &str, to: &str) {
+    let start = src.find(from).expect("`from` should be in `src`");
+    let edit = TextEdit {
+        start: start as u32,
+        end: (start + from.len()) as u32,
+        text: to.into(),
+    };
+
+    testing::run_test(false, |cm, _| {
+        let fm = cm.new_source_file(FileName::Anon, src.into());
+        let module = parse(&fm).expect("failed to parse the original source");
+
+        let reparsed = reparse_module(
+            &cm,
+            Syntax::default(),
+            Default::default(),
+            &fm,
+            &module,
+            &edit,
+        )
+        .expect("the edit should be valid");
+        assert_eq!(reparsed.fm.src.as_str(), src.replacen(from, to, 1));
+        assert_eq!(reparsed.module, parse(&reparsed.fm));
+
+        Ok(())
+    })
+    .unwrap();
+}
+
+#[test]
+fn edit_in_stmt() {
+    check("const a = 1;\nconst b = 2;\nconst c = 3;\n", "2", "20 + a");
+}
+
+#[test]
+fn edit_in_first_stmt() {
+    check("const a = 1;\nconst b = 2;\n", "1", "");
+}
+
+#[test]
+fn insert_at_end() {
+    check("const a = 1;\nconst b = 2;", "2;", "2;\nfoo();");
+}
+
+#[test]
+fn continue_prev_stmt() {
+    check("x;\na\nb\n", "b", ".b");
+}
+
+#[test]
+fn delete_stmts() {
+    check(
+        "const a = 1;\nconst b = 2;\nconst c = 3;\nconst d = 4;\n",
+        "2;\nconst c",
+        "3;\nlet c",
+    );
+}
+
+#[test]
+fn edit_in_class_body() {
+    check(
+        "import a from 'a';\nexport class A extends B {\n    a() {}\n    b() {\n        return \
+         1;\n    }\n    c = 1;\n}\nfoo();\n",
+        "1;\n    }",
+        "super.b() + 2;\n    }\n    d() {}",
+    );
+}
+
+#[test]
+fn edit_closes_class_body() {
+    check(
+        "class A {\n    a() {}\n    b() {}\n}\nfoo();\n",
+        "b() {}",
+        "b() {}}\nclass B {",
+    );
+}
+
+#[test]
+fn edit_with_error() {
+    check("const a = 1;\nconst b = 2;\n", "2;", "(;");
+}
+
+#[test]
+fn edit_in_method_body() {
+    check(
+        "class A {\n    a() {}\n    b() {\n        return 1;\n    }\n    c() {}\n}\n",
+        "return 1;",
+        "const x = 1;\n        return x;",
+    );
+}
+
+#[test]
+fn add_class_member() {
+    check(
+        "class A {\n    a() {}\n    b() {}\n}\nfoo();\n",
+        "b() {}",
+        "b() {}\n    static c = 1;\n    get d() {\n        return 2;\n    }",
+    );
+}
+
+#[test]
+fn remove_class_member() {
+    check(
+        "export default class A {\n    a() {}\n    b = 1;\n    c() {}\n}\n",
+        "b = 1;\n    ",
+        "",
+    );
+}
+
+#[test]
+fn edit_before_class_rbrace() {
+    check(
+        "class A {\n    a() {}\n    b() {}\n}\nfoo();\n",
+        "b() {}\n",
+        "b() {}\n    c = 1;\n",
+    );
+}
+
+#[test]
+fn edit_includes_class_rbrace() {
+    check(
+        "class A {\n    a() {}\n    b() {}\n}\nfoo();\n",
+        "b() {}\n}",
+        "b() {}\n    c = 1;\n}",
+    );
+}
+
+#[test]
+fn edit_after_class_rbrace() {
+    check("class A {\n    a() {}\n}\nfoo();\n", "}\nfoo", "}\nbar");
+}
+
+#[test]
+fn invalid_edit() {
+    testing::run_test(false, |cm, _| {
+        let src = "const a = '\u{1F600}';\n";
+        let fm = cm.new_source_file(FileName::Anon, src.into());
+        let module = parse(&fm).expect("failed to parse the original source");
+
+        let emoji = src.find('\u{1F600}').unwrap() as u32;
+        for &(start, end) in &[(2, 1), (0, src.len() as u32 + 1), (emoji + 1, emoji + 2)] {
+            let edit = TextEdit {
+                start,
+                end,
+                text: String::new(),
+            };
+            let res = reparse_module(
+                &cm,
+                Syntax::default(),
+                Default::default(),
+                &fm,
+                &module,
+                &edit,
+            );
+            assert_eq!(res.unwrap_err(), InvalidEdit(edit));
+        }
+
+        Ok(())
+    })
+    .unwrap();
+}
@@ -114,6 +114,11 @@ fn spec(file: PathBuf) {
             panic!()
         }
 
+        let binary = swc_ecma_ast::binary::to_vec(&program).expect("failed to encode program");
+        let decoded = swc_ecma_ast::binary::from_slice::<Program>(&binary)
+            .expect("failed to decode binary program");
+        assert_eq!(program, decoded, "binary roundtrip failed");
+
         let program = program.fold_with(&mut Normalizer {
             drop_span: true,
             is_test262: false,
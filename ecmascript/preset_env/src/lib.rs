@@ -466,6 +466,14 @@ pub struct Config {
     pub bugfixes: bool,
 }
 
+impl Config {
+    /// Resolves `targets` into versions of browsers, in the same way as
+    /// [preset_env]. Queries are resolved using browserslist at `path`.
+    pub fn resolved_targets(&self) -> Result<Versions, ()> {
+        targets_to_versions(self.targets.clone(), &self.path)
+    }
+}
+
 fn default_targets() -> Option<Targets> {
     Some(Targets::Query(Query::Single("".into())))
 }
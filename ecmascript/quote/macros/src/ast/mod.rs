@@ -0,0 +1,316 @@
+//! Conversion of ast nodes to rust code which creates them.
+
+use crate::ctxt::{Ctx, VarKind};
+use num_bigint::BigInt as BigIntValue;
+use proc_macro2::{Literal, Span as RustSpan, TokenStream};
+use quote::quote;
+use swc_atoms::JsWord;
+use swc_common::Span;
+use swc_ecma_ast::{Expr, ExprStmt, Ident, ModuleItem, Pat, Stmt, StrKind};
+use syn::LitStr;
+
+pub(crate) trait ToCode {
+    /// Returns an expression which creates `self`.
+    fn to_code(&self, cx: &Ctx) -> TokenStream;
+
+    /// Returns an expression which creates `Vec<Self>`.
+    fn to_code_vec(items: &[Self], cx: &Ctx) -> TokenStream
+    where
+        Self: Sized,
+    {
+        let items = items.iter().map(|item| item.to_code(cx));
+
+        quote!(vec![#(#items),*])
+    }
+}
+
+/// Implements [ToCode] for a struct.
+///
+/// With `@fn`, this creates a function instead, which is used by types
+/// handling variables.
+macro_rules! impl_struct {
+    ($name:ident, [$($field:ident),* $(,)?]) => {
+        impl crate::ast::ToCode for swc_ecma_ast::$name {
+            fn to_code(&self, cx: &crate::ctxt::Ctx) -> proc_macro2::TokenStream {
+                impl_struct!(@body self, cx, $name, [$($field),*])
+            }
+        }
+    };
+
+    (@fn $fn_name:ident, $name:ident, [$($field:ident),* $(,)?]) => {
+        pub(super) fn $fn_name(n: &swc_ecma_ast::$name, cx: &crate::ctxt::Ctx) -> proc_macro2::TokenStream {
+            impl_struct!(@body n, cx, $name, [$($field),*])
+        }
+    };
+
+    (@body $n:ident, $cx:ident, $name:ident, [$($field:ident),*]) => {{
+        // Destructured to ensure that all fields are converted.
+        let swc_ecma_ast::$name { $($field),* } = $n;
+        $(
+            let $field = crate::ast::ToCode::to_code($field, $cx);
+        )*
+
+        quote::quote!(::swc_ecma_quote::swc_ecma_ast::$name { $($field: #$field),* })
+    }};
+}
+
+/// Implements [ToCode] for an enum whose variants have one field.
+///
+/// With `@fn`, this creates a function instead, which is used by types
+/// handling variables.
+macro_rules! impl_enum {
+    ($name:ident, [$($variant:ident),* $(,)?]) => {
+        impl crate::ast::ToCode for swc_ecma_ast::$name {
+            fn to_code(&self, cx: &crate::ctxt::Ctx) -> proc_macro2::TokenStream {
+                impl_enum!(@body self, cx, $name, [$($variant),*])
+            }
+        }
+    };
+
+    (@fn $fn_name:ident, $name:ident, [$($variant:ident),* $(,)?]) => {
+        pub(super) fn $fn_name(n: &swc_ecma_ast::$name, cx: &crate::ctxt::Ctx) -> proc_macro2::TokenStream {
+            impl_enum!(@body n, cx, $name, [$($variant),*])
+        }
+    };
+
+    (@body $n:ident, $cx:ident, $name:ident, [$($variant:ident),*]) => {
+        match $n {
+            $(
+                swc_ecma_ast::$name::$variant(v) => {
+                    let v = crate::ast::ToCode::to_code(v, $cx);
+
+                    quote::quote!(::swc_ecma_quote::swc_ecma_ast::$name::$variant(#v))
+                }
+            )*
+        }
+    };
+}
+
+/// Implements [ToCode] for an enum without fields.
+macro_rules! impl_unit_enum {
+    ($name:ident, [$($variant:ident),* $(,)?]) => {
+        impl crate::ast::ToCode for swc_ecma_ast::$name {
+            fn to_code(&self, _: &crate::ctxt::Ctx) -> proc_macro2::TokenStream {
+                match self {
+                    $(
+                        swc_ecma_ast::$name::$variant => {
+                            quote::quote!(::swc_ecma_quote::swc_ecma_ast::$name::$variant)
+                        }
+                    )*
+                }
+            }
+        }
+    };
+}
+
+mod nodes;
+
+impl ToCode for Span {
+    fn to_code(&self, _: &Ctx) -> TokenStream {
+        quote!(::swc_ecma_quote::swc_common::DUMMY_SP)
+    }
+}
+
+impl ToCode for JsWord {
+    fn to_code(&self, _: &Ctx) -> TokenStream {
+        let s = LitStr::new(&**self, RustSpan::call_site());
+
+        quote!(::swc_ecma_quote::swc_atoms::JsWord::from(#s))
+    }
+}
+
+impl ToCode for bool {
+    fn to_code(&self, _: &Ctx) -> TokenStream {
+        quote!(#self)
+    }
+}
+
+impl ToCode for f64 {
+    fn to_code(&self, _: &Ctx) -> TokenStream {
+        if self.is_finite() {
+            let v = Literal::f64_suffixed(*self);
+            quote!(#v)
+        } else {
+            let bits = self.to_bits();
+            quote!(f64::from_bits(#bits))
+        }
+    }
+}
+
+impl ToCode for BigIntValue {
+    fn to_code(&self, _: &Ctx) -> TokenStream {
+        let s = self.to_string();
+
+        quote!(
+            ::swc_ecma_quote::num_bigint::BigInt::parse_bytes(#s.as_bytes(), 10)
+                .expect("invalid bigint")
+        )
+    }
+}
+
+impl ToCode for StrKind {
+    fn to_code(&self, _: &Ctx) -> TokenStream {
+        // Spans are not preserved, so the string cannot be printed from the source.
+        quote!(::swc_ecma_quote::swc_ecma_ast::StrKind::Synthesized)
+    }
+}
+
+impl<T> ToCode for Option<T>
+where
+    T: ToCode,
+{
+    fn to_code(&self, cx: &Ctx) -> TokenStream {
+        match self {
+            Some(v) => {
+                let v = v.to_code(cx);
+                quote!(Some(#v))
+            }
+            None => quote!(None),
+        }
+    }
+}
+
+impl<T> ToCode for Box<T>
+where
+    T: ToCode,
+{
+    fn to_code(&self, cx: &Ctx) -> TokenStream {
+        let v = (**self).to_code(cx);
+        quote!(Box::new(#v))
+    }
+}
+
+impl<T> ToCode for Vec<T>
+where
+    T: ToCode,
+{
+    fn to_code(&self, cx: &Ctx) -> TokenStream {
+        T::to_code_vec(self, cx)
+    }
+}
+
+impl ToCode for Ident {
+    fn to_code(&self, cx: &Ctx) -> TokenStream {
+        if let Some(var) = cx.ident_var(&self.sym) {
+            return var;
+        }
+
+        nodes::ident_to_code(self, cx)
+    }
+}
+
+impl ToCode for Expr {
+    fn to_code(&self, cx: &Ctx) -> TokenStream {
+        if let Expr::Ident(i) = self {
+            if let Some(var) = cx.var(VarKind::Expr, &i.sym) {
+                return var;
+            }
+        }
+
+        nodes::expr_to_code(self, cx)
+    }
+}
+
+impl ToCode for Pat {
+    fn to_code(&self, cx: &Ctx) -> TokenStream {
+        if let Pat::Ident(i) = self {
+            if i.type_ann.is_none() {
+                if let Some(var) = cx.var(VarKind::Pat, &i.id.sym) {
+                    return var;
+                }
+
+                // Assignment targets are parsed as patterns.
+                if let Some(var) = cx.var(VarKind::Expr, &i.id.sym) {
+                    return quote!(::swc_ecma_quote::swc_ecma_ast::Pat::Expr(Box::new(#var)));
+                }
+            }
+        }
+
+        nodes::pat_to_code(self, cx)
+    }
+}
+
+/// Returns the name of `$var;`.
+fn stmt_var(s: &Stmt) -> Option<&JsWord> {
+    match s {
+        Stmt::Expr(ExprStmt { expr, .. }) => match &**expr {
+            Expr::Ident(i) => Some(&i.sym),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+impl ToCode for Stmt {
+    fn to_code(&self, cx: &Ctx) -> TokenStream {
+        if let Some(var) = stmt_var(self).and_then(|sym| cx.var(VarKind::Stmt, sym)) {
+            return var;
+        }
+
+        nodes::stmt_to_code(self, cx)
+    }
+
+    fn to_code_vec(items: &[Self], cx: &Ctx) -> TokenStream {
+        to_code_vec_with_stmts(items, cx, stmt_var, |stmts| stmts)
+    }
+}
+
+impl ToCode for ModuleItem {
+    fn to_code(&self, cx: &Ctx) -> TokenStream {
+        nodes::module_item_to_code(self, cx)
+    }
+
+    fn to_code_vec(items: &[Self], cx: &Ctx) -> TokenStream {
+        to_code_vec_with_stmts(
+            items,
+            cx,
+            |item| match item {
+                ModuleItem::Stmt(s) => stmt_var(s),
+                _ => None,
+            },
+            |stmts| {
+                quote!(#stmts
+                    .into_iter()
+                    .map(::swc_ecma_quote::swc_ecma_ast::ModuleItem::Stmt))
+            },
+        )
+    }
+}
+
+/// Creates a list, splicing `$var;` if `var` is a `Vec<Stmt>`.
+fn to_code_vec_with_stmts<T>(
+    items: &[T],
+    cx: &Ctx,
+    var_of: impl Fn(&T) -> Option<&JsWord>,
+    convert: impl Fn(TokenStream) -> TokenStream,
+) -> TokenStream
+where
+    T: ToCode,
+{
+    // `Err` is a list to splice.
+    let items = items
+        .iter()
+        .map(
+            |item| match var_of(item).and_then(|sym| cx.var(VarKind::Stmts, sym)) {
+                Some(var) => Err(convert(var)),
+                None => Ok(item.to_code(cx)),
+            },
+        )
+        .collect::<Vec<_>>();
+
+    if items.iter().all(|item| item.is_ok()) {
+        let items = items.into_iter().map(|item| item.unwrap());
+        return quote!(vec![#(#items),*]);
+    }
+
+    let stmts = items.into_iter().map(|item| match item {
+        Ok(item) => quote!(items.push(#item);),
+        Err(list) => quote!(items.extend(#list);),
+    });
+
+    quote!({
+        let mut items = Vec::new();
+        #(#stmts)*
+        items
+    })
+}
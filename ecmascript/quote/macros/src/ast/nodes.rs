@@ -0,0 +1,591 @@
+//! Nodes, in the same order as `swc_ecma_visit`.
+
+impl_struct!(
+    Class,
+    [
+        span,
+        decorators,
+        body,
+        super_class,
+        is_abstract,
+        type_params,
+        super_type_params,
+        implements
+    ]
+);
+impl_enum!(
+    ClassMember,
+    [
+        Constructor,
+        Method,
+        PrivateMethod,
+        ClassProp,
+        PrivateProp,
+        TsIndexSignature,
+        Empty
+    ]
+);
+impl_struct!(
+    ClassProp,
+    [
+        span,
+        key,
+        value,
+        type_ann,
+        is_static,
+        decorators,
+        computed,
+        accessibility,
+        is_abstract,
+        is_optional,
+        is_override,
+        readonly,
+        declare,
+        definite,
+        is_accessor
+    ]
+);
+impl_struct!(
+    PrivateProp,
+    [
+        span,
+        key,
+        value,
+        type_ann,
+        is_static,
+        decorators,
+        computed,
+        accessibility,
+        is_abstract,
+        is_optional,
+        is_override,
+        readonly,
+        definite,
+        is_accessor
+    ]
+);
+impl_struct!(
+    ClassMethod,
+    [
+        span,
+        key,
+        function,
+        kind,
+        is_static,
+        accessibility,
+        is_abstract,
+        is_optional,
+        is_override
+    ]
+);
+impl_struct!(
+    PrivateMethod,
+    [
+        span,
+        key,
+        function,
+        kind,
+        is_static,
+        accessibility,
+        is_abstract,
+        is_optional,
+        is_override
+    ]
+);
+impl_struct!(
+    Constructor,
+    [span, key, params, body, accessibility, is_optional]
+);
+impl_struct!(Decorator, [span, expr]);
+impl_unit_enum!(MethodKind, [Method, Getter, Setter]);
+impl_enum!(
+    Decl,
+    [Class, Fn, Var, TsInterface, TsTypeAlias, TsEnum, TsModule]
+);
+impl_struct!(FnDecl, [ident, declare, function]);
+impl_struct!(ClassDecl, [ident, declare, class]);
+impl_struct!(VarDecl, [span, kind, declare, decls]);
+impl_unit_enum!(VarDeclKind, [Var, Let, Const]);
+impl_struct!(VarDeclarator, [span, name, init, definite]);
+impl_enum!(
+    @fn expr_to_code,
+    Expr,
+    [
+        This,
+        Array,
+        Object,
+        Fn,
+        Unary,
+        Update,
+        Bin,
+        Assign,
+        Member,
+        Cond,
+        Call,
+        New,
+        Seq,
+        Ident,
+        Lit,
+        Tpl,
+        TaggedTpl,
+        Arrow,
+        Class,
+        Yield,
+        MetaProp,
+        Await,
+        Paren,
+        JSXMember,
+        JSXNamespacedName,
+        JSXEmpty,
+        JSXElement,
+        JSXFragment,
+        TsTypeAssertion,
+        TsConstAssertion,
+        TsNonNull,
+        TsAs,
+        PrivateName,
+        OptChain,
+        TopicRef,
+        Invalid
+    ]
+);
+impl_struct!(ThisExpr, [span]);
+impl_struct!(ArrayLit, [span, elems]);
+impl_struct!(ObjectLit, [span, props]);
+impl_enum!(PropOrSpread, [Spread, Prop]);
+impl_struct!(SpreadElement, [dot3_token, expr]);
+impl_struct!(UnaryExpr, [span, op, arg]);
+impl_struct!(UpdateExpr, [span, op, prefix, arg]);
+impl_struct!(BinExpr, [span, op, left, right]);
+impl_struct!(FnExpr, [ident, function]);
+impl_struct!(ClassExpr, [ident, class]);
+impl_struct!(AssignExpr, [span, op, left, right]);
+impl_struct!(MemberExpr, [span, obj, prop, computed]);
+impl_struct!(CondExpr, [span, test, cons, alt]);
+impl_struct!(CallExpr, [span, callee, args, type_args]);
+impl_struct!(NewExpr, [span, callee, args, type_args]);
+impl_struct!(SeqExpr, [span, exprs]);
+impl_struct!(
+    ArrowExpr,
+    [
+        span,
+        params,
+        body,
+        is_async,
+        is_generator,
+        type_params,
+        return_type
+    ]
+);
+impl_struct!(YieldExpr, [span, arg, delegate]);
+impl_struct!(MetaPropExpr, [meta, prop]);
+impl_struct!(AwaitExpr, [span, arg]);
+impl_struct!(Tpl, [span, exprs, quasis]);
+impl_struct!(TaggedTpl, [span, tag, type_params, tpl]);
+impl_struct!(TplElement, [span, tail, cooked, raw]);
+impl_struct!(ParenExpr, [span, expr]);
+impl_enum!(ExprOrSuper, [Super, Expr]);
+impl_struct!(Super, [span]);
+impl_struct!(ExprOrSpread, [spread, expr]);
+impl_enum!(BlockStmtOrExpr, [BlockStmt, Expr]);
+impl_enum!(PatOrExpr, [Expr, Pat]);
+impl_struct!(OptChainExpr, [span, question_dot_token, expr]);
+impl_struct!(TopicRef, [span, token]);
+impl_unit_enum!(TopicToken, [Percent, Caret]);
+impl_struct!(
+    Function,
+    [
+        params,
+        decorators,
+        span,
+        body,
+        is_generator,
+        is_async,
+        type_params,
+        return_type
+    ]
+);
+impl_struct!(Param, [span, decorators, pat]);
+impl_enum!(ParamOrTsParamProp, [TsParamProp, Param]);
+impl_struct!(BindingIdent, [id, type_ann]);
+impl_struct!(@fn ident_to_code, Ident, [span, sym, optional]);
+impl_struct!(PrivateName, [span, id]);
+impl_enum!(JSXObject, [JSXMemberExpr, Ident]);
+impl_struct!(JSXMemberExpr, [obj, prop]);
+impl_struct!(JSXNamespacedName, [ns, name]);
+impl_struct!(JSXEmptyExpr, [span]);
+impl_struct!(JSXExprContainer, [span, expr]);
+impl_enum!(JSXExpr, [JSXEmptyExpr, Expr]);
+impl_struct!(JSXSpreadChild, [span, expr]);
+impl_enum!(JSXElementName, [Ident, JSXMemberExpr, JSXNamespacedName]);
+impl_struct!(
+    JSXOpeningElement,
+    [name, span, attrs, self_closing, type_args]
+);
+impl_enum!(JSXAttrOrSpread, [JSXAttr, SpreadElement]);
+impl_struct!(JSXClosingElement, [span, name]);
+impl_struct!(JSXAttr, [span, name, value]);
+impl_enum!(JSXAttrName, [Ident, JSXNamespacedName]);
+impl_enum!(
+    JSXAttrValue,
+    [Lit, JSXExprContainer, JSXElement, JSXFragment]
+);
+impl_struct!(JSXText, [span, value, raw]);
+impl_struct!(JSXElement, [span, opening, children, closing]);
+impl_enum!(
+    JSXElementChild,
+    [
+        JSXText,
+        JSXExprContainer,
+        JSXSpreadChild,
+        JSXElement,
+        JSXFragment
+    ]
+);
+impl_struct!(JSXFragment, [span, opening, children, closing]);
+impl_struct!(JSXOpeningFragment, [span]);
+impl_struct!(JSXClosingFragment, [span]);
+impl_struct!(Invalid, [span]);
+impl_enum!(Lit, [Str, Bool, Null, Num, BigInt, Regex, JSXText]);
+impl_struct!(BigInt, [span, value]);
+impl_struct!(Str, [span, value, has_escape, kind]);
+impl_struct!(Bool, [span, value]);
+impl_struct!(Null, [span]);
+impl_struct!(Regex, [span, exp, flags]);
+impl_struct!(Number, [span, value]);
+impl_enum!(Program, [Module, Script]);
+impl_struct!(Module, [span, body, shebang]);
+impl_struct!(Script, [span, body, shebang]);
+impl_enum!(@fn module_item_to_code, ModuleItem, [ModuleDecl, Stmt]);
+impl_enum!(
+    ModuleDecl,
+    [
+        Import,
+        ExportDecl,
+        ExportNamed,
+        ExportDefaultDecl,
+        ExportDefaultExpr,
+        ExportAll,
+        TsImportEquals,
+        TsExportAssignment,
+        TsNamespaceExport
+    ]
+);
+impl_struct!(ExportDefaultExpr, [span, expr]);
+impl_struct!(ExportDecl, [span, decl]);
+impl_struct!(ImportDecl, [span, specifiers, src, type_only, asserts]);
+impl_struct!(ExportAll, [span, src, asserts]);
+impl_struct!(NamedExport, [span, specifiers, src, type_only, asserts]);
+impl_struct!(ExportDefaultDecl, [span, decl]);
+impl_enum!(DefaultDecl, [Class, Fn, TsInterfaceDecl]);
+impl_enum!(ImportSpecifier, [Named, Default, Namespace]);
+impl_struct!(ImportDefaultSpecifier, [span, local]);
+impl_struct!(ImportStarAsSpecifier, [span, local]);
+impl_struct!(ImportNamedSpecifier, [span, local, imported]);
+impl_enum!(ExportSpecifier, [Namespace, Default, Named]);
+impl_struct!(ExportNamespaceSpecifier, [span, name]);
+impl_struct!(ExportDefaultSpecifier, [exported]);
+impl_struct!(ExportNamedSpecifier, [span, orig, exported]);
+impl_unit_enum!(
+    BinaryOp,
+    [
+        EqEq,
+        NotEq,
+        EqEqEq,
+        NotEqEq,
+        Lt,
+        LtEq,
+        Gt,
+        GtEq,
+        LShift,
+        RShift,
+        ZeroFillRShift,
+        Add,
+        Sub,
+        Mul,
+        Div,
+        Mod,
+        BitOr,
+        BitXor,
+        BitAnd,
+        LogicalOr,
+        LogicalAnd,
+        In,
+        InstanceOf,
+        Exp,
+        NullishCoalescing,
+        Pipeline
+    ]
+);
+impl_unit_enum!(
+    AssignOp,
+    [
+        Assign,
+        AddAssign,
+        SubAssign,
+        MulAssign,
+        DivAssign,
+        ModAssign,
+        LShiftAssign,
+        RShiftAssign,
+        ZeroFillRShiftAssign,
+        BitOrAssign,
+        BitXorAssign,
+        BitAndAssign,
+        ExpAssign,
+        AndAssign,
+        OrAssign,
+        NullishAssign
+    ]
+);
+impl_unit_enum!(UpdateOp, [PlusPlus, MinusMinus]);
+impl_unit_enum!(UnaryOp, [Minus, Plus, Bang, Tilde, TypeOf, Void, Delete]);
+impl_enum!(@fn pat_to_code, Pat, [Ident, Array, Rest, Object, Assign, Invalid, Expr]);
+impl_struct!(ArrayPat, [span, elems, optional, type_ann]);
+impl_struct!(ObjectPat, [span, props, optional, type_ann]);
+impl_struct!(AssignPat, [span, left, right, type_ann]);
+impl_struct!(RestPat, [span, dot3_token, arg, type_ann]);
+impl_enum!(ObjectPatProp, [KeyValue, Assign, Rest]);
+impl_struct!(KeyValuePatProp, [key, value]);
+impl_struct!(AssignPatProp, [span, key, value]);
+impl_enum!(Prop, [Shorthand, KeyValue, Assign, Getter, Setter, Method]);
+impl_struct!(KeyValueProp, [key, value]);
+impl_struct!(AssignProp, [key, value]);
+impl_struct!(GetterProp, [span, key, type_ann, body]);
+impl_struct!(SetterProp, [span, key, param, body]);
+impl_struct!(MethodProp, [key, function]);
+impl_enum!(PropName, [Ident, Str, Num, BigInt, Computed]);
+impl_struct!(ComputedPropName, [span, expr]);
+impl_struct!(BlockStmt, [span, stmts]);
+impl_enum!(
+    @fn stmt_to_code,
+    Stmt,
+    [
+        Block,
+        Empty,
+        Debugger,
+        With,
+        Return,
+        Labeled,
+        Break,
+        Continue,
+        If,
+        Switch,
+        Throw,
+        Try,
+        While,
+        DoWhile,
+        For,
+        ForIn,
+        ForOf,
+        Decl,
+        Expr
+    ]
+);
+impl_struct!(ExprStmt, [span, expr]);
+impl_struct!(EmptyStmt, [span]);
+impl_struct!(DebuggerStmt, [span]);
+impl_struct!(WithStmt, [span, obj, body]);
+impl_struct!(ReturnStmt, [span, arg]);
+impl_struct!(LabeledStmt, [span, label, body]);
+impl_struct!(BreakStmt, [span, label]);
+impl_struct!(ContinueStmt, [span, label]);
+impl_struct!(IfStmt, [span, test, cons, alt]);
+impl_struct!(SwitchStmt, [span, discriminant, cases]);
+impl_struct!(ThrowStmt, [span, arg]);
+impl_struct!(TryStmt, [span, block, handler, finalizer]);
+impl_struct!(WhileStmt, [span, test, body]);
+impl_struct!(DoWhileStmt, [span, test, body]);
+impl_struct!(ForStmt, [span, init, test, update, body]);
+impl_struct!(ForInStmt, [span, left, right, body]);
+impl_struct!(ForOfStmt, [span, await_token, left, right, body]);
+impl_struct!(SwitchCase, [span, test, cons]);
+impl_struct!(CatchClause, [span, param, body]);
+impl_enum!(VarDeclOrPat, [VarDecl, Pat]);
+impl_enum!(VarDeclOrExpr, [VarDecl, Expr]);
+impl_struct!(TsTypeAnn, [span, type_ann]);
+impl_struct!(TsTypeParamDecl, [span, params]);
+impl_struct!(TsTypeParam, [span, name, constraint, default]);
+impl_struct!(TsTypeParamInstantiation, [span, params]);
+impl_struct!(
+    TsParamProp,
+    [
+        span,
+        decorators,
+        accessibility,
+        is_override,
+        readonly,
+        param
+    ]
+);
+impl_enum!(TsParamPropParam, [Ident, Assign]);
+impl_struct!(TsQualifiedName, [left, right]);
+impl_enum!(TsEntityName, [TsQualifiedName, Ident]);
+impl_enum!(
+    TsTypeElement,
+    [
+        TsCallSignatureDecl,
+        TsConstructSignatureDecl,
+        TsPropertySignature,
+        TsGetterSignature,
+        TsSetterSignature,
+        TsMethodSignature,
+        TsIndexSignature
+    ]
+);
+impl_struct!(TsCallSignatureDecl, [span, params, type_ann, type_params]);
+impl_struct!(
+    TsConstructSignatureDecl,
+    [span, params, type_ann, type_params]
+);
+impl_struct!(
+    TsPropertySignature,
+    [
+        span,
+        readonly,
+        key,
+        computed,
+        optional,
+        init,
+        params,
+        type_ann,
+        type_params
+    ]
+);
+impl_struct!(
+    TsGetterSignature,
+    [span, readonly, key, computed, optional, type_ann]
+);
+impl_struct!(
+    TsSetterSignature,
+    [span, readonly, key, computed, optional, param]
+);
+impl_struct!(
+    TsMethodSignature,
+    [
+        span,
+        readonly,
+        key,
+        computed,
+        optional,
+        params,
+        type_ann,
+        type_params
+    ]
+);
+impl_struct!(
+    TsIndexSignature,
+    [params, type_ann, readonly, is_static, span]
+);
+impl_enum!(
+    TsType,
+    [
+        TsKeywordType,
+        TsThisType,
+        TsFnOrConstructorType,
+        TsTypeRef,
+        TsTypeQuery,
+        TsTypeLit,
+        TsArrayType,
+        TsTupleType,
+        TsOptionalType,
+        TsRestType,
+        TsUnionOrIntersectionType,
+        TsConditionalType,
+        TsInferType,
+        TsParenthesizedType,
+        TsTypeOperator,
+        TsIndexedAccessType,
+        TsMappedType,
+        TsLitType,
+        TsTypePredicate,
+        TsImportType
+    ]
+);
+impl_enum!(TsFnOrConstructorType, [TsFnType, TsConstructorType]);
+impl_struct!(TsKeywordType, [span, kind]);
+impl_unit_enum!(
+    TsKeywordTypeKind,
+    [
+        TsAnyKeyword,
+        TsUnknownKeyword,
+        TsNumberKeyword,
+        TsObjectKeyword,
+        TsBooleanKeyword,
+        TsBigIntKeyword,
+        TsStringKeyword,
+        TsSymbolKeyword,
+        TsVoidKeyword,
+        TsUndefinedKeyword,
+        TsNullKeyword,
+        TsNeverKeyword,
+        TsIntrinsicKeyword
+    ]
+);
+impl_struct!(TsThisType, [span]);
+impl_enum!(TsFnParam, [Ident, Array, Rest, Object]);
+impl_struct!(TsFnType, [span, params, type_params, type_ann]);
+impl_struct!(
+    TsConstructorType,
+    [span, params, type_params, type_ann, is_abstract]
+);
+impl_struct!(TsTypeRef, [span, type_name, type_params]);
+impl_struct!(TsTypePredicate, [span, asserts, param_name, type_ann]);
+impl_enum!(TsThisTypeOrIdent, [TsThisType, Ident]);
+impl_struct!(TsTypeQuery, [span, expr_name]);
+impl_enum!(TsTypeQueryExpr, [TsEntityName, Import]);
+impl_struct!(TsImportType, [span, arg, qualifier, type_args]);
+impl_struct!(TsTypeLit, [span, members]);
+impl_struct!(TsArrayType, [span, elem_type]);
+impl_struct!(TsTupleType, [span, elem_types]);
+impl_struct!(TsTupleElement, [span, label, ty]);
+impl_struct!(TsOptionalType, [span, type_ann]);
+impl_struct!(TsRestType, [span, type_ann]);
+impl_enum!(TsUnionOrIntersectionType, [TsUnionType, TsIntersectionType]);
+impl_struct!(TsUnionType, [span, types]);
+impl_struct!(TsIntersectionType, [span, types]);
+impl_struct!(
+    TsConditionalType,
+    [span, check_type, extends_type, true_type, false_type]
+);
+impl_struct!(TsInferType, [span, type_param]);
+impl_struct!(TsParenthesizedType, [span, type_ann]);
+impl_struct!(TsTypeOperator, [span, op, type_ann]);
+impl_unit_enum!(TsTypeOperatorOp, [KeyOf, Unique, ReadOnly]);
+impl_struct!(TsIndexedAccessType, [span, readonly, obj_type, index_type]);
+impl_unit_enum!(TruePlusMinus, [True, Plus, Minus]);
+impl_struct!(
+    TsMappedType,
+    [span, readonly, type_param, name_type, optional, type_ann]
+);
+impl_struct!(TsLitType, [span, lit]);
+impl_enum!(TsLit, [BigInt, Number, Str, Bool, Tpl]);
+impl_struct!(TsTplLitType, [span, types, quasis]);
+impl_struct!(
+    TsInterfaceDecl,
+    [span, id, declare, type_params, extends, body]
+);
+impl_struct!(TsInterfaceBody, [span, body]);
+impl_struct!(TsExprWithTypeArgs, [span, expr, type_args]);
+impl_struct!(TsTypeAliasDecl, [span, declare, id, type_params, type_ann]);
+impl_struct!(TsEnumDecl, [span, declare, is_const, id, members]);
+impl_struct!(TsEnumMember, [span, id, init]);
+impl_enum!(TsEnumMemberId, [Ident, Str]);
+impl_struct!(TsModuleDecl, [span, declare, global, id, body]);
+impl_enum!(TsNamespaceBody, [TsModuleBlock, TsNamespaceDecl]);
+impl_struct!(TsModuleBlock, [span, body]);
+impl_struct!(TsNamespaceDecl, [span, declare, global, id, body]);
+impl_enum!(TsModuleName, [Ident, Str]);
+impl_struct!(
+    TsImportEqualsDecl,
+    [span, declare, is_export, is_type_only, id, module_ref]
+);
+impl_enum!(TsModuleRef, [TsEntityName, TsExternalModuleRef]);
+impl_struct!(TsExternalModuleRef, [span, expr]);
+impl_struct!(TsExportAssignment, [span, expr]);
+impl_struct!(TsNamespaceExportDecl, [span, id]);
+impl_struct!(TsAsExpr, [span, expr, type_ann]);
+impl_struct!(TsTypeAssertion, [span, expr, type_ann]);
+impl_struct!(TsNonNullExpr, [span, expr]);
+impl_unit_enum!(Accessibility, [Public, Protected, Private]);
+impl_struct!(TsConstAssertion, [span, expr]);
@@ -0,0 +1,188 @@
+use proc_macro2::{Span, TokenStream};
+use quote::quote;
+use std::cell::{Cell, RefCell};
+use syn::{Expr, Ident, Type};
+
+/// Type of a variable.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum VarKind {
+    Ident,
+    Expr,
+    Pat,
+    Stmt,
+    /// `Vec<Stmt>`, which is spliced into a list of statements.
+    Stmts,
+}
+
+impl VarKind {
+    fn from_type(ty: &Type) -> syn::Result<Self> {
+        let s = quote!(#ty).to_string().replace(' ', "");
+
+        Ok(match &*s {
+            "Ident" => VarKind::Ident,
+            "Expr" => VarKind::Expr,
+            "Pat" => VarKind::Pat,
+            "Stmt" => VarKind::Stmt,
+            "Vec<Stmt>" => VarKind::Stmts,
+            _ => {
+                return Err(syn::Error::new_spanned(
+                    ty,
+                    "type of a variable should be one of `Ident`, `Expr`, `Pat`, `Stmt` and \
+                     `Vec<Stmt>`",
+                ))
+            }
+        })
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            VarKind::Ident => "Ident",
+            VarKind::Expr => "Expr",
+            VarKind::Pat => "Pat",
+            VarKind::Stmt => "Stmt",
+            VarKind::Stmts => "Vec<Stmt>",
+        }
+    }
+
+    fn ty(self) -> TokenStream {
+        match self {
+            VarKind::Ident => quote!(::swc_ecma_quote::swc_ecma_ast::Ident),
+            VarKind::Expr => quote!(::swc_ecma_quote::swc_ecma_ast::Expr),
+            VarKind::Pat => quote!(::swc_ecma_quote::swc_ecma_ast::Pat),
+            VarKind::Stmt => quote!(::swc_ecma_quote::swc_ecma_ast::Stmt),
+            VarKind::Stmts => quote!(Vec<::swc_ecma_quote::swc_ecma_ast::Stmt>),
+        }
+    }
+}
+
+/// `name: Type = value` in the input of the macro.
+pub(crate) struct VarDef {
+    pub name: Ident,
+    pub ty: Option<Type>,
+    pub value: Expr,
+}
+
+struct Var {
+    name: Ident,
+    kind: VarKind,
+    /// Local variable storing the value. This uses [Span::mixed_site] so it
+    /// does not conflict with variables of the caller.
+    binding: Ident,
+    value: Expr,
+    used: Cell<bool>,
+}
+
+pub(crate) struct Ctx {
+    vars: Vec<Var>,
+    errors: RefCell<Vec<syn::Error>>,
+}
+
+impl Ctx {
+    pub fn new(defs: Vec<VarDef>) -> syn::Result<Self> {
+        let mut vars: Vec<Var> = vec![];
+
+        for def in defs {
+            if vars.iter().any(|v| v.name == def.name) {
+                return Err(syn::Error::new(
+                    def.name.span(),
+                    format!("duplicate variable `{}`", def.name),
+                ));
+            }
+
+            let kind = match &def.ty {
+                Some(ty) => VarKind::from_type(ty)?,
+                None => VarKind::Ident,
+            };
+
+            vars.push(Var {
+                binding: Ident::new(&def.name.to_string(), Span::mixed_site()),
+                name: def.name,
+                kind,
+                value: def.value,
+                used: Cell::new(false),
+            });
+        }
+
+        Ok(Ctx {
+            vars,
+            errors: Default::default(),
+        })
+    }
+
+    fn find(&self, sym: &str) -> Option<&Var> {
+        let name = sym.strip_prefix('$')?;
+        self.vars.iter().find(|v| v.name == name)
+    }
+
+    /// Returns the code of the variable if `sym` is `$name` and `name` is a
+    /// variable of `kind`.
+    pub fn var(&self, kind: VarKind, sym: &str) -> Option<TokenStream> {
+        let var = self.find(sym)?;
+        if var.kind != kind {
+            return None;
+        }
+        var.used.set(true);
+
+        let binding = &var.binding;
+        Some(quote!(#binding.clone()))
+    }
+
+    /// Same as [Ctx::var] with [VarKind::Ident], but reports variables of other
+    /// types because identifiers are the last place where a variable can
+    /// appear.
+    pub fn ident_var(&self, sym: &str) -> Option<TokenStream> {
+        if let Some(code) = self.var(VarKind::Ident, sym) {
+            return Some(code);
+        }
+
+        if let Some(var) = self.find(sym) {
+            var.used.set(true);
+            self.errors.borrow_mut().push(syn::Error::new(
+                var.name.span(),
+                format!(
+                    "`${}` is a variable of `{}`, which cannot be used here",
+                    var.name,
+                    var.kind.name()
+                ),
+            ));
+        }
+
+        None
+    }
+
+    /// Statements binding the variables, in the order of the definition.
+    pub fn bindings(&self) -> TokenStream {
+        let bindings = self.vars.iter().map(|var| {
+            let binding = &var.binding;
+            let ty = var.kind.ty();
+            let value = &var.value;
+
+            quote!(let #binding: #ty = #value;)
+        });
+
+        quote!(#(#bindings)*)
+    }
+
+    /// Returns errors found while converting the ast.
+    pub fn finish(self) -> syn::Result<()> {
+        let mut errors = self.errors.into_inner();
+
+        for var in &self.vars {
+            if !var.used.get() {
+                errors.push(syn::Error::new(
+                    var.name.span(),
+                    format!("`${}` is not used in the source", var.name),
+                ));
+            }
+        }
+
+        let mut errors = errors.into_iter();
+        match errors.next() {
+            Some(mut err) => {
+                errors.for_each(|e| err.combine(e));
+                Err(err)
+            }
+            None => Ok(()),
+        }
+    }
+}
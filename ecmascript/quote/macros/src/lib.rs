@@ -0,0 +1,228 @@
+//! Macros of `swc_ecma_quote`. See the crate for documentation.
+
+extern crate proc_macro;
+
+use self::{
+    ast::ToCode,
+    ctxt::{Ctx, VarDef},
+};
+use proc_macro2::TokenStream;
+use quote::quote;
+use swc_common::{BytePos, Globals, Spanned, GLOBALS};
+use swc_ecma_ast::{Expr, ModuleItem, Pat};
+use swc_ecma_parser::{
+    error::{Error, SyntaxError},
+    lexer::Lexer,
+    PResult, Parser, StringInput, Syntax, TsConfig,
+};
+use syn::{
+    parse::{Parse, ParseStream},
+    parse_macro_input, LitStr, Token, Type,
+};
+
+mod ast;
+mod ctxt;
+
+/// See `swc_ecma_quote::quote`.
+#[proc_macro]
+pub fn quote(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = parse_macro_input!(input as QuoteInput);
+
+    let output = match &input.output {
+        Some(ty) => Output::from_type(ty),
+        None => Ok(Output::Stmt),
+    };
+
+    output
+        .and_then(|output| expand(input, output))
+        .unwrap_or_else(|err| err.to_compile_error())
+        .into()
+}
+
+/// See `swc_ecma_quote::quote_expr`.
+#[proc_macro]
+pub fn quote_expr(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = parse_macro_input!(input as QuoteInput);
+
+    if let Some(ty) = &input.output {
+        return syn::Error::new_spanned(ty, "quote_expr! always creates `Box<Expr>`")
+            .to_compile_error()
+            .into();
+    }
+
+    expand(input, Output::BoxExpr)
+        .unwrap_or_else(|err| err.to_compile_error())
+        .into()
+}
+
+/// `"src" as Type, name: Type = value, ...`
+struct QuoteInput {
+    src: LitStr,
+    output: Option<Type>,
+    vars: Vec<VarDef>,
+}
+
+impl Parse for QuoteInput {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let src = input.parse()?;
+
+        let output = if input.peek(Token![as]) {
+            input.parse::<Token![as]>()?;
+            Some(input.parse()?)
+        } else {
+            None
+        };
+
+        let mut vars = vec![];
+        while !input.is_empty() {
+            input.parse::<Token![,]>()?;
+            if input.is_empty() {
+                break;
+            }
+
+            let name = input.parse()?;
+            let ty = if input.peek(Token![:]) {
+                input.parse::<Token![:]>()?;
+                Some(input.parse()?)
+            } else {
+                None
+            };
+            input.parse::<Token![=]>()?;
+            let value = input.parse()?;
+
+            vars.push(VarDef { name, ty, value });
+        }
+
+        Ok(QuoteInput { src, output, vars })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Output {
+    Expr,
+    BoxExpr,
+    Pat,
+    Stmt,
+    ModuleItem,
+}
+
+impl Output {
+    fn from_type(ty: &Type) -> syn::Result<Self> {
+        let s = quote!(#ty).to_string().replace(' ', "");
+
+        Ok(match &*s {
+            "Expr" => Output::Expr,
+            "Box<Expr>" => Output::BoxExpr,
+            "Pat" => Output::Pat,
+            "Stmt" => Output::Stmt,
+            "ModuleItem" => Output::ModuleItem,
+            _ => {
+                return Err(syn::Error::new_spanned(
+                    ty,
+                    "output type should be one of `Expr`, `Box<Expr>`, `Pat`, `Stmt` and \
+                     `ModuleItem`",
+                ))
+            }
+        })
+    }
+}
+
+fn expand(input: QuoteInput, output: Output) -> syn::Result<TokenStream> {
+    let src = input.src;
+    let cx = Ctx::new(input.vars)?;
+
+    let code = match output {
+        Output::Expr => parse(&src, |p| p.parse_expr())?.to_code(&cx),
+        Output::BoxExpr => parse(&src, |p| p.parse_expr())?.to_code(&cx),
+        Output::Pat => parse_pat(&src)?.to_code(&cx),
+        Output::Stmt => parse(&src, |p| p.parse_stmt(true))?.to_code(&cx),
+        Output::ModuleItem => parse_module_item(&src)?.to_code(&cx),
+    };
+    let code = match output {
+        // `parse_expr` returns `Box<Expr>`.
+        Output::Expr => quote!(*#code),
+        _ => code,
+    };
+
+    let bindings = cx.bindings();
+    cx.finish()?;
+
+    Ok(quote!({
+        #bindings
+        #code
+    }))
+}
+
+fn syntax() -> Syntax {
+    Syntax::Typescript(TsConfig {
+        decorators: true,
+        dynamic_import: true,
+        ..Default::default()
+    })
+}
+
+fn parse_error(src: &LitStr, err: Error) -> syn::Error {
+    syn::Error::new(
+        src.span(),
+        format!("failed to parse the source: {}", err.kind().msg()),
+    )
+}
+
+/// Parses the whole source using `op`.
+fn parse<F, T>(src: &LitStr, op: F) -> syn::Result<T>
+where
+    F: FnOnce(&mut Parser<Lexer<StringInput>>) -> PResult<T>,
+    T: Spanned,
+{
+    let s = src.value();
+    let s = s.trim();
+    let end = BytePos(1 + s.len() as u32);
+
+    GLOBALS.set(&Globals::new(), || {
+        let mut parser = Parser::new(syntax(), StringInput::new(s, BytePos(1), end), None);
+
+        let node = op(&mut parser).map_err(|err| parse_error(src, err))?;
+
+        // `return` is allowed because statements are usually inserted into a function.
+        let mut errors = parser.take_errors();
+        errors.retain(|err| !matches!(err.kind(), SyntaxError::ReturnNotAllowed));
+        if let Some(err) = errors.into_iter().next() {
+            return Err(parse_error(src, err));
+        }
+
+        if node.span().hi != end {
+            return Err(syn::Error::new(
+                src.span(),
+                "the source should be a single node",
+            ));
+        }
+
+        Ok(node)
+    })
+}
+
+fn parse_pat(src: &LitStr) -> syn::Result<Pat> {
+    // Parsed as the parameter of an arrow function.
+    let wrapped = LitStr::new(&format!("({}) => {{}}", src.value().trim()), src.span());
+
+    match *parse(&wrapped, |p| p.parse_expr())? {
+        Expr::Arrow(mut arrow) if arrow.params.len() == 1 => Ok(arrow.params.remove(0)),
+        _ => Err(syn::Error::new(
+            src.span(),
+            "the source should be a pattern",
+        )),
+    }
+}
+
+fn parse_module_item(src: &LitStr) -> syn::Result<ModuleItem> {
+    let mut module = parse(src, |p| p.parse_module())?;
+
+    if module.body.len() != 1 {
+        return Err(syn::Error::new(
+            src.span(),
+            "the source should be a single module item",
+        ));
+    }
+
+    Ok(module.body.remove(0))
+}
@@ -0,0 +1,67 @@
+//! Quasi quotation system for ecmascript.
+//!
+//! The source is parsed at compile time, and the macros expand to code which
+//! creates the ast. Spans of the created nodes are
+//! [DUMMY_SP](swc_common::DUMMY_SP).
+//!
+//! # Variables
+//!
+//! `$name` in the source is replaced by the value of the variable `name`,
+//! which is passed as `name: Type = value`. The type is one of
+//!
+//!  - `Ident` (default): Used for identifiers.
+//!  - `Expr`: Used for expressions and assignment targets.
+//!  - `Pat`: Used for patterns.
+//!  - `Stmt`: Used for `$name;`.
+//!  - `Vec<Stmt>`: Used for `$name;`, and spliced into the list of statements.
+//!
+//! Every variable should be used in the source.
+//!
+//! # Example
+//!
+//! ```
+//! use swc_common::DUMMY_SP;
+//! use swc_ecma_ast::*;
+//! use swc_ecma_quote::{quote, quote_expr};
+//!
+//! let name = Ident::new("foo".into(), DUMMY_SP);
+//! let init = quote_expr!("1 + 2");
+//!
+//! let stmt: Stmt = quote!("const $name = $init;", name = name, init: Expr = *init);
+//! ```
+
+/// Creates an ast node from the source.
+///
+/// The source is parsed as a statement unless the type of the output is
+/// specified with `as`, which is one of `Expr`, `Box<Expr>`, `Pat`, `Stmt` and
+/// `ModuleItem`.
+///
+/// ```
+/// use swc_ecma_ast::*;
+/// use swc_ecma_quote::quote;
+///
+/// let stmts: Vec<Stmt> = vec![quote!("foo();")];
+/// let stmt: Stmt = quote!("if (cond) { $stmts; }", stmts: Vec<Stmt> = stmts);
+/// let pat: Pat = quote!("{ a, b }" as Pat);
+/// ```
+pub use swc_ecma_quote_macros::quote;
+/// Same as [quote!] with `as Box<Expr>`.
+///
+/// ```
+/// use swc_ecma_ast::*;
+/// use swc_ecma_quote::quote_expr;
+///
+/// let arg = quote_expr!("bar");
+/// let call: Box<Expr> = quote_expr!("foo($arg)", arg: Expr = *arg);
+/// ```
+pub use swc_ecma_quote_macros::quote_expr;
+
+/// Used by the generated code.
+#[doc(hidden)]
+pub use num_bigint;
+#[doc(hidden)]
+pub use swc_atoms;
+#[doc(hidden)]
+pub use swc_common;
+#[doc(hidden)]
+pub use swc_ecma_ast;
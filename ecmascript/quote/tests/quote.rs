@@ -0,0 +1,145 @@
+use swc_common::{sync::Lrc, SourceMap, DUMMY_SP};
+use swc_ecma_ast::*;
+use swc_ecma_codegen::{text_writer::JsWriter, Emitter, Node};
+use swc_ecma_quote::{quote, quote_expr};
+
+fn print<N>(node: &N) -> String
+where
+    N: Node,
+{
+    let cm = Lrc::new(SourceMap::default());
+    let mut buf = vec![];
+
+    {
+        let mut emitter = Emitter {
+            cfg: Default::default(),
+            cm: cm.clone(),
+            comments: None,
+            wr: Box::new(JsWriter::new(cm.clone(), "\n", &mut buf, None)),
+        };
+
+        node.emit_with(&mut emitter).unwrap();
+    }
+
+    let s = String::from_utf8(buf).unwrap();
+    // Indentation and line breaks are not interesting.
+    s.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+#[test]
+fn stmt() {
+    let stmt: Stmt = quote!("const a = 1 + 2;");
+
+    assert_eq!(print(&stmt), "const a = 1 + 2;");
+}
+
+#[test]
+fn expr() {
+    let expr = quote_expr!("foo(bar, 'baz')");
+
+    assert_eq!(print(&expr), "foo(bar, \"baz\")");
+}
+
+#[test]
+fn output_type() {
+    let expr: Expr = quote!("a.b" as Expr);
+    assert_eq!(print(&expr), "a.b");
+
+    let pat: Pat = quote!("{ a, b = 1 }" as Pat);
+    assert_eq!(print(&pat), "{ a, b = 1 }");
+
+    let item: ModuleItem = quote!("export const a = 1;" as ModuleItem);
+    assert_eq!(print(&item), "export const a = 1;");
+}
+
+#[test]
+fn ident_var() {
+    let name = Ident::new("foo".into(), DUMMY_SP);
+
+    let stmt: Stmt = quote!("function $name() { return $name; }", name = name);
+
+    assert_eq!(print(&stmt), "function foo() { return foo; }");
+}
+
+#[test]
+fn expr_var() {
+    let arg = quote_expr!("a + b");
+
+    let expr = quote_expr!("foo($arg)", arg: Expr = *arg);
+
+    assert_eq!(print(&expr), "foo(a + b)");
+}
+
+#[test]
+fn expr_var_as_assign_target() {
+    let target = quote_expr!("a.b");
+
+    let stmt: Stmt = quote!("$target = 1;", target: Expr = *target);
+
+    assert_eq!(print(&stmt), "a.b = 1;");
+}
+
+#[test]
+fn pat_var() {
+    let pat: Pat = quote!("[a, b]" as Pat);
+
+    let stmt: Stmt = quote!("const $pat = foo;", pat: Pat = pat);
+
+    assert_eq!(print(&stmt), "const [a, b] = foo;");
+}
+
+#[test]
+fn stmt_var() {
+    let body: Stmt = quote!("foo();");
+
+    let stmt: Stmt = quote!("if (a) $body;", body: Stmt = body);
+
+    assert_eq!(print(&stmt), "if (a) foo();");
+}
+
+#[test]
+fn stmts_var() {
+    let stmts: Vec<Stmt> = vec![quote!("foo();"), quote!("bar();")];
+
+    let stmt: Stmt = quote!(
+        "function f() { before(); $stmts; after(); }",
+        stmts: Vec<Stmt> = stmts
+    );
+
+    assert_eq!(
+        print(&stmt),
+        "function f() { before(); foo(); bar(); after(); }"
+    );
+}
+
+#[test]
+fn stmts_var_in_module() {
+    let stmts: Vec<Stmt> = vec![quote!("foo();")];
+
+    let module = Module {
+        span: DUMMY_SP,
+        body: vec![
+            quote!("import a from 'a';" as ModuleItem),
+            ModuleItem::Stmt(quote!("{ $stmts; }", stmts: Vec<Stmt> = stmts)),
+        ],
+        shebang: None,
+    };
+
+    assert_eq!(print(&module), "import a from \"a\"; { foo(); }");
+}
+
+#[test]
+fn multiple_vars() {
+    let name = Ident::new("x".into(), DUMMY_SP);
+    let init = quote_expr!("1");
+    let step = quote_expr!("2");
+
+    let stmt: Stmt = quote!(
+        "{ let $name = $init; $name += $step; }" as Stmt,
+        name = name,
+        init: Expr = *init,
+        step: Expr = *step,
+    );
+
+    assert_eq!(print(&stmt), "{ let x = 1; x += 2; }");
+}
@@ -0,0 +1,269 @@
+function _applyDecs2203(targetClass, memberDecs, classDecs) {
+  var ret = [];
+  var protoInitializers;
+  var staticInitializers;
+  var existingProtoNonFields = new Map();
+  var existingStaticNonFields = new Map();
+
+  for (var i = 0; i < memberDecs.length; i++) {
+    var decInfo = memberDecs[i];
+    var kind = decInfo[1];
+    var name = decInfo[2];
+    var isPrivate = decInfo.length > 3;
+    var isStatic = kind >= 5;
+    var base;
+    var initializers;
+
+    if (isStatic) {
+      base = targetClass;
+      kind = kind - 5;
+
+      if (kind !== 0) {
+        staticInitializers = staticInitializers || [];
+        initializers = staticInitializers;
+      }
+    } else {
+      base = targetClass.prototype;
+
+      if (kind !== 0) {
+        protoInitializers = protoInitializers || [];
+        initializers = protoInitializers;
+      }
+    }
+
+    if (kind !== 0 && !isPrivate) {
+      var existingNonFields = isStatic ? existingStaticNonFields : existingProtoNonFields;
+      var existingKind = existingNonFields.get(name) || 0;
+
+      if (existingKind === true || (existingKind === 3 && kind !== 4) || (existingKind === 4 && kind !== 3)) {
+        throw new Error("Attempted to decorate a public method/accessor that has the same name as a previously decorated public method/accessor. Property name was: " + name);
+      } else if (!existingKind && kind > 2) {
+        existingNonFields.set(name, kind);
+      } else {
+        existingNonFields.set(name, true);
+      }
+    }
+
+    _applyDecs2203MemberDec(ret, base, decInfo, name, kind, isStatic, isPrivate, initializers);
+  }
+
+  _applyDecs2203PushInitializers(ret, protoInitializers);
+  _applyDecs2203PushInitializers(ret, staticInitializers);
+
+  if (classDecs.length > 0) {
+    var classInitializers = [];
+    var newClass = targetClass;
+    var className = targetClass.name;
+
+    for (var i = classDecs.length - 1; i >= 0; i--) {
+      var finished = { v: false };
+
+      try {
+        var nextNewClass = classDecs[i](newClass, {
+          kind: "class",
+          name: className,
+          addInitializer: _applyDecs2203AddInitializer(classInitializers, finished)
+        });
+      } finally {
+        finished.v = true;
+      }
+
+      if (nextNewClass !== undefined) {
+        _applyDecs2203AssertCallable(nextNewClass, "class decorators");
+        newClass = nextNewClass;
+      }
+    }
+
+    ret.push(newClass, function () {
+      for (var i = 0; i < classInitializers.length; i++) {
+        classInitializers[i].call(newClass);
+      }
+    });
+  }
+
+  return ret;
+}
+
+function _applyDecs2203MemberDec(ret, base, decInfo, name, kind, isStatic, isPrivate, initializers) {
+  var decs = decInfo[0];
+  var desc;
+  var value;
+  var inits = [];
+
+  if (isPrivate) {
+    if (kind === 0 || kind === 1) {
+      desc = { get: decInfo[3], set: decInfo[4] };
+    } else if (kind === 3) {
+      desc = { get: decInfo[3] };
+    } else if (kind === 4) {
+      desc = { set: decInfo[3] };
+    } else {
+      desc = { value: decInfo[3] };
+    }
+  } else if (kind !== 0) {
+    desc = Object.getOwnPropertyDescriptor(base, name);
+  }
+
+  if (kind === 1) {
+    value = { get: desc.get, set: desc.set };
+  } else if (kind === 2) {
+    value = desc.value;
+  } else if (kind === 3) {
+    value = desc.get;
+  } else if (kind === 4) {
+    value = desc.set;
+  }
+
+  for (var i = decs.length - 1; i >= 0; i--) {
+    var finished = { v: false };
+
+    try {
+      var newValue = decs[i](value, _applyDecs2203Context(name, kind, isStatic, isPrivate, desc, initializers, finished));
+    } finally {
+      finished.v = true;
+    }
+
+    if (newValue === undefined) {
+      continue;
+    }
+
+    if (kind === 0) {
+      _applyDecs2203AssertCallable(newValue, "field decorators");
+      inits.push(newValue);
+    } else if (kind === 1) {
+      if (typeof newValue !== "object" || newValue === null) {
+        throw new TypeError("accessor decorators must return an object with get, set, or init properties or void 0");
+      }
+      value = {
+        get: newValue.get === undefined ? value.get : newValue.get,
+        set: newValue.set === undefined ? value.set : newValue.set
+      };
+      _applyDecs2203AssertCallable(value.get, "accessor.get");
+      _applyDecs2203AssertCallable(value.set, "accessor.set");
+      if (newValue.init !== undefined) {
+        _applyDecs2203AssertCallable(newValue.init, "accessor.init");
+        inits.push(newValue.init);
+      }
+    } else {
+      _applyDecs2203AssertCallable(newValue, "method decorators");
+      value = newValue;
+    }
+  }
+
+  if (kind === 0 || kind === 1) {
+    ret.push(function (instance, init) {
+      for (var i = 0; i < inits.length; i++) {
+        init = inits[i].call(instance, init);
+      }
+      return init;
+    });
+  }
+
+  if (kind === 0) {
+    return;
+  }
+
+  if (isPrivate) {
+    if (kind === 1) {
+      ret.push(function (instance) {
+        return value.get.call(instance);
+      }, function (instance, v) {
+        return value.set.call(instance, v);
+      });
+    } else if (kind === 2) {
+      ret.push(value);
+    } else {
+      ret.push(function (instance, v) {
+        return value.call(instance, v);
+      });
+    }
+  } else {
+    if (kind === 1) {
+      desc.get = value.get;
+      desc.set = value.set;
+    } else if (kind === 2) {
+      desc.value = value;
+    } else if (kind === 3) {
+      desc.get = value;
+    } else {
+      desc.set = value;
+    }
+
+    Object.defineProperty(base, name, desc);
+  }
+}
+
+function _applyDecs2203Context(name, kind, isStatic, isPrivate, desc, initializers, finished) {
+  var ctx = {
+    kind: ["field", "accessor", "method", "getter", "setter"][kind],
+    name: name,
+    static: isStatic,
+    private: isPrivate
+  };
+  var get;
+  var set;
+
+  if (kind !== 0) {
+    ctx.addInitializer = _applyDecs2203AddInitializer(initializers, finished);
+  }
+
+  if (kind === 0) {
+    if (isPrivate) {
+      get = desc.get;
+      set = desc.set;
+    } else {
+      get = function () {
+        return this[name];
+      };
+      set = function (v) {
+        this[name] = v;
+      };
+    }
+  } else if (kind === 2) {
+    get = function () {
+      return desc.value;
+    };
+  } else {
+    if (kind === 1 || kind === 3) {
+      get = function () {
+        return desc.get.call(this);
+      };
+    }
+    if (kind === 1 || kind === 4) {
+      set = function (v) {
+        desc.set.call(this, v);
+      };
+    }
+  }
+
+  ctx.access = get && set ? { get: get, set: set } : get ? { get: get } : { set: set };
+
+  return ctx;
+}
+
+function _applyDecs2203AddInitializer(initializers, finished) {
+  return function addInitializer(initializer) {
+    if (finished.v) {
+      throw new Error("attempted to call addInitializer after decoration was finished");
+    }
+    _applyDecs2203AssertCallable(initializer, "An initializer");
+    initializers.push(initializer);
+  };
+}
+
+function _applyDecs2203PushInitializers(ret, initializers) {
+  if (initializers) {
+    ret.push(function (instance) {
+      for (var i = 0; i < initializers.length; i++) {
+        initializers[i].call(instance);
+      }
+      return instance;
+    });
+  }
+}
+
+function _applyDecs2203AssertCallable(fn, hint) {
+  if (typeof fn !== "function") {
+    throw new TypeError(hint + " must be a function");
+  }
+}
@@ -164,6 +164,7 @@ macro_rules! define_helpers {
 
 define_helpers!(Helpers {
     apply_decorated_descriptor: (),
+    apply_decs_2203: (),
     array_with_holes: (),
     array_without_holes: (),
     assert_this_initialized: (),
@@ -1120,6 +1120,8 @@ fn can_be_null(e: &Expr) -> bool {
         | Expr::TsConstAssertion(TsConstAssertion { ref expr, .. }) => can_be_null(expr),
         Expr::OptChain(ref e) => can_be_null(&e.expr),
 
+        Expr::TopicRef(..) => true,
+
         Expr::Invalid(..) => unreachable!(),
     }
 }
@@ -331,6 +331,7 @@ impl CaseHandler<'_> {
             | Expr::TsNonNull(..)
             | Expr::TsAs(..)
             | Expr::PrivateName(..)
+            | Expr::TopicRef(..)
             | Expr::Invalid(..) => return e,
 
             Expr::OptChain(e) => {
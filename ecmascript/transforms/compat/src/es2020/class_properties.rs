@@ -1,4 +1,5 @@
 use self::{
+    accessor::lower_accessors,
     class_name_tdz::ClassNameTdzFolder,
     private_field::FieldAccessFolder,
     this_in_static::ThisInStaticFolder,
@@ -22,6 +23,7 @@ use swc_ecma_utils::{
 use swc_ecma_visit::noop_visit_type;
 use swc_ecma_visit::{noop_fold_type, Fold, FoldWith, Node, Visit, VisitWith};
 
+mod accessor;
 mod class_name_tdz;
 mod private_field;
 mod this_in_static;
@@ -335,7 +337,7 @@ impl ClassProperties {
         let mut statics = HashSet::default();
         let mut private_methods = HashSet::default();
 
-        for member in class.body {
+        for member in lower_accessors(class.body, &mut vars) {
             match member {
                 ClassMember::Empty(..) | ClassMember::TsIndexSignature(..) => members.push(member),
 
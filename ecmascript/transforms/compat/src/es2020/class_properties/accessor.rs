@@ -0,0 +1,218 @@
+use fxhash::FxHashSet;
+use swc_atoms::JsWord;
+use swc_common::DUMMY_SP;
+use swc_ecma_ast::*;
+use swc_ecma_utils::{private_ident, quote_ident, ExprFactory};
+
+/// Converts `accessor` class members to a private storage and a getter /
+/// setter pair, which are handled like other members.
+///
+/// ```js
+/// class Foo {
+///     accessor foo = 1;
+/// }
+/// ```
+///
+/// becomes
+///
+/// ```js
+/// class Foo {
+///     #__foo = 1;
+///     get foo() { return this.#__foo; }
+///     set foo(v) { this.#__foo = v; }
+/// }
+/// ```
+///
+/// Getter and setter of `accessor #foo` cannot be observed, so it's converted
+/// to `#foo`. A computed key is stored in a variable, which is pushed to
+/// `vars`, so it's evaluated only once.
+pub(super) fn lower_accessors(
+    body: Vec<ClassMember>,
+    vars: &mut Vec<VarDeclarator>,
+) -> Vec<ClassMember> {
+    let has_accessor = body.iter().any(|member| match member {
+        ClassMember::ClassProp(p) => p.is_accessor,
+        ClassMember::PrivateProp(p) => p.is_accessor,
+        _ => false,
+    });
+    if !has_accessor {
+        return body;
+    }
+
+    let mut private_names: FxHashSet<JsWord> = body
+        .iter()
+        .filter_map(|member| match member {
+            ClassMember::PrivateProp(p) => Some(p.key.id.sym.clone()),
+            ClassMember::PrivateMethod(m) => Some(m.key.id.sym.clone()),
+            _ => None,
+        })
+        .collect();
+
+    let mut buf = Vec::with_capacity(body.len() + 2);
+    for member in body {
+        let p = match member {
+            ClassMember::PrivateProp(p) if p.is_accessor => {
+                buf.push(ClassMember::PrivateProp(PrivateProp {
+                    is_accessor: false,
+                    ..p
+                }));
+                continue;
+            }
+            ClassMember::ClassProp(p) if p.is_accessor => p,
+            _ => {
+                buf.push(member);
+                continue;
+            }
+        };
+
+        let hint = match &*p.key {
+            Expr::Ident(i) if !p.computed => i.sym.to_string(),
+            Expr::Lit(Lit::Str(s)) if !p.computed => s
+                .value
+                .chars()
+                .filter(|c| c.is_ascii_alphanumeric() || *c == '_' || *c == '$')
+                .collect(),
+            _ => String::new(),
+        };
+        let hint = if hint.is_empty() {
+            "accessor".to_string()
+        } else {
+            hint
+        };
+
+        let (get_key, set_key) = if p.computed {
+            // get [_key = foo]() {}
+            // set [_key](v) {}
+            let key = private_ident!("_key");
+            vars.push(VarDeclarator {
+                span: DUMMY_SP,
+                name: Pat::Ident(key.clone().into()),
+                init: None,
+                definite: false,
+            });
+
+            (
+                computed(Box::new(Expr::Assign(AssignExpr {
+                    span: DUMMY_SP,
+                    op: op!("="),
+                    left: PatOrExpr::Pat(Box::new(Pat::Ident(key.clone().into()))),
+                    right: p.key,
+                }))),
+                computed(Box::new(Expr::Ident(key))),
+            )
+        } else {
+            let key = match *p.key {
+                Expr::Ident(i) => PropName::Ident(i),
+                Expr::Lit(Lit::Str(s)) => PropName::Str(s),
+                Expr::Lit(Lit::Num(n)) => PropName::Num(n),
+                Expr::Lit(Lit::BigInt(b)) => PropName::BigInt(b),
+                key => computed(Box::new(key)),
+            };
+            (key.clone(), key)
+        };
+
+        let mut sym: JsWord = format!("__{}", hint).into();
+        let mut i = 1;
+        while private_names.contains(&sym) {
+            i += 1;
+            sym = format!("__{}{}", hint, i).into();
+        }
+        private_names.insert(sym.clone());
+        let storage = PrivateName {
+            span: DUMMY_SP,
+            id: Ident::new(sym, DUMMY_SP),
+        };
+
+        // this.#__foo
+        let storage_expr = Box::new(Expr::Member(MemberExpr {
+            span: DUMMY_SP,
+            obj: ThisExpr { span: DUMMY_SP }.as_obj(),
+            prop: Box::new(Expr::PrivateName(storage.clone())),
+            computed: false,
+        }));
+        let param = quote_ident!("v");
+
+        buf.push(ClassMember::PrivateProp(PrivateProp {
+            span: p.span,
+            key: storage,
+            value: p.value,
+            type_ann: None,
+            is_static: p.is_static,
+            decorators: p.decorators,
+            computed: false,
+            accessibility: None,
+            is_abstract: false,
+            is_optional: false,
+            is_override: false,
+            readonly: false,
+            definite: false,
+            is_accessor: false,
+        }));
+        buf.push(ClassMember::Method(ClassMethod {
+            span: DUMMY_SP,
+            key: get_key,
+            function: function(
+                vec![],
+                Stmt::Return(ReturnStmt {
+                    span: DUMMY_SP,
+                    arg: Some(storage_expr.clone()),
+                }),
+            ),
+            kind: MethodKind::Getter,
+            is_static: p.is_static,
+            accessibility: p.accessibility,
+            is_abstract: false,
+            is_optional: false,
+            is_override: false,
+        }));
+        buf.push(ClassMember::Method(ClassMethod {
+            span: DUMMY_SP,
+            key: set_key,
+            function: function(
+                vec![Param {
+                    span: DUMMY_SP,
+                    decorators: Default::default(),
+                    pat: Pat::Ident(param.clone().into()),
+                }],
+                Expr::Assign(AssignExpr {
+                    span: DUMMY_SP,
+                    op: op!("="),
+                    left: PatOrExpr::Expr(storage_expr),
+                    right: Box::new(Expr::Ident(param)),
+                })
+                .into_stmt(),
+            ),
+            kind: MethodKind::Setter,
+            is_static: p.is_static,
+            accessibility: p.accessibility,
+            is_abstract: false,
+            is_optional: false,
+            is_override: false,
+        }));
+    }
+
+    buf
+}
+
+fn computed(expr: Box<Expr>) -> PropName {
+    PropName::Computed(ComputedPropName {
+        span: DUMMY_SP,
+        expr,
+    })
+}
+
+fn function(params: Vec<Param>, stmt: Stmt) -> Function {
+    Function {
+        params,
+        decorators: Default::default(),
+        span: DUMMY_SP,
+        body: Some(BlockStmt {
+            span: DUMMY_SP,
+            stmts: vec![stmt],
+        }),
+        is_generator: false,
+        is_async: false,
+        type_params: Default::default(),
+        return_type: Default::default(),
+    }
+}
@@ -5386,3 +5386,67 @@ test!(
     }
     "
 );
+
+fn syntax_accessor() -> Syntax {
+    Syntax::Es(EsConfig {
+        class_private_props: true,
+        class_props: true,
+        auto_accessors: true,
+        ..Default::default()
+    })
+}
+
+test!(
+    syntax_accessor(),
+    |_| class_properties(),
+    accessor_private,
+    "
+    class Foo {
+      accessor #foo = 1;
+    }
+    ",
+    "
+    var _foo = new WeakMap();
+    class Foo {
+        constructor(){
+            _foo.set(this, {
+                writable: true,
+                value: 1
+            });
+        }
+    }
+    "
+);
+
+test_exec!(
+    syntax_accessor(),
+    |t| tr(t),
+    accessor_exec,
+    r#"
+let i = 0;
+
+class Foo {
+  accessor foo = 1;
+  static accessor bar = 2;
+  accessor [`baz${i++}`];
+}
+
+const a = new Foo();
+const b = new Foo();
+expect(a.foo).toBe(1);
+a.foo = 3;
+expect(a.foo).toBe(3);
+expect(b.foo).toBe(1);
+expect(Object.keys(a)).toEqual([]);
+
+expect(Foo.bar).toBe(2);
+Foo.bar = 4;
+expect(Foo.bar).toBe(4);
+
+expect(i).toBe(1);
+expect(a.baz0).toBeUndefined();
+a.baz0 = 5;
+expect(a.baz0).toBe(5);
+expect(b.baz0).toBeUndefined();
+"#
+);
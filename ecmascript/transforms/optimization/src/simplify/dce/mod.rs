@@ -1,4 +1,4 @@
-use self::side_effect::{ImportDetector, SideEffectVisitor};
+use self::side_effect::{ImportDetector, PureFnCollector, SideEffectVisitor};
 use fxhash::FxHashSet;
 use retain_mut::RetainMut;
 use std::{any::type_name, borrow::Cow, fmt::Debug, mem::take};
@@ -6,9 +6,10 @@ use swc_atoms::js_word;
 use swc_atoms::JsWord;
 use swc_common::{
     chain,
+    comments::Comments,
     pass::{CompilerPass, Repeated},
     util::move_map::MoveMap,
-    Mark, Span, Spanned,
+    Mark, Span, Spanned, DUMMY_SP,
 };
 use swc_ecma_ast::*;
 use swc_ecma_transforms_base::pass::RepeatedJsPass;
@@ -45,7 +46,13 @@ impl Default for Config<'_> {
     }
 }
 
-pub fn dce<'a>(config: Config<'a>) -> impl RepeatedJsPass + 'a {
+/// If `comments` is provided, calls annotated with `/*#__PURE__*/` and calls
+/// to functions annotated with `/*#__NO_SIDE_EFFECTS__*/` are treated as side
+/// effect free.
+pub fn dce<'a, C>(config: Config<'a>, comments: Option<C>) -> impl RepeatedJsPass + 'a
+where
+    C: 'a + Comments,
+{
     assert_ne!(
         config.used_mark,
         Mark::root(),
@@ -57,6 +64,8 @@ pub fn dce<'a>(config: Config<'a>) -> impl RepeatedJsPass + 'a {
     chain!(
         as_folder(Dce {
             config,
+            comments,
+            pure_fns: Default::default(),
             dropped: false,
             included: Default::default(),
             changed: false,
@@ -97,11 +106,18 @@ impl VisitMut for UsedMarkRemover {
     }
 }
 
-#[derive(Debug)]
-struct Dce<'a> {
+struct Dce<'a, C>
+where
+    C: Comments,
+{
     changed: bool,
     config: Config<'a>,
 
+    comments: Option<C>,
+
+    /// Functions annotated with `/*#__NO_SIDE_EFFECTS__*/`.
+    pure_fns: FxHashSet<Id>,
+
     /// Identifiers which should be emitted.
     included: FxHashSet<Id>,
 
@@ -122,13 +138,19 @@ struct Dce<'a> {
     cur_defining: FxHashSet<Id>,
 }
 
-impl CompilerPass for Dce<'_> {
+impl<C> CompilerPass for Dce<'_, C>
+where
+    C: Comments,
+{
     fn name() -> Cow<'static, str> {
         Cow::Borrowed("dce")
     }
 }
 
-impl Repeated for Dce<'_> {
+impl<C> Repeated for Dce<'_, C>
+where
+    C: Comments,
+{
     fn changed(&self) -> bool {
         self.dropped
     }
@@ -196,9 +218,24 @@ macro_rules! normal {
     };
 }
 
-impl VisitMut for Dce<'_> {
+impl<C> VisitMut for Dce<'_, C>
+where
+    C: Comments,
+{
     noop_visit_mut_type!();
 
+    fn visit_mut_module(&mut self, m: &mut Module) {
+        self.collect_pure_fns(&*m);
+
+        m.visit_mut_children_with(self);
+    }
+
+    fn visit_mut_script(&mut self, s: &mut Script) {
+        self.collect_pure_fns(&*s);
+
+        s.visit_mut_children_with(self);
+    }
+
     preserve!(visit_mut_debugger_stmt, DebuggerStmt);
     preserve!(visit_mut_with_stmt, WithStmt);
     preserve!(visit_mut_break_stmt, BreakStmt);
@@ -817,7 +854,25 @@ impl VisitMut for Dce<'_> {
     normal!(visit_mut_yield_expr, YieldExpr, arg);
 }
 
-impl Dce<'_> {
+impl<C> Dce<'_, C>
+where
+    C: Comments,
+{
+    fn collect_pure_fns<N>(&mut self, node: &N)
+    where
+        N: for<'any> VisitWith<PureFnCollector<'any>>,
+    {
+        self.pure_fns.clear();
+
+        if let Some(comments) = &self.comments {
+            let mut v = PureFnCollector {
+                comments,
+                pure_fns: &mut self.pure_fns,
+            };
+            node.visit_with(&Invalid { span: DUMMY_SP } as _, &mut v);
+        }
+    }
+
     fn visit_mut_stmt_like<T>(&mut self, items: &mut Vec<T>)
     where
         T: Debug + StmtLike + VisitMutWith<Self> + Spanned + std::fmt::Debug,
@@ -900,7 +955,10 @@ impl Dce<'_> {
     }
 }
 
-impl Dce<'_> {
+impl<C> Dce<'_, C>
+where
+    C: Comments,
+{
     pub fn is_marked(&self, span: Span) -> bool {
         let mut ctxt = span.ctxt().clone();
 
@@ -1,7 +1,10 @@
 use super::Dce;
 use fxhash::FxHashSet;
 use swc_atoms::JsWord;
-use swc_common::DUMMY_SP;
+use swc_common::{
+    comments::{CommentKind, Comments, CommentsExt},
+    BytePos, Span, Spanned, DUMMY_SP,
+};
 use swc_ecma_ast::*;
 use swc_ecma_utils::{ident::IdentLike, ExprExt, Id};
 use swc_ecma_visit::noop_visit_type;
@@ -19,7 +22,10 @@ impl Visit for ImportDetector {
     }
 }
 
-impl Dce<'_> {
+impl<C> Dce<'_, C>
+where
+    C: Comments,
+{
     pub(super) fn should_include<T>(&mut self, node: &T) -> bool
     where
         T: for<'any> VisitWith<SideEffectVisitor<'any>> + VisitWith<ImportDetector>,
@@ -38,6 +44,8 @@ impl Dce<'_> {
         let mut v = SideEffectVisitor {
             included: &mut self.included,
             exports: self.config.used.as_ref().map(|v| &**v),
+            comments: self.comments.as_ref().map(|c| c as &dyn Comments),
+            pure_fns: &self.pure_fns,
             found: false,
         };
 
@@ -62,6 +70,9 @@ impl SideEffectVisitor<'_> {
 pub(super) struct SideEffectVisitor<'a> {
     included: &'a mut FxHashSet<Id>,
     exports: Option<&'a [Id]>,
+    comments: Option<&'a dyn Comments>,
+    /// Functions annotated with `/*#__NO_SIDE_EFFECTS__*/`.
+    pure_fns: &'a FxHashSet<Id>,
     found: bool,
 }
 
@@ -80,6 +91,21 @@ impl SideEffectVisitor<'_> {
 
         false
     }
+
+    /// Returns true if the call or `new` expression at `span` does not have
+    /// side effects, excluding its arguments.
+    fn is_pure_call(&self, span: Span, callee: &Expr) -> bool {
+        if let Some(comments) = self.comments {
+            if has_annotation(comments, span.lo, "PURE") {
+                return true;
+            }
+        }
+
+        match callee {
+            Expr::Ident(i) => self.pure_fns.contains(&i.to_id()),
+            _ => false,
+        }
+    }
 }
 
 impl Visit for SideEffectVisitor<'_> {
@@ -140,6 +166,10 @@ impl Visit for SideEffectVisitor<'_> {
 
         match node.callee {
             ExprOrSuper::Expr(ref e) if e.is_pure_callee() => return,
+            ExprOrSuper::Expr(ref e) if self.is_pure_call(node.span, e) => {
+                node.args.visit_with(node as _, self);
+                return;
+            }
             _ => {}
         }
 
@@ -238,11 +268,16 @@ impl Visit for SideEffectVisitor<'_> {
         self.found = true
     }
 
-    fn visit_new_expr(&mut self, _: &NewExpr, _: &dyn Node) {
+    fn visit_new_expr(&mut self, node: &NewExpr, _: &dyn Node) {
         if self.found {
             return;
         }
 
+        if self.is_pure_call(node.span, &node.callee) {
+            node.args.visit_with(node as _, self);
+            return;
+        }
+
         self.found = true;
     }
 
@@ -284,3 +319,90 @@ impl Visit for SideEffectVisitor<'_> {
         self.found = true;
     }
 }
+
+/// Collects functions annotated with `/*#__NO_SIDE_EFFECTS__*/`.
+pub(super) struct PureFnCollector<'a> {
+    pub comments: &'a dyn Comments,
+    pub pure_fns: &'a mut FxHashSet<Id>,
+}
+
+impl PureFnCollector<'_> {
+    fn is_annotated(&self, pos: BytePos) -> bool {
+        has_annotation(self.comments, pos, "NO_SIDE_EFFECTS")
+    }
+
+    fn add_var(&mut self, var: &VarDecl, annotated: bool) {
+        for d in &var.decls {
+            let init = match &d.init {
+                Some(init) => init,
+                None => continue,
+            };
+
+            if let Pat::Ident(name) = &d.name {
+                if is_fn_expr(init) && (annotated || self.is_annotated(init.span().lo)) {
+                    self.pure_fns.insert(name.id.to_id());
+                }
+            }
+        }
+    }
+}
+
+impl Visit for PureFnCollector<'_> {
+    noop_visit_type!();
+
+    fn visit_export_decl(&mut self, n: &ExportDecl, _: &dyn Node) {
+        // `/*#__NO_SIDE_EFFECTS__*/ export function foo() {}`
+        if self.is_annotated(n.span.lo) {
+            match &n.decl {
+                Decl::Fn(f) => {
+                    self.pure_fns.insert(f.ident.to_id());
+                }
+                Decl::Var(var) => self.add_var(var, true),
+                _ => {}
+            }
+        }
+
+        n.visit_children_with(self);
+    }
+
+    fn visit_fn_decl(&mut self, n: &FnDecl, _: &dyn Node) {
+        if self.is_annotated(n.function.span.lo) {
+            self.pure_fns.insert(n.ident.to_id());
+        }
+
+        n.visit_children_with(self);
+    }
+
+    fn visit_var_decl(&mut self, n: &VarDecl, _: &dyn Node) {
+        let annotated = self.is_annotated(n.span.lo);
+        self.add_var(n, annotated);
+
+        n.visit_children_with(self);
+    }
+}
+
+fn is_fn_expr(e: &Expr) -> bool {
+    match e {
+        Expr::Fn(..) | Expr::Arrow(..) => true,
+        Expr::Paren(ParenExpr { expr, .. }) => is_fn_expr(expr),
+        _ => false,
+    }
+}
+
+/// Returns true if there's a `/*#__NAME__*/` or `/*@__NAME__*/` comment
+/// right before `pos`.
+fn has_annotation(comments: &dyn Comments, pos: BytePos, name: &str) -> bool {
+    comments.with_leading(pos, |comments| {
+        comments.iter().any(|c| {
+            if c.kind != CommentKind::Block {
+                return false;
+            }
+
+            let text = c.text.trim();
+            (text.starts_with("#__") || text.starts_with("@__"))
+                && text.ends_with("__")
+                && text.len() == name.len() + 5
+                && &text[3..text.len() - 2] == name
+        })
+    })
+}
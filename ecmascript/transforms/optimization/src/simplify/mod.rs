@@ -1,6 +1,6 @@
 //! Ported from closure compiler.
 pub use self::{branch::dead_branch_remover, expr::expr_simplifier};
-use swc_common::{chain, pass::Repeat};
+use swc_common::{chain, comments::Comments, pass::Repeat};
 use swc_ecma_transforms_base::pass::RepeatedJsPass;
 
 mod branch;
@@ -17,11 +17,16 @@ pub struct Config<'a> {
 
 /// Performs simplify-expr, inlining, remove-dead-branch and dce until nothing
 /// changes.
-pub fn simplifier<'a>(c: Config<'a>) -> impl RepeatedJsPass + 'a {
+///
+/// `comments` is used by dce to find `/*#__PURE__*/` annotations.
+pub fn simplifier<'a, C>(c: Config<'a>, comments: Option<C>) -> impl RepeatedJsPass + 'a
+where
+    C: 'a + Comments,
+{
     Repeat::new(chain!(
         expr_simplifier(),
         inlining::inlining(c.inlining),
         dead_branch_remover(),
-        dce::dce(c.dce)
+        dce::dce(c.dce, comments)
     ))
 }
@@ -15,7 +15,7 @@ fn dce_single_pass(input: PathBuf) {
             dynamic_import: true,
             ..Default::default()
         }),
-        &|_| dce(Default::default()),
+        &|t| dce(Default::default(), Some(t.comments.clone())),
         &input,
         &output,
     );
@@ -30,7 +30,7 @@ fn dce_repeated(input: PathBuf) {
             dynamic_import: true,
             ..Default::default()
         }),
-        &|_| Repeat::new(dce(Default::default())),
+        &|t| Repeat::new(dce(Default::default(), Some(t.comments.clone()))),
         &input,
         &output,
     );
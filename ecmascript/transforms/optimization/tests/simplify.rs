@@ -26,7 +26,12 @@ use swc_ecma_transforms_typescript::strip;
 fn test(src: &str, expected: &str) {
     test_transform(
         ::swc_ecma_parser::Syntax::default(),
-        |_| chain!(resolver(), simplifier(Default::default())),
+        |t| {
+            chain!(
+                resolver(),
+                simplifier(Default::default(), Some(t.comments.clone()))
+            )
+        },
         src,
         expected,
         true,
@@ -41,7 +46,10 @@ macro_rules! to {
     ($name:ident, $src:expr, $expected:expr) => {
         test!(
             Default::default(),
-            |_| chain!(resolver(), simplifier(Default::default())),
+            |t| chain!(
+                resolver(),
+                simplifier(Default::default(), Some(t.comments.clone()))
+            ),
             $name,
             $src,
             $expected
@@ -465,10 +473,10 @@ test!(
         decorators: true,
         ..Default::default()
     }),
-    |_| chain!(
+    |t| chain!(
         strip(),
         resolver(),
-        dce(Default::default()),
+        dce(Default::default(), Some(t.comments.clone())),
         inlining(Default::default())
     ),
     issue_1156_1,
@@ -535,7 +543,7 @@ test!(
         strip(),
         decorators(Default::default()),
         class_properties(),
-        simplifier(Default::default()),
+        simplifier(Default::default(), Some(t.comments.clone())),
         es2018(),
         es2017(),
         es2016(),
@@ -15,7 +15,10 @@ macro_rules! to {
                 decorators: true,
                 ..Default::default()
             }),
-            |_| chain!(resolver(), dce(Default::default())),
+            |t| chain!(
+                resolver(),
+                dce(Default::default(), Some(t.comments.clone()))
+            ),
             $name,
             $src,
             $expected
@@ -26,20 +29,23 @@ macro_rules! to {
 fn used(ids: &[&str], src: &str, expected: &str) {
     test_transform(
         Default::default(),
-        |_| {
+        |t| {
             let mark = Mark::fresh(Mark::root());
 
             chain!(
                 resolver(),
-                dce(dce::Config {
-                    used: Some(
-                        ids.into_iter()
-                            .map(|&v| { (v.into(), SyntaxContext::empty().apply_mark(mark)) })
-                            .collect()
-                    ),
-                    used_mark: mark,
-                    ..Default::default()
-                })
+                dce(
+                    dce::Config {
+                        used: Some(
+                            ids.into_iter()
+                                .map(|&v| { (v.into(), SyntaxContext::empty().apply_mark(mark)) })
+                                .collect()
+                        ),
+                        used_mark: mark,
+                        ..Default::default()
+                    },
+                    Some(t.comments.clone())
+                )
             )
         },
         src,
@@ -479,14 +485,15 @@ test!(
         decorators: true,
         ..Default::default()
     }),
-    |_| chain!(
+    |t| chain!(
         resolver(),
         strip(),
         decorators(decorators::Config {
             legacy: true,
-            emit_metadata: false
+            emit_metadata: false,
+            ..Default::default()
         }),
-        dce(Default::default())
+        dce(Default::default(), Some(t.comments.clone()))
     ),
     issue_898_2,
     "export default class X {
@@ -526,14 +533,15 @@ test!(
         decorators: true,
         ..Default::default()
     }),
-    |_| chain!(
+    |t| chain!(
         resolver(),
         strip(),
         decorators(decorators::Config {
             legacy: true,
-            emit_metadata: false
+            emit_metadata: false,
+            ..Default::default()
         }),
-        dce(Default::default())
+        dce(Default::default(), Some(t.comments.clone()))
     ),
     issue_1111,
     "
@@ -553,7 +561,10 @@ test!(
         decorators: true,
         ..Default::default()
     }),
-    |_| chain!(resolver(), dce(Default::default())),
+    |t| chain!(
+        resolver(),
+        dce(Default::default(), Some(t.comments.clone()))
+    ),
     issue_1150_1,
     "
 class A {
@@ -600,7 +611,11 @@ test!(
         decorators: true,
         ..Default::default()
     }),
-    |_| chain!(strip(), resolver(), dce(Default::default())),
+    |t| chain!(
+        strip(),
+        resolver(),
+        dce(Default::default(), Some(t.comments.clone()))
+    ),
     issue_1156_1,
     "
     export interface D {
@@ -635,7 +650,11 @@ test!(
         decorators: true,
         ..Default::default()
     }),
-    |_| chain!(strip(), resolver(), dce(Default::default()),),
+    |t| chain!(
+        strip(),
+        resolver(),
+        dce(Default::default(), Some(t.comments.clone())),
+    ),
     issue_1156_2,
     "
     interface D {
@@ -696,7 +715,11 @@ test!(
         decorators: true,
         ..Default::default()
     }),
-    |_| chain!(strip(), resolver(), dce(Default::default()),),
+    |t| chain!(
+        strip(),
+        resolver(),
+        dce(Default::default(), Some(t.comments.clone())),
+    ),
     issue_1156_3,
     "
     function d() {
@@ -729,7 +752,11 @@ test!(
         decorators: true,
         ..Default::default()
     }),
-    |_| chain!(strip(), resolver(), dce(Default::default()),),
+    |t| chain!(
+        strip(),
+        resolver(),
+        dce(Default::default(), Some(t.comments.clone())),
+    ),
     issue_1156_4,
     "
     interface D {
@@ -955,3 +982,36 @@ noop!(
     wt()
     "
 );
+
+to!(
+    pure_annotation,
+    "
+const a = /*#__PURE__*/ foo();
+const b = /*#__PURE__*/ new Foo();
+/*#__PURE__*/ bar();
+const c = /*#__PURE__*/ baz(sideEffect());
+const d = foo();
+",
+    "
+const c = /*#__PURE__*/ baz(sideEffect());
+const d = foo();
+"
+);
+
+to!(
+    no_side_effects_annotation,
+    "
+/*#__NO_SIDE_EFFECTS__*/
+function foo() {}
+const bar = /*#__NO_SIDE_EFFECTS__*/ () => {};
+/*#__NO_SIDE_EFFECTS__*/
+const baz = function () {};
+const a = foo();
+bar();
+baz(1);
+export const b = 1;
+",
+    "
+export const b = 1;
+"
+);
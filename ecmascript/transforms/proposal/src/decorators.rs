@@ -1,5 +1,5 @@
 use either::Either;
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use std::iter;
 use swc_common::{Spanned, DUMMY_SP};
 use swc_ecma_ast::*;
@@ -9,10 +9,11 @@ use swc_ecma_utils::quote_ident;
 use swc_ecma_utils::quote_str;
 use swc_ecma_utils::{
     alias_ident_for, constructor::inject_after_super, prop_name_to_expr_value, undefined,
-    ExprFactory, IdentExt,
+    ExprFactory, IdentExt, HANDLER,
 };
 use swc_ecma_visit::{noop_fold_type, Fold, FoldWith, Node, Visit, VisitWith};
 
+mod decorator_2022_03;
 mod legacy;
 
 /// ## Simple class decorator
@@ -62,9 +63,14 @@ pub fn decorators(c: Config) -> impl Fold {
         if c.emit_metadata {
             unimplemented!("emitting decorator metadata while using new proposal")
         }
-        Either::Right(Decorators {
-            is_in_strict: false,
-        })
+        match c.decorator_version {
+            DecoratorVersion::V201809 => Either::Right(Either::Left(Decorators {
+                is_in_strict: false,
+            })),
+            DecoratorVersion::V202203 => {
+                Either::Right(Either::Right(self::decorator_2022_03::decorator_2022_03()))
+            }
+        }
     }
 }
 
@@ -74,6 +80,24 @@ pub struct Config {
     pub legacy: bool,
     #[serde(default)]
     pub emit_metadata: bool,
+    /// Version of the proposal to follow. Ignored if `legacy` is true.
+    #[serde(default)]
+    pub decorator_version: DecoratorVersion,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum DecoratorVersion {
+    #[serde(rename = "2018-09")]
+    V201809,
+    /// Supports `accessor` class members and `context.addInitializer`.
+    #[serde(rename = "2022-03")]
+    V202203,
+}
+
+impl Default for DecoratorVersion {
+    fn default() -> Self {
+        DecoratorVersion::V201809
+    }
 }
 
 #[derive(Debug, Default)]
@@ -249,6 +273,8 @@ impl Fold for Decorators {
 
 impl Decorators {
     fn fold_class_inner(&self, ident: Ident, mut class: Class) -> Expr {
+        report_accessors(&class);
+
         let initialize = private_ident!("_initialize");
         let super_class_ident = match class.super_class {
             Some(ref expr) => Some(alias_ident_for(expr, "_super")),
@@ -646,6 +672,35 @@ impl Visit for DecoratorFinder {
     }
 }
 
+/// Reports `accessor` class members, which are supported only by the 2022-03
+/// version of the proposal.
+fn report_accessors(class: &Class) {
+    for member in &class.body {
+        let span = match member {
+            ClassMember::ClassProp(ClassProp {
+                span,
+                is_accessor: true,
+                ..
+            })
+            | ClassMember::PrivateProp(PrivateProp {
+                span,
+                is_accessor: true,
+                ..
+            }) => *span,
+            _ => continue,
+        };
+
+        HANDLER.with(|handler| {
+            handler
+                .struct_span_err(
+                    span,
+                    "`accessor` is supported only by the 2022-03 version of decorators",
+                )
+                .emit();
+        });
+    }
+}
+
 fn contains_decorator<N>(node: &N) -> bool
 where
     N: VisitWith<DecoratorFinder>,
@@ -0,0 +1,914 @@
+use fxhash::FxHashSet;
+use std::mem::{replace, take};
+use swc_atoms::JsWord;
+use swc_common::{Span, DUMMY_SP};
+use swc_ecma_ast::*;
+use swc_ecma_transforms_base::helper;
+use swc_ecma_utils::{
+    constructor::inject_after_super, default_constructor, private_ident, quote_ident, quote_str,
+    undefined, ExprFactory, ModuleItemLike,
+};
+use swc_ecma_visit::{as_folder, noop_visit_mut_type, Fold, VisitMut, VisitMutWith};
+
+const FIELD: f64 = 0.0;
+const ACCESSOR: f64 = 1.0;
+const METHOD: f64 = 2.0;
+const GETTER: f64 = 3.0;
+const SETTER: f64 = 4.0;
+const STATIC: f64 = 5.0;
+
+/// Lowers decorators following the semantics of the 2022-03 version of the
+/// proposal, including `accessor` class members.
+///
+/// The decorators are applied by `applyDecs2203` from a static private field,
+/// which is the first member of the class. It returns the initializers of
+/// fields, the wrappers of private methods and accessors and the decorated
+/// class, which are assigned to temporary variables declared before the
+/// class.
+///
+/// Decorators of a class expression are evaluated in a sequence expression,
+/// and the temporary variables are declared before the statement containing
+/// it. An arrow function with an expression body is converted to have a block
+/// body for them.
+pub(super) fn decorator_2022_03() -> impl Fold {
+    as_folder(Decorator202203::default())
+}
+
+#[derive(Default)]
+struct Decorator202203 {
+    /// Temporary variables of class expressions in the current statement.
+    vars: Vec<Ident>,
+}
+
+impl VisitMut for Decorator202203 {
+    noop_visit_mut_type!();
+
+    fn visit_mut_arrow_expr(&mut self, f: &mut ArrowExpr) {
+        f.params.visit_mut_with(self);
+
+        let outer = take(&mut self.vars);
+        f.body.visit_mut_with(self);
+        if let BlockStmtOrExpr::Expr(expr) = &mut f.body {
+            if !self.vars.is_empty() {
+                let arg = replace(expr, undefined(DUMMY_SP));
+                f.body = BlockStmtOrExpr::BlockStmt(BlockStmt {
+                    span: DUMMY_SP,
+                    stmts: vec![var_decl(take(&mut self.vars)), return_stmt(arg)],
+                });
+            }
+        }
+        self.vars = outer;
+    }
+
+    fn visit_mut_expr(&mut self, e: &mut Expr) {
+        e.visit_mut_children_with(self);
+
+        let needs_transform = match e {
+            Expr::Class(c) => needs_transform(&c.class),
+            _ => false,
+        };
+        if !needs_transform {
+            return;
+        }
+
+        let ClassExpr { ident, mut class } =
+            match replace(e, Expr::Invalid(Invalid { span: DUMMY_SP })) {
+                Expr::Class(c) => c,
+                _ => unreachable!(),
+            };
+        let span = class.span;
+
+        // (_dec = dec, class Foo {}, _initClass(), _Foo)
+        let class_ident = ident.clone().unwrap_or_else(|| private_ident!("_class"));
+        let t = transform_class(&class_ident, &mut class);
+        self.vars.extend(t.vars);
+
+        let mut exprs = t.hoisted;
+        exprs.push(Box::new(Expr::Class(ClassExpr { ident, class })));
+        if let Some((new_class, init_class)) = t.class_init {
+            exprs.push(call(init_class, vec![]));
+            exprs.push(Box::new(Expr::Ident(new_class)));
+        }
+
+        *e = if exprs.len() == 1 {
+            *exprs.pop().unwrap()
+        } else {
+            Expr::Seq(SeqExpr { span, exprs })
+        };
+    }
+
+    fn visit_mut_module_items(&mut self, items: &mut Vec<ModuleItem>) {
+        self.visit_mut_stmt_likes(items)
+    }
+
+    fn visit_mut_stmts(&mut self, stmts: &mut Vec<Stmt>) {
+        self.visit_mut_stmt_likes(stmts)
+    }
+}
+
+impl Decorator202203 {
+    fn visit_mut_stmt_likes<T>(&mut self, stmts: &mut Vec<T>)
+    where
+        T: ModuleItemLike + VisitMutWith<Self>,
+    {
+        let outer = take(&mut self.vars);
+        let mut buf = Vec::with_capacity(stmts.len());
+
+        for mut stmt in stmts.drain(..) {
+            stmt.visit_mut_with(self);
+
+            if !self.vars.is_empty() {
+                buf.push(T::from_stmt(var_decl(take(&mut self.vars))));
+            }
+
+            let stmt = match stmt.try_into_stmt() {
+                Ok(Stmt::Decl(Decl::Class(mut c))) if needs_transform(&c.class) => {
+                    let (pre, post) = transform_class(&c.ident, &mut c.class).into_stmts(&c.ident);
+
+                    buf.extend(pre.into_iter().map(T::from_stmt));
+                    buf.push(T::from_stmt(Stmt::Decl(Decl::Class(c))));
+                    buf.extend(post.into_iter().map(T::from_stmt));
+                    continue;
+                }
+                Ok(stmt) => T::from_stmt(stmt),
+                Err(stmt) => stmt,
+            };
+
+            match stmt.try_into_module_decl() {
+                Ok(ModuleDecl::ExportDecl(ExportDecl {
+                    span,
+                    decl: Decl::Class(mut c),
+                })) if needs_transform(&c.class) => {
+                    let (pre, post) = transform_class(&c.ident, &mut c.class).into_stmts(&c.ident);
+
+                    buf.extend(pre.into_iter().map(T::from_stmt));
+                    buf.push(
+                        T::try_from_module_decl(ModuleDecl::ExportDecl(ExportDecl {
+                            span,
+                            decl: Decl::Class(c),
+                        }))
+                        .unwrap(),
+                    );
+                    buf.extend(post.into_iter().map(T::from_stmt));
+                }
+                Ok(ModuleDecl::ExportDefaultDecl(ExportDefaultDecl {
+                    span,
+                    decl: DefaultDecl::Class(ClassExpr { ident, mut class }),
+                })) if needs_transform(&class) => {
+                    let ident = ident.unwrap_or_else(|| private_ident!("_default"));
+                    let (pre, post) = transform_class(&ident, &mut class).into_stmts(&ident);
+
+                    buf.extend(pre.into_iter().map(T::from_stmt));
+                    buf.push(T::from_stmt(Stmt::Decl(Decl::Class(ClassDecl {
+                        ident: ident.clone(),
+                        declare: false,
+                        class,
+                    }))));
+                    buf.extend(post.into_iter().map(T::from_stmt));
+
+                    // export { Foo as default }
+                    buf.push(
+                        T::try_from_module_decl(ModuleDecl::ExportNamed(NamedExport {
+                            span,
+                            specifiers: vec![ExportNamedSpecifier {
+                                span: DUMMY_SP,
+                                orig: ident,
+                                exported: Some(quote_ident!("default")),
+                            }
+                            .into()],
+                            src: None,
+                            type_only: false,
+                            asserts: None,
+                        }))
+                        .unwrap(),
+                    );
+                }
+                Ok(decl) => buf.push(T::try_from_module_decl(decl).unwrap()),
+                Err(stmt) => buf.push(stmt),
+            }
+        }
+
+        *stmts = buf;
+        self.vars = outer;
+    }
+}
+
+fn needs_transform(class: &Class) -> bool {
+    !class.decorators.is_empty()
+        || class.body.iter().any(|member| match member {
+            ClassMember::Method(m) => !m.function.decorators.is_empty(),
+            ClassMember::PrivateMethod(m) => !m.function.decorators.is_empty(),
+            ClassMember::ClassProp(p) => !p.decorators.is_empty() || p.is_accessor,
+            ClassMember::PrivateProp(p) => !p.decorators.is_empty() || p.is_accessor,
+            _ => false,
+        })
+}
+
+/// Temporary variables and expressions required by a transformed class.
+struct Transformed {
+    vars: Vec<Ident>,
+    /// Decorators and computed keys, which are evaluated before the class.
+    hoisted: Vec<Box<Expr>>,
+    /// `(_Foo, _initClass)` if the class itself is decorated.
+    class_init: Option<(Ident, Ident)>,
+}
+
+impl Transformed {
+    /// Returns statements which should be placed before and after the class
+    /// declaration.
+    fn into_stmts(self, class_ident: &Ident) -> (Vec<Stmt>, Vec<Stmt>) {
+        let mut pre = vec![];
+        if !self.vars.is_empty() {
+            pre.push(var_decl(self.vars));
+        }
+        pre.extend(self.hoisted.into_iter().map(|e| e.into_stmt()));
+
+        let mut post = vec![];
+        if let Some((new_class, init_class)) = self.class_init {
+            // Foo = _Foo;
+            post.push(
+                Expr::Assign(AssignExpr {
+                    span: DUMMY_SP,
+                    op: op!("="),
+                    left: PatOrExpr::Pat(Box::new(Pat::Ident(class_ident.clone().into()))),
+                    right: Box::new(Expr::Ident(new_class)),
+                })
+                .into_stmt(),
+            );
+            // _initClass();
+            post.push(call(init_class, vec![]).into_stmt());
+        }
+
+        (pre, post)
+    }
+}
+
+/// Transforms `class` in place.
+fn transform_class(class_ident: &Ident, class: &mut Class) -> Transformed {
+    let mut s = ClassState::default();
+
+    for member in &class.body {
+        match member {
+            ClassMember::PrivateMethod(PrivateMethod { key, .. })
+            | ClassMember::PrivateProp(PrivateProp { key, .. }) => {
+                s.private_names.insert(key.id.sym.clone());
+            }
+            _ => {}
+        }
+    }
+
+    let class_decs: Vec<_> = take(&mut class.decorators)
+        .into_iter()
+        .map(|dec| s.hoist_dec(dec.expr))
+        .collect();
+
+    let mut body = Vec::with_capacity(class.body.len() + 1);
+    for member in take(&mut class.body) {
+        match member {
+            ClassMember::ClassProp(p) if !p.decorators.is_empty() || p.is_accessor => {
+                s.class_prop(p, &mut body)
+            }
+            ClassMember::PrivateProp(p) if !p.decorators.is_empty() || p.is_accessor => {
+                s.private_prop(p, &mut body)
+            }
+            ClassMember::Method(m) if !m.function.decorators.is_empty() => s.method(m, &mut body),
+            ClassMember::PrivateMethod(m) if !m.function.decorators.is_empty() => {
+                s.private_method(m, &mut body)
+            }
+            _ => body.push(member),
+        }
+    }
+    class.body = body;
+
+    let mut class_init = None;
+
+    if !s.dec_infos.is_empty() || !class_decs.is_empty() {
+        if let Some(init_proto) = &s.init_proto {
+            s.targets.push(init_proto.clone());
+            inject_init_proto(class, init_proto.clone());
+        }
+        if let Some(init_static) = &s.init_static {
+            s.targets.push(init_static.clone());
+        }
+        if !class_decs.is_empty() {
+            let new_class = private_ident!(format!("_{}", class_ident.sym));
+            let init_class = private_ident!("_initClass");
+            s.vars.push(new_class.clone());
+            s.vars.push(init_class.clone());
+            s.targets.push(new_class.clone());
+            s.targets.push(init_class.clone());
+            class_init = Some((new_class, init_class));
+        }
+
+        // [_init_foo, _initProto] = _applyDecs2203(this, [...], [...])
+        let apply = Box::new(Expr::Assign(AssignExpr {
+            span: DUMMY_SP,
+            op: op!("="),
+            left: PatOrExpr::Pat(Box::new(Pat::Array(ArrayPat {
+                span: DUMMY_SP,
+                elems: s
+                    .targets
+                    .drain(..)
+                    .map(|i| Some(Pat::Ident(i.into())))
+                    .collect(),
+                optional: false,
+                type_ann: Default::default(),
+            }))),
+            right: Box::new(Expr::Call(CallExpr {
+                span: DUMMY_SP,
+                callee: helper!(apply_decs_2203, "applyDecs2203"),
+                args: vec![
+                    ThisExpr { span: DUMMY_SP }.as_arg(),
+                    ArrayLit {
+                        span: DUMMY_SP,
+                        elems: take(&mut s.dec_infos),
+                    }
+                    .as_arg(),
+                    array(class_decs).as_arg(),
+                ],
+                type_args: Default::default(),
+            })),
+        }));
+        let value = match &s.init_static {
+            // Static methods are decorated before static fields are initialized.
+            Some(init_static) => Box::new(Expr::Seq(SeqExpr {
+                span: DUMMY_SP,
+                exprs: vec![apply, call(init_static.clone(), vec![this()])],
+            })),
+            None => apply,
+        };
+
+        let name = s.private_name("__decs");
+        class.body.insert(
+            0,
+            ClassMember::PrivateProp(PrivateProp {
+                span: DUMMY_SP,
+                key: name,
+                value: Some(value),
+                type_ann: Default::default(),
+                is_static: true,
+                decorators: Default::default(),
+                computed: false,
+                accessibility: Default::default(),
+                is_abstract: false,
+                is_optional: false,
+                is_override: false,
+                readonly: false,
+                definite: false,
+                is_accessor: false,
+            }),
+        );
+    }
+
+    Transformed {
+        vars: s.vars,
+        hoisted: s.hoisted,
+        class_init,
+    }
+}
+
+/// Calls `_initProto(this)` before the first instance field is initialized,
+/// or from the constructor if there's no instance field.
+fn inject_init_proto(class: &mut Class, init_proto: Ident) {
+    let init = call(init_proto, vec![this()]);
+
+    for member in &mut class.body {
+        let value = match member {
+            ClassMember::ClassProp(ClassProp {
+                is_static: false,
+                value,
+                ..
+            })
+            | ClassMember::PrivateProp(PrivateProp {
+                is_static: false,
+                value,
+                ..
+            }) => value,
+            _ => continue,
+        };
+
+        let prev = value.take().unwrap_or_else(|| undefined(DUMMY_SP));
+        *value = Some(Box::new(Expr::Seq(SeqExpr {
+            span: DUMMY_SP,
+            exprs: vec![init, prev],
+        })));
+        return;
+    }
+
+    let pos = class
+        .body
+        .iter()
+        .position(|member| matches!(member, ClassMember::Constructor(..)));
+    match pos {
+        Some(pos) => {
+            if let ClassMember::Constructor(c) = &mut class.body[pos] {
+                inject_after_super(c, vec![init]);
+            }
+        }
+        None => {
+            let mut c = default_constructor(class.super_class.is_some());
+            inject_after_super(&mut c, vec![init]);
+            class.body.push(ClassMember::Constructor(c));
+        }
+    }
+}
+
+#[derive(Default)]
+struct ClassState {
+    /// Temporary variables, declared before the class.
+    vars: Vec<Ident>,
+    /// Decorators and computed keys, which are evaluated before the class.
+    hoisted: Vec<Box<Expr>>,
+    /// Arguments for `applyDecs2203`, one per decorated member.
+    dec_infos: Vec<Option<ExprOrSpread>>,
+    /// Variables assigned from the return value of `applyDecs2203`.
+    targets: Vec<Ident>,
+    init_proto: Option<Ident>,
+    init_static: Option<Ident>,
+    private_names: FxHashSet<JsWord>,
+}
+
+impl ClassState {
+    fn var(&mut self, name: &str) -> Ident {
+        let i = private_ident!(name);
+        self.vars.push(i.clone());
+        i
+    }
+
+    fn hoist(&mut self, name: &str, e: Box<Expr>) -> Ident {
+        let i = self.var(name);
+        self.hoisted.push(Box::new(Expr::Assign(AssignExpr {
+            span: DUMMY_SP,
+            op: op!("="),
+            left: PatOrExpr::Pat(Box::new(Pat::Ident(i.clone().into()))),
+            right: e,
+        })));
+        i
+    }
+
+    /// Decorators are evaluated in the order of appearance, before the class
+    /// is defined.
+    fn hoist_dec(&mut self, e: Box<Expr>) -> Box<Expr> {
+        match *e {
+            Expr::Ident(..) => e,
+            _ => Box::new(Expr::Ident(self.hoist("_dec", e))),
+        }
+    }
+
+    fn hoist_decs(&mut self, decs: Vec<Decorator>) -> Vec<Box<Expr>> {
+        decs.into_iter()
+            .map(|dec| self.hoist_dec(dec.expr))
+            .collect()
+    }
+
+    /// Returns a private name which is not used by the class.
+    fn private_name(&mut self, hint: &str) -> PrivateName {
+        let mut sym: JsWord = hint.into();
+        let mut i = 1;
+        while self.private_names.contains(&sym) {
+            i += 1;
+            sym = format!("{}{}", hint, i).into();
+        }
+        self.private_names.insert(sym.clone());
+
+        PrivateName {
+            span: DUMMY_SP,
+            id: Ident::new(sym, DUMMY_SP),
+        }
+    }
+
+    fn add_dec_info(
+        &mut self,
+        decs: Vec<Box<Expr>>,
+        kind: f64,
+        is_static: bool,
+        name: Box<Expr>,
+        extra: Vec<Box<Expr>>,
+    ) {
+        if kind != FIELD {
+            if is_static {
+                if self.init_static.is_none() {
+                    self.init_static = Some(self.var("_initStatic"));
+                }
+            } else if self.init_proto.is_none() {
+                self.init_proto = Some(self.var("_initProto"));
+            }
+        }
+
+        let kind = if is_static { kind + STATIC } else { kind };
+        let mut elems = vec![
+            Some(array(decs).as_arg()),
+            Some(
+                Lit::Num(Number {
+                    span: DUMMY_SP,
+                    value: kind,
+                })
+                .as_arg(),
+            ),
+            Some(name.as_arg()),
+        ];
+        elems.extend(extra.into_iter().map(|e| Some(e.as_arg())));
+
+        self.dec_infos.push(Some(
+            ArrayLit {
+                span: DUMMY_SP,
+                elems,
+            }
+            .as_arg(),
+        ));
+    }
+
+    /// Returns the name passed to decorators and a hint for temporary
+    /// variables. Computed keys are replaced with a temporary variable.
+    fn key_name(&mut self, key: &mut Box<Expr>, computed: bool) -> (Box<Expr>, String) {
+        if computed {
+            let k = self.hoist("_computedKey", replace(key, undefined(DUMMY_SP)));
+            *key = Box::new(Expr::Ident(k.clone()));
+            return (Box::new(Expr::Ident(k)), "computedKey".into());
+        }
+
+        match &**key {
+            Expr::Ident(i) => (
+                Box::new(Expr::Lit(Lit::Str(quote_str!(i.sym.clone())))),
+                i.sym.to_string(),
+            ),
+            Expr::Lit(Lit::Str(s)) => (key.clone(), hint_of(&s.value)),
+            _ => (key.clone(), String::new()),
+        }
+    }
+
+    fn prop_name_key(&mut self, key: &mut PropName) -> (Box<Expr>, String) {
+        match key {
+            PropName::Ident(i) => (
+                Box::new(Expr::Lit(Lit::Str(quote_str!(i.sym.clone())))),
+                i.sym.to_string(),
+            ),
+            PropName::Str(s) => (Box::new(Expr::Lit(Lit::Str(s.clone()))), hint_of(&s.value)),
+            PropName::Num(n) => (Box::new(Expr::Lit(Lit::Num(n.clone()))), String::new()),
+            PropName::BigInt(b) => (Box::new(Expr::Lit(Lit::BigInt(b.clone()))), String::new()),
+            PropName::Computed(c) => {
+                let k = self.hoist("_computedKey", replace(&mut c.expr, undefined(DUMMY_SP)));
+                c.expr = Box::new(Expr::Ident(k.clone()));
+                (Box::new(Expr::Ident(k)), "computedKey".into())
+            }
+        }
+    }
+
+    fn class_prop(&mut self, mut p: ClassProp, body: &mut Vec<ClassMember>) {
+        let decs = self.hoist_decs(take(&mut p.decorators));
+        let (name, hint) = self.key_name(&mut p.key, p.computed);
+
+        let value = if decs.is_empty() {
+            p.value.take()
+        } else {
+            let init = self.var(&format!("_init_{}", hint));
+            self.targets.push(init.clone());
+            let kind = if p.is_accessor { ACCESSOR } else { FIELD };
+            self.add_dec_info(decs, kind, p.is_static, name, vec![]);
+
+            Some(call(
+                init,
+                vec![
+                    this(),
+                    p.value.take().unwrap_or_else(|| undefined(DUMMY_SP)),
+                ],
+            ))
+        };
+
+        if !p.is_accessor {
+            body.push(ClassMember::ClassProp(ClassProp { value, ..p }));
+            return;
+        }
+
+        // accessor foo = 1;
+        //
+        // #__foo = 1;
+        // get foo() { return this.#__foo; }
+        // set foo(v) { this.#__foo = v; }
+        let storage = self.private_name(&format!("__{}", hint));
+        let key = if p.computed {
+            PropName::Computed(ComputedPropName {
+                span: DUMMY_SP,
+                expr: p.key,
+            })
+        } else {
+            match *p.key {
+                Expr::Ident(i) => PropName::Ident(i),
+                Expr::Lit(Lit::Str(s)) => PropName::Str(s),
+                Expr::Lit(Lit::Num(n)) => PropName::Num(n),
+                Expr::Lit(Lit::BigInt(b)) => PropName::BigInt(b),
+                key => PropName::Computed(ComputedPropName {
+                    span: DUMMY_SP,
+                    expr: Box::new(key),
+                }),
+            }
+        };
+
+        body.push(storage_prop(p.span, storage.clone(), value, p.is_static));
+        body.push(ClassMember::Method(ClassMethod {
+            span: DUMMY_SP,
+            key: key.clone(),
+            function: getter(vec![return_stmt(storage_get(&storage))]),
+            kind: MethodKind::Getter,
+            is_static: p.is_static,
+            accessibility: Default::default(),
+            is_abstract: false,
+            is_optional: false,
+            is_override: false,
+        }));
+        body.push(ClassMember::Method(ClassMethod {
+            span: DUMMY_SP,
+            key,
+            function: setter(storage_set(&storage).into_stmt()),
+            kind: MethodKind::Setter,
+            is_static: p.is_static,
+            accessibility: Default::default(),
+            is_abstract: false,
+            is_optional: false,
+            is_override: false,
+        }));
+    }
+
+    fn private_prop(&mut self, mut p: PrivateProp, body: &mut Vec<ClassMember>) {
+        let decs = self.hoist_decs(take(&mut p.decorators));
+        let hint = p.key.id.sym.to_string();
+        let name = Box::new(Expr::Lit(Lit::Str(quote_str!(format!("#{}", hint)))));
+
+        if !p.is_accessor {
+            // #foo = _init_foo(this, 1);
+            let init = self.var(&format!("_init_{}", hint));
+            self.targets.push(init.clone());
+            let extra = vec![
+                Box::new(Expr::Fn(FnExpr {
+                    ident: None,
+                    function: getter(vec![return_stmt(storage_get(&p.key))]),
+                })),
+                Box::new(Expr::Fn(FnExpr {
+                    ident: None,
+                    function: setter(storage_set(&p.key).into_stmt()),
+                })),
+            ];
+            self.add_dec_info(decs, FIELD, p.is_static, name, extra);
+
+            p.value = Some(call(
+                init,
+                vec![
+                    this(),
+                    p.value.take().unwrap_or_else(|| undefined(DUMMY_SP)),
+                ],
+            ));
+            body.push(ClassMember::PrivateProp(p));
+            return;
+        }
+
+        let storage = self.private_name(&format!("__{}", hint));
+
+        let (value, get, set) = if decs.is_empty() {
+            (
+                p.value.take(),
+                return_stmt(storage_get(&storage)),
+                storage_set(&storage).into_stmt(),
+            )
+        } else {
+            // get #foo() { return _get_foo(this); }
+            // set #foo(v) { _set_foo(this, v); }
+            let init = self.var(&format!("_init_{}", hint));
+            let get = self.var(&format!("_get_{}", hint));
+            let set = self.var(&format!("_set_{}", hint));
+            self.targets
+                .extend(vec![init.clone(), get.clone(), set.clone()]);
+
+            let extra = vec![
+                Box::new(Expr::Fn(FnExpr {
+                    ident: None,
+                    function: getter(vec![return_stmt(storage_get(&storage))]),
+                })),
+                Box::new(Expr::Fn(FnExpr {
+                    ident: None,
+                    function: setter(storage_set(&storage).into_stmt()),
+                })),
+            ];
+            self.add_dec_info(decs, ACCESSOR, p.is_static, name, extra);
+
+            (
+                Some(call(
+                    init,
+                    vec![
+                        this(),
+                        p.value.take().unwrap_or_else(|| undefined(DUMMY_SP)),
+                    ],
+                )),
+                return_stmt(call(get, vec![this()])),
+                call(set, vec![this(), Box::new(Expr::Ident(quote_ident!("v")))]).into_stmt(),
+            )
+        };
+
+        body.push(storage_prop(p.span, storage, value, p.is_static));
+        body.push(ClassMember::PrivateMethod(PrivateMethod {
+            span: DUMMY_SP,
+            key: p.key.clone(),
+            function: getter(vec![get]),
+            kind: MethodKind::Getter,
+            is_static: p.is_static,
+            accessibility: Default::default(),
+            is_abstract: false,
+            is_optional: false,
+            is_override: false,
+        }));
+        body.push(ClassMember::PrivateMethod(PrivateMethod {
+            span: DUMMY_SP,
+            key: p.key,
+            function: setter(set),
+            kind: MethodKind::Setter,
+            is_static: p.is_static,
+            accessibility: Default::default(),
+            is_abstract: false,
+            is_optional: false,
+            is_override: false,
+        }));
+    }
+
+    fn method(&mut self, mut m: ClassMethod, body: &mut Vec<ClassMember>) {
+        let decs = self.hoist_decs(take(&mut m.function.decorators));
+        let (name, _) = self.prop_name_key(&mut m.key);
+
+        self.add_dec_info(decs, method_kind(m.kind), m.is_static, name, vec![]);
+        body.push(ClassMember::Method(m));
+    }
+
+    /// Private methods cannot be redefined, so the original function is
+    /// passed to `applyDecs2203` and the method calls the decorated one.
+    fn private_method(&mut self, mut m: PrivateMethod, body: &mut Vec<ClassMember>) {
+        let decs = self.hoist_decs(take(&mut m.function.decorators));
+        let hint = m.key.id.sym.to_string();
+        let name = Box::new(Expr::Lit(Lit::Str(quote_str!(format!("#{}", hint)))));
+
+        let call_fn = self.var(&format!("_call_{}", hint));
+        self.targets.push(call_fn.clone());
+
+        let kind = method_kind(m.kind);
+        let f = Box::new(Expr::Fn(FnExpr {
+            ident: None,
+            function: replace(&mut m.function, getter(vec![])),
+        }));
+        self.add_dec_info(decs, kind, m.is_static, name, vec![f]);
+
+        let function = match m.kind {
+            // get #foo() { return _call_foo; }
+            MethodKind::Method => getter(vec![return_stmt(Box::new(Expr::Ident(call_fn)))]),
+            // get #foo() { return _call_foo(this); }
+            MethodKind::Getter => getter(vec![return_stmt(call(call_fn, vec![this()]))]),
+            // set #foo(v) { _call_foo(this, v); }
+            MethodKind::Setter => setter(
+                call(
+                    call_fn,
+                    vec![this(), Box::new(Expr::Ident(quote_ident!("v")))],
+                )
+                .into_stmt(),
+            ),
+        };
+        body.push(ClassMember::PrivateMethod(PrivateMethod {
+            function,
+            kind: match m.kind {
+                MethodKind::Setter => MethodKind::Setter,
+                _ => MethodKind::Getter,
+            },
+            ..m
+        }));
+    }
+}
+
+fn method_kind(kind: MethodKind) -> f64 {
+    match kind {
+        MethodKind::Method => METHOD,
+        MethodKind::Getter => GETTER,
+        MethodKind::Setter => SETTER,
+    }
+}
+
+/// Converts a string key to something usable as a part of an identifier.
+fn hint_of(s: &str) -> String {
+    s.chars()
+        .filter(|c| c.is_ascii_alphanumeric() || *c == '_' || *c == '$')
+        .collect()
+}
+
+fn array(elems: Vec<Box<Expr>>) -> ArrayLit {
+    ArrayLit {
+        span: DUMMY_SP,
+        elems: elems.into_iter().map(|e| Some(e.as_arg())).collect(),
+    }
+}
+
+fn this() -> Box<Expr> {
+    Box::new(Expr::This(ThisExpr { span: DUMMY_SP }))
+}
+
+fn call(callee: Ident, args: Vec<Box<Expr>>) -> Box<Expr> {
+    Box::new(Expr::Call(CallExpr {
+        span: DUMMY_SP,
+        callee: callee.as_callee(),
+        args: args.into_iter().map(|e| e.as_arg()).collect(),
+        type_args: Default::default(),
+    }))
+}
+
+fn var_decl(vars: Vec<Ident>) -> Stmt {
+    Stmt::Decl(Decl::Var(VarDecl {
+        span: DUMMY_SP,
+        kind: VarDeclKind::Var,
+        declare: false,
+        decls: vars
+            .into_iter()
+            .map(|i| VarDeclarator {
+                span: DUMMY_SP,
+                name: Pat::Ident(i.into()),
+                init: None,
+                definite: false,
+            })
+            .collect(),
+    }))
+}
+
+fn return_stmt(arg: Box<Expr>) -> Stmt {
+    Stmt::Return(ReturnStmt {
+        span: DUMMY_SP,
+        arg: Some(arg),
+    })
+}
+
+/// `this.#foo`
+fn storage_get(name: &PrivateName) -> Box<Expr> {
+    Box::new(Expr::Member(MemberExpr {
+        span: DUMMY_SP,
+        obj: ThisExpr { span: DUMMY_SP }.as_obj(),
+        prop: Box::new(Expr::PrivateName(name.clone())),
+        computed: false,
+    }))
+}
+
+/// `this.#foo = v`
+fn storage_set(name: &PrivateName) -> Box<Expr> {
+    Box::new(Expr::Assign(AssignExpr {
+        span: DUMMY_SP,
+        op: op!("="),
+        left: PatOrExpr::Expr(storage_get(name)),
+        right: Box::new(Expr::Ident(quote_ident!("v"))),
+    }))
+}
+
+fn storage_prop(
+    span: Span,
+    key: PrivateName,
+    value: Option<Box<Expr>>,
+    is_static: bool,
+) -> ClassMember {
+    ClassMember::PrivateProp(PrivateProp {
+        span,
+        key,
+        value,
+        type_ann: Default::default(),
+        is_static,
+        decorators: Default::default(),
+        computed: false,
+        accessibility: Default::default(),
+        is_abstract: false,
+        is_optional: false,
+        is_override: false,
+        readonly: false,
+        definite: false,
+        is_accessor: false,
+    })
+}
+
+fn function(params: Vec<Pat>, stmts: Vec<Stmt>) -> Function {
+    Function {
+        params: params
+            .into_iter()
+            .map(|pat| Param {
+                span: DUMMY_SP,
+                decorators: Default::default(),
+                pat,
+            })
+            .collect(),
+        decorators: Default::default(),
+        span: DUMMY_SP,
+        body: Some(BlockStmt {
+            span: DUMMY_SP,
+            stmts,
+        }),
+        is_generator: false,
+        is_async: false,
+        type_params: Default::default(),
+        return_type: Default::default(),
+    }
+}
+
+fn getter(stmts: Vec<Stmt>) -> Function {
+    function(vec![], stmts)
+}
+
+/// A setter with a parameter named `v`.
+fn setter(stmt: Stmt) -> Function {
+    function(vec![Pat::Ident(quote_ident!("v").into())], vec![stmt])
+}
@@ -1,5 +1,6 @@
 use self::metadata::{Metadata, ParamMetadata};
 use super::contains_decorator;
+use super::report_accessors;
 use super::DecoratorFinder;
 use fxhash::FxHashMap;
 use smallvec::SmallVec;
@@ -272,6 +273,8 @@ impl Legacy {
 
 impl Legacy {
     fn handle(&mut self, mut c: ClassExpr) -> Box<Expr> {
+        report_accessors(&c.class);
+
         if self.metadata {
             let i = c.ident.clone();
 
@@ -0,0 +1,280 @@
+use std::mem::replace;
+use swc_common::DUMMY_SP;
+use swc_ecma_ast::*;
+use swc_ecma_utils::{private_ident, ExprFactory, StmtLike};
+use swc_ecma_visit::{noop_fold_type, noop_visit_mut_type, Fold, FoldWith, VisitMut, VisitMutWith};
+
+/// `@babel/plugin-proposal-pipeline-operator`
+///
+/// # Example
+///
+/// ## In
+///
+/// ```js
+/// a |> f;
+/// a |> f(%, 1);
+/// ```
+///
+/// ## Out
+///
+/// ```js
+/// var _ref;
+/// _ref = a, f(_ref);
+/// var _ref1;
+/// _ref1 = a, f(_ref1, 1);
+/// ```
+///
+/// The right hand side is called with the left hand side if it does not
+/// contain a topic reference, which is the case for the minimal proposal.
+///
+/// Temporary variables are declared in the function containing the pipeline,
+/// and an arrow function with an expression body is converted to have a
+/// block body. Pipelines in parameters and class properties are lowered to a
+/// call of an arrow function, as there's no statement to declare variables.
+pub fn pipeline_operator() -> impl Fold {
+    PipelineOperator::default()
+}
+
+#[derive(Debug, Default)]
+struct PipelineOperator {
+    vars: Vec<VarDeclarator>,
+    /// `true` while folding parameters or values of class properties.
+    in_init: bool,
+}
+
+impl PipelineOperator {
+    fn in_init() -> Self {
+        PipelineOperator {
+            in_init: true,
+            ..Default::default()
+        }
+    }
+
+    fn take_var_decl(&mut self) -> Option<Stmt> {
+        if self.vars.is_empty() {
+            return None;
+        }
+
+        Some(Stmt::Decl(Decl::Var(VarDecl {
+            span: DUMMY_SP,
+            kind: VarDeclKind::Var,
+            decls: replace(&mut self.vars, Default::default()),
+            declare: false,
+        })))
+    }
+
+    fn fold_stmt_like<T>(&mut self, stmts: Vec<T>) -> Vec<T>
+    where
+        T: FoldWith<Self> + StmtLike,
+    {
+        let mut buf = Vec::with_capacity(stmts.len() + 2);
+
+        for stmt in stmts {
+            let stmt = stmt.fold_with(self);
+
+            if let Some(decl) = self.take_var_decl() {
+                buf.push(T::from_stmt(decl));
+            }
+
+            buf.push(stmt);
+        }
+
+        buf
+    }
+}
+
+impl Fold for PipelineOperator {
+    noop_fold_type!();
+
+    fn fold_function(&mut self, f: Function) -> Function {
+        Function {
+            params: f.params.fold_with(&mut PipelineOperator::in_init()),
+            decorators: f.decorators.fold_with(self),
+            body: f.body.fold_with(&mut PipelineOperator::default()),
+            ..f
+        }
+    }
+
+    fn fold_constructor(&mut self, c: Constructor) -> Constructor {
+        Constructor {
+            key: c.key.fold_with(self),
+            params: c.params.fold_with(&mut PipelineOperator::in_init()),
+            body: c.body.fold_with(&mut PipelineOperator::default()),
+            ..c
+        }
+    }
+
+    fn fold_setter_prop(&mut self, p: SetterProp) -> SetterProp {
+        SetterProp {
+            key: p.key.fold_with(self),
+            param: p.param.fold_with(&mut PipelineOperator::in_init()),
+            body: p.body.fold_with(&mut PipelineOperator::default()),
+            ..p
+        }
+    }
+
+    fn fold_arrow_expr(&mut self, f: ArrowExpr) -> ArrowExpr {
+        let params = f.params.fold_with(&mut PipelineOperator::in_init());
+
+        let mut folder = PipelineOperator::default();
+        let body = match f.body.fold_with(&mut folder) {
+            BlockStmtOrExpr::Expr(expr) => match folder.take_var_decl() {
+                Some(decl) => BlockStmtOrExpr::BlockStmt(BlockStmt {
+                    span: DUMMY_SP,
+                    stmts: vec![
+                        decl,
+                        Stmt::Return(ReturnStmt {
+                            span: DUMMY_SP,
+                            arg: Some(expr),
+                        }),
+                    ],
+                }),
+                None => BlockStmtOrExpr::Expr(expr),
+            },
+            body => body,
+        };
+
+        ArrowExpr { params, body, ..f }
+    }
+
+    fn fold_class_prop(&mut self, mut p: ClassProp) -> ClassProp {
+        let value = p.value.take().fold_with(&mut PipelineOperator::in_init());
+
+        ClassProp {
+            value,
+            ..p.fold_children_with(self)
+        }
+    }
+
+    fn fold_private_prop(&mut self, mut p: PrivateProp) -> PrivateProp {
+        let value = p.value.take().fold_with(&mut PipelineOperator::in_init());
+
+        PrivateProp {
+            value,
+            ..p.fold_children_with(self)
+        }
+    }
+
+    fn fold_block_stmt(&mut self, s: BlockStmt) -> BlockStmt {
+        s.fold_children_with(&mut PipelineOperator::default())
+    }
+
+    fn fold_switch_case(&mut self, s: SwitchCase) -> SwitchCase {
+        s.fold_children_with(&mut PipelineOperator::default())
+    }
+
+    fn fold_module_items(&mut self, n: Vec<ModuleItem>) -> Vec<ModuleItem> {
+        self.fold_stmt_like(n)
+    }
+
+    fn fold_stmts(&mut self, n: Vec<Stmt>) -> Vec<Stmt> {
+        self.fold_stmt_like(n)
+    }
+
+    fn fold_expr(&mut self, e: Expr) -> Expr {
+        match e {
+            Expr::Bin(BinExpr {
+                span,
+                op: op!("|>"),
+                left,
+                mut right,
+            }) => {
+                let left = left.fold_with(self);
+
+                let topic = private_ident!("_ref");
+                if !self.in_init {
+                    self.vars.push(VarDeclarator {
+                        span: DUMMY_SP,
+                        name: Pat::Ident(topic.clone().into()),
+                        init: None,
+                        definite: false,
+                    });
+                }
+
+                let mut replacer = TopicReplacer {
+                    topic: &topic,
+                    found: false,
+                };
+                right.visit_mut_with(&mut replacer);
+                let found = replacer.found;
+                // Nested pipelines are lowered after replacing the topic references.
+                let right = right.fold_with(self);
+
+                let right = if found {
+                    right
+                } else {
+                    // a |> f
+                    Box::new(Expr::Call(CallExpr {
+                        span: DUMMY_SP,
+                        callee: right.as_callee(),
+                        args: vec![topic.clone().as_arg()],
+                        type_args: Default::default(),
+                    }))
+                };
+
+                if self.in_init {
+                    // (_ref => f(_ref))(a)
+                    return Expr::Call(CallExpr {
+                        span,
+                        callee: ArrowExpr {
+                            span: DUMMY_SP,
+                            params: vec![Pat::Ident(topic.into())],
+                            body: BlockStmtOrExpr::Expr(right),
+                            is_async: false,
+                            is_generator: false,
+                            type_params: Default::default(),
+                            return_type: Default::default(),
+                        }
+                        .as_callee(),
+                        args: vec![ExprOrSpread {
+                            spread: None,
+                            expr: left,
+                        }],
+                        type_args: Default::default(),
+                    });
+                }
+
+                Expr::Seq(SeqExpr {
+                    span,
+                    exprs: vec![
+                        Box::new(Expr::Assign(AssignExpr {
+                            span: DUMMY_SP,
+                            op: op!("="),
+                            left: PatOrExpr::Pat(Box::new(Pat::Ident(topic.into()))),
+                            right: left,
+                        })),
+                        right,
+                    ],
+                })
+            }
+            _ => e.fold_children_with(self),
+        }
+    }
+}
+
+/// Replaces topic references which belong to the current pipeline.
+struct TopicReplacer<'a> {
+    topic: &'a Ident,
+    found: bool,
+}
+
+impl VisitMut for TopicReplacer<'_> {
+    noop_visit_mut_type!();
+
+    fn visit_mut_expr(&mut self, e: &mut Expr) {
+        match e {
+            Expr::TopicRef(..) => {
+                self.found = true;
+                *e = Expr::Ident(self.topic.clone());
+            }
+            // Topic references in the body of a nested pipeline refer to its
+            // left hand side.
+            Expr::Bin(BinExpr {
+                op: op!("|>"),
+                left,
+                ..
+            }) => left.visit_mut_with(self),
+            _ => e.visit_mut_children_with(self),
+        }
+    }
+}
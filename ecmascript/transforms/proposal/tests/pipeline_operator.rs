@@ -0,0 +1,120 @@
+use swc_ecma_parser::{EsConfig, PipelineOperator, Syntax, TopicToken};
+use swc_ecma_transforms_proposal::pipeline_operator;
+use swc_ecma_transforms_testing::test;
+
+fn syntax_minimal() -> Syntax {
+    Syntax::Es(EsConfig {
+        pipeline_operator: Some(PipelineOperator::Minimal),
+        ..Default::default()
+    })
+}
+
+fn syntax_hack() -> Syntax {
+    Syntax::Es(EsConfig {
+        pipeline_operator: Some(PipelineOperator::Hack {
+            topic_token: TopicToken::Percent,
+        }),
+        class_props: true,
+        ..Default::default()
+    })
+}
+
+test!(
+    syntax_minimal(),
+    |_| pipeline_operator(),
+    minimal_call,
+    "a |> f;",
+    "var _ref;
+    _ref = a, f(_ref);"
+);
+
+test!(
+    syntax_minimal(),
+    |_| pipeline_operator(),
+    minimal_chain,
+    "a |> f |> g;",
+    "var _ref, _ref1;
+    _ref1 = (_ref = a, f(_ref)), g(_ref1);"
+);
+
+test!(
+    syntax_hack(),
+    |_| pipeline_operator(),
+    hack_topic,
+    "a |> f(%, 1);",
+    "var _ref;
+    _ref = a, f(_ref, 1);"
+);
+
+test!(
+    syntax_hack(),
+    |_| pipeline_operator(),
+    hack_nested,
+    "a |> (% |> g(%, %));",
+    "var _ref, _ref1;
+    _ref = a, (_ref1 = _ref, g(_ref1, _ref1));"
+);
+
+test!(
+    syntax_hack(),
+    |_| pipeline_operator(),
+    hack_chain,
+    "a |> f(%) |> g(%);",
+    "var _ref, _ref1;
+    _ref = a, (_ref1 = f(_ref), g(_ref1));"
+);
+
+test!(
+    syntax_hack(),
+    |_| pipeline_operator(),
+    hack_in_function,
+    "function foo() {
+        return a |> % + 1;
+    }",
+    "function foo() {
+        var _ref;
+        return _ref = a, _ref + 1;
+    }"
+);
+
+test!(
+    syntax_hack(),
+    |_| pipeline_operator(),
+    hack_in_arrow,
+    "const foo = x => x |> f(%);",
+    "const foo = (x)=>{
+        var _ref;
+        return _ref = x, f(_ref);
+    };"
+);
+
+test!(
+    syntax_hack(),
+    |_| pipeline_operator(),
+    hack_in_async_arrow,
+    "const foo = async x => (await x) |> f(%, await y);",
+    "const foo = async (x)=>{
+        var _ref;
+        return _ref = await x, f(_ref, await y);
+    };"
+);
+
+test!(
+    syntax_hack(),
+    |_| pipeline_operator(),
+    hack_in_param,
+    "function foo(a = b |> f(%)) {}",
+    "function foo(a = ((_ref)=>f(_ref))(b)) {}"
+);
+
+test!(
+    syntax_hack(),
+    |_| pipeline_operator(),
+    hack_in_class_prop,
+    "class Foo {
+        a = b |> f(%);
+    }",
+    "class Foo {
+        a = ((_ref)=>f(_ref))(b);
+    }"
+);
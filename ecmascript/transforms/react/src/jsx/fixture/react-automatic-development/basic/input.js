@@ -0,0 +1,5 @@
+var x = <div key="a" className="foo">
+    <span />
+    <span />
+</div>;
+var y = <Foo bar={1} />;
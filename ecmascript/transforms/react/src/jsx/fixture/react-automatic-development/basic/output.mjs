@@ -0,0 +1,27 @@
+import { jsxDEV as _jsxDEV } from "react/jsx-dev-runtime";
+var x = /*#__PURE__*/_jsxDEV("div", {
+    className: "foo",
+    children: [
+        /*#__PURE__*/_jsxDEV("span", {}, void 0, false, {
+            fileName: "input.js",
+            lineNumber: 2,
+            columnNumber: 5
+        }, this),
+        /*#__PURE__*/_jsxDEV("span", {}, void 0, false, {
+            fileName: "input.js",
+            lineNumber: 3,
+            columnNumber: 5
+        }, this)
+    ]
+}, "a", true, {
+    fileName: "input.js",
+    lineNumber: 1,
+    columnNumber: 9
+}, this);
+var y = /*#__PURE__*/_jsxDEV(Foo, {
+    bar: 1
+}, void 0, false, {
+    fileName: "input.js",
+    lineNumber: 5,
+    columnNumber: 9
+}, this);
@@ -0,0 +1,2 @@
+/** @jsxRuntime classic */
+var x = <div><span /></div>;
@@ -0,0 +1,14 @@
+/** @jsxRuntime classic */
+var x = /*#__PURE__*/React.createElement("div", {
+    __source: {
+        fileName: "input.js",
+        lineNumber: 2
+    },
+    __self: this
+}, /*#__PURE__*/React.createElement("span", {
+    __source: {
+        fileName: "input.js",
+        lineNumber: 2
+    },
+    __self: this
+}));
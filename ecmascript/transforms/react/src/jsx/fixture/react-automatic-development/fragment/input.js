@@ -0,0 +1,3 @@
+var x = <>
+    <div />
+</>;
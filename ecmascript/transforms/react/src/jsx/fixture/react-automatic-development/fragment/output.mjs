@@ -0,0 +1,14 @@
+import { jsxDEV as _jsxDEV, Fragment as _Fragment } from "react/jsx-dev-runtime";
+var x = /*#__PURE__*/_jsxDEV(_Fragment, {
+    children: [
+        /*#__PURE__*/_jsxDEV("div", {}, void 0, false, {
+            fileName: "input.js",
+            lineNumber: 2,
+            columnNumber: 5
+        }, this)
+    ]
+}, void 0, false, {
+    fileName: "input.js",
+    lineNumber: 1,
+    columnNumber: 9
+}, this);
@@ -0,0 +1 @@
+var x = <div {...props} key="1" foo="bar" />;
@@ -0,0 +1,12 @@
+import { createElement as _createElement } from "react";
+var x = /*#__PURE__*/_createElement("div", {
+    ...props,
+    key: "1",
+    foo: "bar",
+    __source: {
+        fileName: "input.js",
+        lineNumber: 1,
+        columnNumber: 9
+    },
+    __self: this
+});
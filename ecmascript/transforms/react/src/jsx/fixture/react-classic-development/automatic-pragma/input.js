@@ -0,0 +1,2 @@
+/** @jsxRuntime automatic */
+var x = <Foo bar={1} />;
@@ -0,0 +1,9 @@
+import { jsxDEV as _jsxDEV } from "react/jsx-dev-runtime";
+/** @jsxRuntime automatic */
+var x = /*#__PURE__*/_jsxDEV(Foo, {
+    bar: 1
+}, void 0, false, {
+    fileName: "input.js",
+    lineNumber: 2,
+    columnNumber: 9
+}, this);
@@ -0,0 +1 @@
+var x = <Foo bar={1} />;
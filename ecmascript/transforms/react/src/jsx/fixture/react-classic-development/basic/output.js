@@ -0,0 +1,8 @@
+var x = /*#__PURE__*/React.createElement(Foo, {
+    bar: 1,
+    __source: {
+        fileName: "input.js",
+        lineNumber: 1
+    },
+    __self: this
+});
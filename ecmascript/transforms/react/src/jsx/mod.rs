@@ -11,7 +11,7 @@ use swc_common::{
     comments::{CommentKind, Comments},
     iter::IdentifyLast,
     sync::Lrc,
-    FileName, SourceMap, Spanned, DUMMY_SP,
+    FileName, SourceMap, Span, Spanned, DUMMY_SP,
 };
 use swc_ecma_ast::*;
 use swc_ecma_parser::{Parser, StringInput, Syntax};
@@ -22,6 +22,7 @@ use swc_ecma_utils::member_expr;
 use swc_ecma_utils::prepend;
 use swc_ecma_utils::private_ident;
 use swc_ecma_utils::quote_ident;
+use swc_ecma_utils::undefined;
 use swc_ecma_utils::ExprFactory;
 use swc_ecma_utils::HANDLER;
 use swc_ecma_visit::as_folder;
@@ -30,7 +31,10 @@ use swc_ecma_visit::Fold;
 use swc_ecma_visit::VisitMut;
 use swc_ecma_visit::VisitMutWith;
 
-use crate::refresh::options::{deserialize_refresh, RefreshOptions};
+use crate::{
+    jsx_self, jsx_src,
+    refresh::options::{deserialize_refresh, RefreshOptions},
+};
 
 mod static_check;
 #[cfg(test)]
@@ -76,6 +80,11 @@ pub struct Options {
     #[serde(default = "default_throw_if_namespace")]
     pub throw_if_namespace: bool,
 
+    /// Adds the source location and `this` of each element.
+    ///
+    /// For automatic runtime, this makes swc emit `jsxDEV` calls from
+    /// `jsx-dev-runtime`. For classic runtime, these are passed as `__source`
+    /// and `__self` props.
     #[serde(default)]
     pub development: bool,
 
@@ -164,8 +173,10 @@ where
         cm: cm.clone(),
         next: options.next,
         runtime: options.runtime.unwrap_or_default(),
+        development: options.development,
         import_source: options.import_source.into(),
         import_jsx: None,
+        import_jsx_dev: None,
         import_jsxs: None,
         import_fragment: None,
         import_create_element: None,
@@ -191,10 +202,13 @@ where
 
     next: bool,
     runtime: Runtime,
+    development: bool,
     /// For automatic runtime.
     import_source: JsWord,
     /// For automatic runtime.
     import_jsx: Option<Ident>,
+    /// For automatic runtime with `development`.
+    import_jsx_dev: Option<Ident>,
     /// For automatic runtime.
     import_jsxs: Option<Ident>,
     /// For automatic runtime.
@@ -215,6 +229,89 @@ impl<C> Jsx<C>
 where
     C: Comments,
 {
+    /// Returns the function to call for an element, for automatic runtime.
+    fn jsx_callee(&mut self, use_jsxs: bool) -> Ident {
+        if self.development {
+            return self
+                .import_jsx_dev
+                .get_or_insert_with(|| private_ident!("_jsxDEV"))
+                .clone();
+        }
+
+        if use_jsxs {
+            self.import_jsxs
+                .get_or_insert_with(|| private_ident!("_jsxs"))
+                .clone()
+        } else {
+            self.import_jsx
+                .get_or_insert_with(|| private_ident!("_jsx"))
+                .clone()
+        }
+    }
+
+    /// Creates arguments of `jsxDEV(type, props, key, isStatic, source,
+    /// self)`.
+    fn jsx_dev_args(
+        &self,
+        span: Span,
+        name: Box<Expr>,
+        props: ObjectLit,
+        key: Option<ExprOrSpread>,
+        is_static: bool,
+    ) -> Vec<ExprOrSpread> {
+        vec![
+            name.as_arg(),
+            props.as_arg(),
+            key.unwrap_or_else(|| undefined(DUMMY_SP).as_arg()),
+            Lit::Bool(Bool {
+                span: DUMMY_SP,
+                value: is_static,
+            })
+            .as_arg(),
+            self.source_obj(span).as_arg(),
+            ThisExpr { span: DUMMY_SP }.as_arg(),
+        ]
+    }
+
+    /// `{ fileName, lineNumber, columnNumber }` of `span`, or `undefined` if
+    /// the span is not available.
+    fn source_obj(&self, span: Span) -> Box<Expr> {
+        if span.is_dummy() {
+            return undefined(DUMMY_SP);
+        }
+
+        let loc = self.cm.lookup_char_pos(span.lo);
+
+        Box::new(Expr::Object(ObjectLit {
+            span: DUMMY_SP,
+            props: vec![
+                PropOrSpread::Prop(Box::new(Prop::KeyValue(KeyValueProp {
+                    key: PropName::Ident(quote_ident!("fileName")),
+                    value: Box::new(Expr::Lit(Lit::Str(Str {
+                        span: DUMMY_SP,
+                        value: loc.file.name.to_string().into(),
+                        has_escape: false,
+                        kind: Default::default(),
+                    }))),
+                }))),
+                PropOrSpread::Prop(Box::new(Prop::KeyValue(KeyValueProp {
+                    key: PropName::Ident(quote_ident!("lineNumber")),
+                    value: Box::new(Expr::Lit(Lit::Num(Number {
+                        span: DUMMY_SP,
+                        value: loc.line as _,
+                    }))),
+                }))),
+                PropOrSpread::Prop(Box::new(Prop::KeyValue(KeyValueProp {
+                    key: PropName::Ident(quote_ident!("columnNumber")),
+                    value: Box::new(Expr::Lit(Lit::Num(Number {
+                        span: DUMMY_SP,
+                        value: (loc.col.0 + 1) as _,
+                    }))),
+                }))),
+            ],
+        }))
+    }
+
     fn jsx_frag_to_expr(&mut self, el: JSXFragment) -> Expr {
         let span = el.span();
 
@@ -226,15 +323,7 @@ where
 
         match self.runtime {
             Runtime::Automatic => {
-                let jsx = if use_jsxs {
-                    self.import_jsxs
-                        .get_or_insert_with(|| private_ident!("_jsxs"))
-                        .clone()
-                } else {
-                    self.import_jsx
-                        .get_or_insert_with(|| private_ident!("_jsx"))
-                        .clone()
-                };
+                let jsx = self.jsx_callee(use_jsxs);
 
                 let fragment = self
                     .import_fragment
@@ -261,7 +350,9 @@ where
                                 callee: ExprOrSuper::Expr(callee),
                                 ..
                             }) => match &**callee {
-                                Expr::Ident(Ident { sym, .. }) => *sym != *"_jsx",
+                                Expr::Ident(Ident { sym, .. }) => {
+                                    *sym != *"_jsx" && *sym != *"_jsxDEV"
+                                }
                                 _ => true,
                             },
                             _ => true,
@@ -287,10 +378,22 @@ where
                     }
                 }
 
+                let args = if self.development {
+                    self.jsx_dev_args(
+                        span,
+                        Box::new(Expr::Ident(fragment)),
+                        props_obj,
+                        None,
+                        use_jsxs,
+                    )
+                } else {
+                    vec![fragment.as_arg(), props_obj.as_arg()]
+                };
+
                 Expr::Call(CallExpr {
                     span,
                     callee: jsx.as_callee(),
-                    args: vec![fragment.as_arg(), props_obj.as_arg()],
+                    args,
                     type_args: None,
                 })
             }
@@ -321,7 +424,13 @@ where
     /// # Classic
     ///
     /// <div></div> => React.createElement('div', null);
-    fn jsx_elem_to_expr(&mut self, el: JSXElement) -> Expr {
+    fn jsx_elem_to_expr(&mut self, mut el: JSXElement) -> Expr {
+        // The runtime is known only after reading the pragma of the file.
+        if self.development && self.runtime == Runtime::Classic {
+            el.opening = jsx_src(true, self.cm.clone()).fold_jsx_opening_element(el.opening);
+            el.opening = jsx_self(true).fold_jsx_opening_element(el.opening);
+        }
+
         let top_level_node = self.top_level_node;
         let span = el.span();
         let use_create_element = should_use_create_element(&el.opening.attrs);
@@ -343,14 +452,8 @@ where
                     self.import_create_element
                         .get_or_insert_with(|| private_ident!("_createElement"))
                         .clone()
-                } else if use_jsxs {
-                    self.import_jsxs
-                        .get_or_insert_with(|| private_ident!("_jsxs"))
-                        .clone()
                 } else {
-                    self.import_jsx
-                        .get_or_insert_with(|| private_ident!("_jsx"))
-                        .clone()
+                    self.jsx_callee(use_jsxs)
                 };
 
                 let mut props_obj = ObjectLit {
@@ -487,13 +590,35 @@ where
 
                 self.top_level_node = top_level_node;
 
+                let args = if self.development && !use_create_element {
+                    self.jsx_dev_args(span, name, props_obj, key, use_jsxs)
+                } else {
+                    if self.development {
+                        // `createElement` does not accept the source location as an argument.
+                        props_obj
+                            .props
+                            .push(PropOrSpread::Prop(Box::new(Prop::KeyValue(KeyValueProp {
+                                key: PropName::Ident(quote_ident!("__source")),
+                                value: self.source_obj(span),
+                            }))));
+                        props_obj
+                            .props
+                            .push(PropOrSpread::Prop(Box::new(Prop::KeyValue(KeyValueProp {
+                                key: PropName::Ident(quote_ident!("__self")),
+                                value: Box::new(Expr::This(ThisExpr { span: DUMMY_SP })),
+                            }))));
+                    }
+
+                    once(name.as_arg())
+                        .chain(once(props_obj.as_arg()))
+                        .chain(key)
+                        .collect()
+                };
+
                 Expr::Call(CallExpr {
                     span,
                     callee: jsx.as_callee(),
-                    args: once(name.as_arg())
-                        .chain(once(props_obj.as_arg()))
-                        .chain(key)
-                        .collect(),
+                    args,
                     type_args: Default::default(),
                 })
             }
@@ -848,6 +973,15 @@ where
                     imported: Some(quote_ident!("jsx")),
                 })
                 .into_iter()
+                .chain(
+                    self.import_jsx_dev
+                        .take()
+                        .map(|local| ImportNamedSpecifier {
+                            span: DUMMY_SP,
+                            local,
+                            imported: Some(quote_ident!("jsxDEV")),
+                        }),
+                )
                 .chain(self.import_jsxs.take().map(|local| ImportNamedSpecifier {
                     span: DUMMY_SP,
                     local,
@@ -873,7 +1007,11 @@ where
                         specifiers: imports,
                         src: Str {
                             span: DUMMY_SP,
-                            value: format!("{}/jsx-runtime", self.import_source).into(),
+                            value: if self.development {
+                                format!("{}/jsx-dev-runtime", self.import_source).into()
+                            } else {
+                                format!("{}/jsx-runtime", self.import_source).into()
+                            },
                             has_escape: false,
                             kind: Default::default(),
                         },
@@ -31,8 +31,6 @@ where
     let refresh_options = mem::replace(&mut options.refresh, None);
 
     chain!(
-        jsx_src(development, cm.clone()),
-        jsx_self(development),
         refresh(development, refresh_options, cm.clone(), comments.clone()),
         jsx(cm.clone(), comments.clone(), options),
         display_name(),
@@ -5736,3 +5736,184 @@ test!(
     }) || _class) || _class) || _class) || _class;
     "#
 );
+
+fn syntax_2022_03() -> Syntax {
+    Syntax::Es(EsConfig {
+        decorators: true,
+        class_props: true,
+        class_private_props: true,
+        class_private_methods: true,
+        auto_accessors: true,
+        ..Default::default()
+    })
+}
+
+fn decorators_2022_03() -> impl Fold {
+    decorators(Config {
+        decorator_version: decorators::DecoratorVersion::V202203,
+        ..Default::default()
+    })
+}
+
+test!(
+    syntax_2022_03(),
+    |_| decorators_2022_03(),
+    decorators_2022_03_field_and_method,
+    r#"
+class Foo {
+  @dec
+  foo = 1;
+
+  @dec
+  bar() {}
+}
+"#,
+    r#"
+var _init_foo, _initProto;
+class Foo {
+  static #__decs = [_init_foo, _initProto] = _applyDecs2203(this, [[[dec], 0, "foo"], [[dec], 2, "bar"]], []);
+  foo = (_initProto(this), _init_foo(this, 1));
+  bar() {}
+}
+"#
+);
+
+test!(
+    syntax_2022_03(),
+    |_| decorators_2022_03(),
+    decorators_2022_03_accessor,
+    r#"
+class Foo {
+  accessor foo = 1;
+
+  @dec
+  accessor bar;
+}
+"#,
+    r#"
+var _init_bar, _initProto;
+class Foo {
+  static #__decs = [_init_bar, _initProto] = _applyDecs2203(this, [[[dec], 1, "bar"]], []);
+  #__foo = (_initProto(this), 1);
+  get foo() {
+    return this.#__foo;
+  }
+  set foo(v) {
+    this.#__foo = v;
+  }
+  #__bar = _init_bar(this, void 0);
+  get bar() {
+    return this.#__bar;
+  }
+  set bar(v) {
+    this.#__bar = v;
+  }
+}
+"#
+);
+
+test!(
+    syntax_2022_03(),
+    |_| decorators_2022_03(),
+    decorators_2022_03_class,
+    r#"
+@dec
+class Foo {}
+"#,
+    r#"
+var _Foo, _initClass;
+class Foo {
+  static #__decs = [_Foo, _initClass] = _applyDecs2203(this, [], [dec]);
+}
+Foo = _Foo;
+_initClass();
+"#
+);
+
+test!(
+    syntax_2022_03(),
+    |_| decorators_2022_03(),
+    decorators_2022_03_class_expr_await,
+    r#"
+const foo = async () => @(await dec()) class {
+  @(await dec())
+  foo = 1;
+};
+"#,
+    r#"
+const foo = async () => {
+  var _dec, _dec1, _init_foo, __class, _initClass;
+  return _dec = await dec(), _dec1 = await dec(), class {
+    static #__decs = [_init_foo, __class, _initClass] = _applyDecs2203(this, [[[_dec1], 0, "foo"]], [_dec]);
+    foo = _init_foo(this, 1);
+  }, _initClass(), __class;
+};
+"#
+);
+
+test!(
+    syntax_2022_03(),
+    |_| decorators_2022_03(),
+    decorators_2022_03_class_expr_yield,
+    r#"
+function* foo() {
+  return @(yield dec) class {};
+}
+"#,
+    r#"
+function* foo() {
+  var _dec, __class, _initClass;
+  return _dec = yield dec, class {
+    static #__decs = [__class, _initClass] = _applyDecs2203(this, [], [_dec]);
+  }, _initClass(), __class;
+}
+"#
+);
+
+test_exec!(
+    syntax_2022_03(),
+    |_| decorators_2022_03(),
+    decorators_2022_03_accessor_exec,
+    r#"
+const log = [];
+
+function double(value, ctx) {
+  log.push(ctx.kind, ctx.name);
+  return {
+    init(v) {
+      return v * 2;
+    },
+  };
+}
+
+function logged(fn, ctx) {
+  ctx.addInitializer(function () {
+    log.push("init " + ctx.name);
+  });
+  return function (...args) {
+    log.push("call " + ctx.name);
+    return fn.apply(this, args);
+  };
+}
+
+class Foo {
+  @double
+  accessor foo = 1;
+
+  @logged
+  #bar() {
+    return this.foo;
+  }
+
+  bar() {
+    return this.#bar();
+  }
+}
+
+const foo = new Foo();
+expect(foo.foo).toBe(2);
+foo.foo = 3;
+expect(foo.bar()).toBe(3);
+expect(log).toEqual(["accessor", "foo", "init #bar", "call #bar"]);
+"#
+);
@@ -237,6 +237,7 @@ impl Strip {
                                         readonly: param_prop.readonly,
                                         declare: false,
                                         definite: false,
+                                        is_accessor: false,
                                     });
                                     param_class_fields.push(param_class_field);
                                 }
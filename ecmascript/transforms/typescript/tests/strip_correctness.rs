@@ -156,6 +156,7 @@ fn correctness_tests(tests: &mut Vec<TestDescAndFn>) -> Result<(), io::Error> {
                             dts: false,
                             no_early_errors: false,
                             import_assertions: true,
+                            auto_accessors: false,
                         }),
                         (&*src).into(),
                         None,
@@ -29,6 +29,7 @@ mod macros;
 pub mod constructor;
 mod factory;
 pub mod ident;
+pub mod scope;
 mod value;
 pub mod var;
 
@@ -1063,6 +1064,7 @@ pub trait ExprExt {
             | Expr::Ident(..)
             | Expr::This(..)
             | Expr::PrivateName(..)
+            | Expr::TopicRef(..)
             | Expr::TsConstAssertion(..) => false,
 
             Expr::Paren(ref e) => e.expr.may_have_side_effects(),
@@ -1077,6 +1079,8 @@ pub trait ExprExt {
                 .filter_map(|e| e.as_ref())
                 .any(|e| e.expr.may_have_side_effects()),
             Expr::Unary(UnaryExpr { ref arg, .. }) => arg.may_have_side_effects(),
+            // `x |> f` calls `f`.
+            Expr::Bin(BinExpr { op: op!("|>"), .. }) => true,
             Expr::Bin(BinExpr {
                 ref left,
                 ref right,
@@ -1870,7 +1874,8 @@ pub fn extract_side_effects_to(to: &mut Vec<Box<Expr>>, expr: Box<Expr>) {
         | Expr::Fn(..)
         | Expr::Arrow(..)
         | Expr::Ident(..)
-        | Expr::PrivateName(..) => {}
+        | Expr::PrivateName(..)
+        | Expr::TopicRef(..) => {}
 
         // In most case, we can do nothing for this.
         Expr::Update(_) | Expr::Assign(_) | Expr::Yield(_) | Expr::Await(_) => {
@@ -1901,6 +1906,7 @@ pub fn extract_side_effects_to(to: &mut Vec<Box<Expr>>, expr: Box<Expr>) {
         Expr::Cond(_) => to.push(Box::new(expr)),
 
         Expr::Unary(UnaryExpr { arg, .. }) => extract_side_effects_to(to, arg),
+        Expr::Bin(BinExpr { op: op!("|>"), .. }) => to.push(Box::new(expr)),
         Expr::Bin(BinExpr { left, right, .. }) => {
             extract_side_effects_to(to, left);
             extract_side_effects_to(to, right);
@@ -0,0 +1,686 @@
+//! Scope analysis.
+//!
+//! [analyze_scopes] relies on the syntax contexts applied by the resolver, so
+//! the resolver of `swc_ecma_transforms_base` should be applied before calling
+//! it. Two identifiers refer to the same binding if their [Id]s are equal.
+
+use crate::{find_ids, ident::IdentLike, Id};
+use std::collections::HashMap;
+use swc_common::{Span, DUMMY_SP};
+use swc_ecma_ast::*;
+use swc_ecma_visit::{noop_visit_type, Node, Visit, VisitWith};
+
+/// Analyzes scopes, bindings and references of `n`.
+///
+/// # Example
+///
+/// ```ignore
+/// let program = program.fold_with(&mut resolver_with_mark(top_level_mark));
+/// let analysis = analyze_scopes(&program);
+///
+/// for binding in analysis.bindings() {
+///     if !binding.is_reassigned() && !binding.is_captured() {
+///         // Safe to inline
+///     }
+/// }
+/// ```
+pub fn analyze_scopes<N>(n: &N) -> ScopeAnalysis
+where
+    N: VisitWith<ScopeAnalyzer>,
+{
+    let mut v = ScopeAnalyzer {
+        scopes: vec![Scope {
+            kind: ScopeKind::TopLevel,
+            span: DUMMY_SP,
+            parent: None,
+            children: vec![],
+            bindings: vec![],
+            fn_scope: ScopeId(0),
+        }],
+        cur: ScopeId(0),
+        bindings: Default::default(),
+        references: vec![],
+        in_export_decl: false,
+    };
+    n.visit_with(&Invalid { span: DUMMY_SP }, &mut v);
+
+    let mut analysis = ScopeAnalysis {
+        scopes: v.scopes,
+        bindings: v.bindings,
+        unresolved: vec![],
+    };
+
+    for (id, mut reference) in v.references {
+        match analysis.bindings.get_mut(&id) {
+            Some(binding) => {
+                let decl_fn = analysis.scopes[binding.scope.0].fn_scope;
+                let ref_fn = analysis.scopes[reference.scope.0].fn_scope;
+                reference.is_capture = decl_fn != ref_fn;
+                binding.references.push(reference);
+            }
+            None => analysis.unresolved.push((id, reference)),
+        }
+    }
+
+    analysis
+}
+
+/// Result of [analyze_scopes].
+#[derive(Debug)]
+pub struct ScopeAnalysis {
+    scopes: Vec<Scope>,
+    bindings: HashMap<Id, Binding>,
+    unresolved: Vec<(Id, Reference)>,
+}
+
+impl ScopeAnalysis {
+    /// The scope of the program itself.
+    pub fn top_level(&self) -> ScopeId {
+        ScopeId(0)
+    }
+
+    pub fn scope(&self, id: ScopeId) -> &Scope {
+        &self.scopes[id.0]
+    }
+
+    /// All scopes, in source order.
+    pub fn scopes(&self) -> impl '_ + Iterator<Item = (ScopeId, &Scope)> {
+        self.scopes
+            .iter()
+            .enumerate()
+            .map(|(idx, scope)| (ScopeId(idx), scope))
+    }
+
+    pub fn binding(&self, id: &Id) -> Option<&Binding> {
+        self.bindings.get(id)
+    }
+
+    /// All bindings, in arbitrary order.
+    pub fn bindings(&self) -> impl '_ + Iterator<Item = &Binding> {
+        self.bindings.values()
+    }
+
+    /// References to identifiers which are not declared in the program, like
+    /// globals.
+    pub fn unresolved(&self) -> &[(Id, Reference)] {
+        &self.unresolved
+    }
+
+    /// Returns true if `scope` is `ancestor` or a descendant of it.
+    pub fn is_within(&self, scope: ScopeId, ancestor: ScopeId) -> bool {
+        let mut cur = Some(scope);
+        while let Some(id) = cur {
+            if id == ancestor {
+                return true;
+            }
+            cur = self.scopes[id.0].parent;
+        }
+
+        false
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct ScopeId(usize);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScopeKind {
+    TopLevel,
+    /// Functions, arrow functions, methods and constructors.
+    Function,
+    /// Block statements, loops, switch statements and named class
+    /// expressions.
+    Block,
+    Catch,
+}
+
+#[derive(Debug)]
+pub struct Scope {
+    pub kind: ScopeKind,
+    pub span: Span,
+    pub parent: Option<ScopeId>,
+    pub children: Vec<ScopeId>,
+    /// Bindings declared in this scope, in source order.
+    pub bindings: Vec<Id>,
+    /// The closest function or top-level scope, which may be the scope itself.
+    pub fn_scope: ScopeId,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BindingKind {
+    Var,
+    Let,
+    Const,
+    Function,
+    Class,
+    Import,
+    Param,
+    CatchParam,
+    /// Name of a function expression or a class expression.
+    ExprName,
+}
+
+impl BindingKind {
+    /// Returns true if the binding cannot be redeclared.
+    pub fn is_lexical(self) -> bool {
+        match self {
+            BindingKind::Let | BindingKind::Const | BindingKind::Class | BindingKind::Import => {
+                true
+            }
+            _ => false,
+        }
+    }
+}
+
+impl From<VarDeclKind> for BindingKind {
+    fn from(kind: VarDeclKind) -> Self {
+        match kind {
+            VarDeclKind::Var => BindingKind::Var,
+            VarDeclKind::Let => BindingKind::Let,
+            VarDeclKind::Const => BindingKind::Const,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Binding {
+    pub id: Id,
+    /// Kind of the first declaration.
+    pub kind: BindingKind,
+    /// `var`s and parameters are declared in the closest function scope.
+    pub scope: ScopeId,
+    /// Declarations in source order. A `var` can be declared multiple times.
+    pub decls: Vec<Declaration>,
+    /// True if declared like `export const a = 1`.
+    pub exported: bool,
+    /// References in source order. Declarations are not references, even if
+    /// they have an initializer.
+    pub references: Vec<Reference>,
+}
+
+impl Binding {
+    /// Returns true if the binding is referenced from a function other than
+    /// the one declaring it.
+    pub fn is_captured(&self) -> bool {
+        self.references.iter().any(|r| r.is_capture)
+    }
+
+    /// Returns true if the binding is assigned to after its declaration.
+    pub fn is_reassigned(&self) -> bool {
+        self.references.iter().any(|r| r.kind.is_write())
+    }
+
+    pub fn is_read(&self) -> bool {
+        self.references.iter().any(|r| r.kind.is_read())
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Declaration {
+    pub span: Span,
+    pub kind: BindingKind,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReferenceKind {
+    /// `a`
+    Read,
+    /// `a = 1`, `[a] = b`, `for (a in b)`
+    Write,
+    /// `a += 1`, `a++`
+    ReadWrite,
+}
+
+impl ReferenceKind {
+    pub fn is_read(self) -> bool {
+        match self {
+            ReferenceKind::Read | ReferenceKind::ReadWrite => true,
+            ReferenceKind::Write => false,
+        }
+    }
+
+    pub fn is_write(self) -> bool {
+        match self {
+            ReferenceKind::Write | ReferenceKind::ReadWrite => true,
+            ReferenceKind::Read => false,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Reference {
+    pub span: Span,
+    pub kind: ReferenceKind,
+    /// The innermost scope containing the reference.
+    pub scope: ScopeId,
+    /// True if the reference is in a function nested in the function which
+    /// declares the binding. Always false for unresolved references.
+    pub is_capture: bool,
+}
+
+/// Used by [analyze_scopes].
+pub struct ScopeAnalyzer {
+    scopes: Vec<Scope>,
+    cur: ScopeId,
+    bindings: HashMap<Id, Binding>,
+    references: Vec<(Id, Reference)>,
+    in_export_decl: bool,
+}
+
+impl ScopeAnalyzer {
+    fn with_scope<F>(&mut self, kind: ScopeKind, span: Span, op: F)
+    where
+        F: FnOnce(&mut Self),
+    {
+        let id = ScopeId(self.scopes.len());
+        let parent = self.cur;
+        self.scopes.push(Scope {
+            kind,
+            span,
+            parent: Some(parent),
+            children: vec![],
+            bindings: vec![],
+            fn_scope: match kind {
+                ScopeKind::Function => id,
+                _ => self.scopes[parent.0].fn_scope,
+            },
+        });
+        self.scopes[parent.0].children.push(id);
+
+        self.cur = id;
+        op(self);
+        self.cur = parent;
+    }
+
+    fn declare(&mut self, i: &Ident, kind: BindingKind) {
+        let scope = match kind {
+            BindingKind::Var | BindingKind::Param => self.scopes[self.cur.0].fn_scope,
+            _ => self.cur,
+        };
+        let id = i.to_id();
+        let decl = Declaration { span: i.span, kind };
+
+        match self.bindings.get_mut(&id) {
+            Some(binding) => binding.decls.push(decl),
+            None => {
+                self.scopes[scope.0].bindings.push(id.clone());
+                self.bindings.insert(
+                    id.clone(),
+                    Binding {
+                        id,
+                        kind,
+                        scope,
+                        decls: vec![decl],
+                        exported: self.in_export_decl,
+                        references: vec![],
+                    },
+                );
+            }
+        }
+    }
+
+    fn declare_pat<N>(&mut self, pat: &N, kind: BindingKind)
+    where
+        N: for<'any> VisitWith<crate::DestructuringFinder<'any, Ident>>,
+    {
+        let ids: Vec<Ident> = find_ids(pat);
+        for i in &ids {
+            self.declare(i, kind);
+        }
+    }
+
+    fn add_ref(&mut self, i: &Ident, kind: ReferenceKind) {
+        self.references.push((
+            i.to_id(),
+            Reference {
+                span: i.span,
+                kind,
+                scope: self.cur,
+                is_capture: false,
+            },
+        ));
+    }
+
+    /// Adds references for identifiers assigned by `pat`.
+    fn add_write_refs(&mut self, pat: &Pat, kind: ReferenceKind) {
+        match pat {
+            Pat::Expr(e) => {
+                if let Expr::Ident(i) = &**e {
+                    self.add_ref(i, kind);
+                }
+            }
+            _ => {
+                let ids: Vec<Ident> = find_ids(pat);
+                for i in &ids {
+                    self.add_ref(i, kind);
+                }
+            }
+        }
+    }
+
+    /// Visits statements without creating a block scope.
+    fn visit_stmts_in_scope(&mut self, stmts: &[Stmt]) {
+        for stmt in stmts {
+            stmt.visit_with(&Invalid { span: DUMMY_SP }, self);
+        }
+    }
+
+    /// Visits params and the body of `f` in the current scope.
+    fn visit_fn_in_scope(&mut self, f: &Function) {
+        for param in &f.params {
+            self.declare_pat(&param.pat, BindingKind::Param);
+        }
+        f.params.visit_with(f, self);
+
+        if let Some(body) = &f.body {
+            self.visit_stmts_in_scope(&body.stmts);
+        }
+    }
+}
+
+impl Visit for ScopeAnalyzer {
+    noop_visit_type!();
+
+    fn visit_module(&mut self, n: &Module, _: &dyn Node) {
+        self.scopes[0].span = n.span;
+        n.visit_children_with(self);
+    }
+
+    fn visit_script(&mut self, n: &Script, _: &dyn Node) {
+        self.scopes[0].span = n.span;
+        n.visit_children_with(self);
+    }
+
+    // Scopes
+
+    fn visit_function(&mut self, f: &Function, _: &dyn Node) {
+        f.decorators.visit_with(f, self);
+        self.with_scope(ScopeKind::Function, f.span, |v| v.visit_fn_in_scope(f));
+    }
+
+    fn visit_fn_expr(&mut self, n: &FnExpr, _: &dyn Node) {
+        n.function.decorators.visit_with(n, self);
+        self.with_scope(ScopeKind::Function, n.function.span, |v| {
+            if let Some(ident) = &n.ident {
+                v.declare(ident, BindingKind::ExprName);
+            }
+            v.visit_fn_in_scope(&n.function);
+        });
+    }
+
+    fn visit_arrow_expr(&mut self, n: &ArrowExpr, _: &dyn Node) {
+        self.with_scope(ScopeKind::Function, n.span, |v| {
+            v.declare_pat(&n.params, BindingKind::Param);
+            n.params.visit_with(n, v);
+
+            match &n.body {
+                BlockStmtOrExpr::BlockStmt(body) => v.visit_stmts_in_scope(&body.stmts),
+                BlockStmtOrExpr::Expr(body) => body.visit_with(n, v),
+            }
+        });
+    }
+
+    fn visit_constructor(&mut self, n: &Constructor, _: &dyn Node) {
+        n.key.visit_with(n, self);
+        self.with_scope(ScopeKind::Function, n.span, |v| {
+            v.declare_pat(&n.params, BindingKind::Param);
+            n.params.visit_with(n, v);
+
+            if let Some(body) = &n.body {
+                v.visit_stmts_in_scope(&body.stmts);
+            }
+        });
+    }
+
+    fn visit_getter_prop(&mut self, n: &GetterProp, _: &dyn Node) {
+        n.key.visit_with(n, self);
+        self.with_scope(ScopeKind::Function, n.span, |v| {
+            if let Some(body) = &n.body {
+                v.visit_stmts_in_scope(&body.stmts);
+            }
+        });
+    }
+
+    fn visit_setter_prop(&mut self, n: &SetterProp, _: &dyn Node) {
+        n.key.visit_with(n, self);
+        self.with_scope(ScopeKind::Function, n.span, |v| {
+            v.declare_pat(&n.param, BindingKind::Param);
+            n.param.visit_with(n, v);
+
+            if let Some(body) = &n.body {
+                v.visit_stmts_in_scope(&body.stmts);
+            }
+        });
+    }
+
+    fn visit_block_stmt(&mut self, n: &BlockStmt, _: &dyn Node) {
+        self.with_scope(ScopeKind::Block, n.span, |v| {
+            v.visit_stmts_in_scope(&n.stmts)
+        });
+    }
+
+    fn visit_for_stmt(&mut self, n: &ForStmt, _: &dyn Node) {
+        self.with_scope(ScopeKind::Block, n.span, |v| n.visit_children_with(v));
+    }
+
+    fn visit_for_in_stmt(&mut self, n: &ForInStmt, _: &dyn Node) {
+        self.with_scope(ScopeKind::Block, n.span, |v| {
+            if let VarDeclOrPat::Pat(pat) = &n.left {
+                v.add_write_refs(pat, ReferenceKind::Write);
+            }
+            n.visit_children_with(v)
+        });
+    }
+
+    fn visit_for_of_stmt(&mut self, n: &ForOfStmt, _: &dyn Node) {
+        self.with_scope(ScopeKind::Block, n.span, |v| {
+            if let VarDeclOrPat::Pat(pat) = &n.left {
+                v.add_write_refs(pat, ReferenceKind::Write);
+            }
+            n.visit_children_with(v)
+        });
+    }
+
+    fn visit_switch_stmt(&mut self, n: &SwitchStmt, _: &dyn Node) {
+        n.discriminant.visit_with(n, self);
+        self.with_scope(ScopeKind::Block, n.span, |v| n.cases.visit_with(n, v));
+    }
+
+    fn visit_catch_clause(&mut self, n: &CatchClause, _: &dyn Node) {
+        self.with_scope(ScopeKind::Catch, n.span, |v| {
+            if let Some(param) = &n.param {
+                v.declare_pat(param, BindingKind::CatchParam);
+                param.visit_with(n, v);
+            }
+            v.visit_stmts_in_scope(&n.body.stmts);
+        });
+    }
+
+    fn visit_class_expr(&mut self, n: &ClassExpr, _: &dyn Node) {
+        match &n.ident {
+            Some(ident) => self.with_scope(ScopeKind::Block, n.class.span, |v| {
+                v.declare(ident, BindingKind::ExprName);
+                n.class.visit_with(n, v);
+            }),
+            None => n.class.visit_with(n, self),
+        }
+    }
+
+    // Declarations
+
+    fn visit_var_decl(&mut self, n: &VarDecl, _: &dyn Node) {
+        for decl in &n.decls {
+            self.declare_pat(&decl.name, n.kind.into());
+        }
+
+        let exported = self.in_export_decl;
+        self.in_export_decl = false;
+        n.visit_children_with(self);
+        self.in_export_decl = exported;
+    }
+
+    fn visit_fn_decl(&mut self, n: &FnDecl, _: &dyn Node) {
+        self.declare(&n.ident, BindingKind::Function);
+
+        let exported = self.in_export_decl;
+        self.in_export_decl = false;
+        n.function.visit_with(n, self);
+        self.in_export_decl = exported;
+    }
+
+    fn visit_class_decl(&mut self, n: &ClassDecl, _: &dyn Node) {
+        self.declare(&n.ident, BindingKind::Class);
+
+        let exported = self.in_export_decl;
+        self.in_export_decl = false;
+        n.class.visit_with(n, self);
+        self.in_export_decl = exported;
+    }
+
+    fn visit_export_decl(&mut self, n: &ExportDecl, _: &dyn Node) {
+        self.in_export_decl = true;
+        n.visit_children_with(self);
+        self.in_export_decl = false;
+    }
+
+    fn visit_default_decl(&mut self, n: &DefaultDecl, _: &dyn Node) {
+        match n {
+            DefaultDecl::Class(ClassExpr {
+                ident: Some(ident),
+                class,
+            }) => {
+                self.in_export_decl = true;
+                self.declare(ident, BindingKind::Class);
+                self.in_export_decl = false;
+                class.visit_with(n, self);
+            }
+            DefaultDecl::Fn(FnExpr {
+                ident: Some(ident),
+                function,
+            }) => {
+                self.in_export_decl = true;
+                self.declare(ident, BindingKind::Function);
+                self.in_export_decl = false;
+                function.visit_with(n, self);
+            }
+            _ => n.visit_children_with(self),
+        }
+    }
+
+    fn visit_import_specifier(&mut self, n: &ImportSpecifier, _: &dyn Node) {
+        let local = match n {
+            ImportSpecifier::Named(s) => &s.local,
+            ImportSpecifier::Default(s) => &s.local,
+            ImportSpecifier::Namespace(s) => &s.local,
+        };
+        self.declare(local, BindingKind::Import);
+    }
+
+    // References
+
+    fn visit_expr(&mut self, e: &Expr, _: &dyn Node) {
+        match e {
+            Expr::Ident(i) => self.add_ref(i, ReferenceKind::Read),
+            _ => e.visit_children_with(self),
+        }
+    }
+
+    fn visit_pat(&mut self, p: &Pat, _: &dyn Node) {
+        match p {
+            // Binding identifiers and assignment targets are handled by the
+            // parent node.
+            Pat::Ident(..) => {}
+            Pat::Expr(e) => match &**e {
+                Expr::Ident(..) => {}
+                _ => e.visit_with(p, self),
+            },
+            _ => p.visit_children_with(self),
+        }
+    }
+
+    fn visit_assign_pat_prop(&mut self, p: &AssignPatProp, _: &dyn Node) {
+        p.value.visit_with(p, self);
+    }
+
+    fn visit_member_expr(&mut self, e: &MemberExpr, _: &dyn Node) {
+        e.obj.visit_with(e, self);
+        if e.computed {
+            e.prop.visit_with(e, self);
+        }
+    }
+
+    fn visit_class_prop(&mut self, p: &ClassProp, _: &dyn Node) {
+        p.decorators.visit_with(p, self);
+        if p.computed {
+            p.key.visit_with(p, self);
+        }
+        p.value.visit_with(p, self);
+    }
+
+    fn visit_prop(&mut self, p: &Prop, _: &dyn Node) {
+        match p {
+            Prop::Shorthand(i) => self.add_ref(i, ReferenceKind::Read),
+            _ => p.visit_children_with(self),
+        }
+    }
+
+    fn visit_named_export(&mut self, e: &NamedExport, _: &dyn Node) {
+        // `export { a } from 'foo'` does not reference local bindings.
+        if e.src.is_none() {
+            for s in &e.specifiers {
+                if let ExportSpecifier::Named(s) = s {
+                    self.add_ref(&s.orig, ReferenceKind::Read);
+                }
+            }
+        }
+    }
+
+    fn visit_assign_expr(&mut self, e: &AssignExpr, _: &dyn Node) {
+        let kind = if e.op == op!("=") {
+            ReferenceKind::Write
+        } else {
+            ReferenceKind::ReadWrite
+        };
+
+        match &e.left {
+            PatOrExpr::Pat(pat) => self.add_write_refs(pat, kind),
+            PatOrExpr::Expr(expr) => match &**expr {
+                Expr::Ident(i) => self.add_ref(i, kind),
+                _ => expr.visit_with(e, self),
+            },
+        }
+
+        if let PatOrExpr::Pat(pat) = &e.left {
+            pat.visit_with(e, self);
+        }
+        e.right.visit_with(e, self);
+    }
+
+    fn visit_update_expr(&mut self, e: &UpdateExpr, _: &dyn Node) {
+        match &*e.arg {
+            Expr::Ident(i) => self.add_ref(i, ReferenceKind::ReadWrite),
+            _ => e.arg.visit_with(e, self),
+        }
+    }
+
+    fn visit_jsx_element_name(&mut self, n: &JSXElementName, _: &dyn Node) {
+        match n {
+            // Lowercase names are intrinsic elements.
+            JSXElementName::Ident(i) if i.sym.starts_with(|c: char| c.is_ascii_uppercase()) => {
+                self.add_ref(i, ReferenceKind::Read)
+            }
+            _ => n.visit_children_with(self),
+        }
+    }
+
+    fn visit_jsx_object(&mut self, n: &JSXObject, _: &dyn Node) {
+        match n {
+            JSXObject::Ident(i) => self.add_ref(i, ReferenceKind::Read),
+            JSXObject::JSXMemberExpr(e) => e.obj.visit_with(n, self),
+        }
+    }
+
+    fn visit_jsx_member_expr(&mut self, n: &JSXMemberExpr, _: &dyn Node) {
+        n.obj.visit_with(n, self);
+    }
+}
@@ -0,0 +1,188 @@
+use swc_common::{FileName, Mark};
+use swc_ecma_parser::{lexer::Lexer, Parser, StringInput, Syntax};
+use swc_ecma_transforms_base::resolver::resolver_with_mark;
+use swc_ecma_utils::scope::{
+    analyze_scopes, Binding, BindingKind, ReferenceKind, ScopeAnalysis, ScopeKind,
+};
+use swc_ecma_visit::FoldWith;
+use testing::Tester;
+
+fn analyze<F>(src: &str, op: F)
+where
+    F: FnOnce(&ScopeAnalysis),
+{
+    Tester::new()
+        .print_errors(|cm, handler| {
+            let fm = cm.new_source_file(FileName::Anon, src.into());
+            let lexer = Lexer::new(
+                Syntax::default(),
+                Default::default(),
+                StringInput::from(&*fm),
+                None,
+            );
+            let module = Parser::new_from(lexer)
+                .parse_module()
+                .map_err(|err| err.into_diagnostic(&handler).emit())?;
+            let module = module.fold_with(&mut resolver_with_mark(Mark::fresh(Mark::root())));
+
+            op(&analyze_scopes(&module));
+            Ok(())
+        })
+        .unwrap()
+}
+
+/// Returns bindings named `sym`, in source order.
+fn bindings<'a>(analysis: &'a ScopeAnalysis, sym: &str) -> Vec<&'a Binding> {
+    let mut bindings = analysis
+        .bindings()
+        .filter(|b| &*b.id.0 == sym)
+        .collect::<Vec<_>>();
+    bindings.sort_by_key(|b| b.decls[0].span.lo);
+    bindings
+}
+
+#[test]
+fn reference_kinds() {
+    analyze(
+        "let a = 1;
+        a = 2;
+        a += 1;
+        a++;
+        [a] = [3];
+        console.log(a);",
+        |analysis| {
+            let a = bindings(analysis, "a");
+            assert_eq!(a.len(), 1);
+            assert_eq!(a[0].kind, BindingKind::Let);
+            assert_eq!(
+                a[0].references.iter().map(|r| r.kind).collect::<Vec<_>>(),
+                vec![
+                    ReferenceKind::Write,
+                    ReferenceKind::ReadWrite,
+                    ReferenceKind::ReadWrite,
+                    ReferenceKind::Write,
+                    ReferenceKind::Read,
+                ]
+            );
+            assert!(a[0].is_reassigned());
+
+            let unresolved = analysis
+                .unresolved()
+                .iter()
+                .map(|(id, _)| &*id.0)
+                .collect::<Vec<_>>();
+            assert_eq!(unresolved, vec!["console"]);
+        },
+    );
+}
+
+#[test]
+fn captures() {
+    analyze(
+        "export function f() {
+            const x = 1;
+            const y = 2;
+            y;
+            return () => x;
+        }",
+        |analysis| {
+            let x = bindings(analysis, "x");
+            assert!(x[0].is_captured());
+            assert!(!x[0].is_reassigned());
+
+            let y = bindings(analysis, "y");
+            assert!(!y[0].is_captured());
+
+            let f = bindings(analysis, "f");
+            assert!(f[0].exported);
+            assert!(f[0].references.is_empty());
+        },
+    );
+}
+
+#[test]
+fn shadowing() {
+    analyze(
+        "let a;
+        function f(a) {
+            return a;
+        }
+        f();",
+        |analysis| {
+            let a = bindings(analysis, "a");
+            assert_eq!(a.len(), 2);
+            assert!(a[0].references.is_empty());
+            assert_eq!(a[1].kind, BindingKind::Param);
+            assert_eq!(a[1].references.len(), 1);
+
+            let f_scope = analysis.scope(a[1].scope);
+            assert_eq!(f_scope.kind, ScopeKind::Function);
+            assert_eq!(f_scope.parent, Some(analysis.top_level()));
+        },
+    );
+}
+
+#[test]
+fn hoisting() {
+    analyze(
+        "{
+            var a;
+            let b;
+        }
+        try {} catch (e) {
+            var c;
+        }",
+        |analysis| {
+            let a = bindings(analysis, "a");
+            assert_eq!(a[0].scope, analysis.top_level());
+
+            let b = bindings(analysis, "b");
+            let block = analysis.scope(b[0].scope);
+            assert_eq!(block.kind, ScopeKind::Block);
+            assert_eq!(block.parent, Some(analysis.top_level()));
+            assert_eq!(block.bindings, vec![b[0].id.clone()]);
+
+            let e = bindings(analysis, "e");
+            assert_eq!(analysis.scope(e[0].scope).kind, ScopeKind::Catch);
+
+            let c = bindings(analysis, "c");
+            assert_eq!(c[0].scope, analysis.top_level());
+            assert!(analysis.is_within(e[0].scope, analysis.top_level()));
+        },
+    );
+}
+
+#[test]
+fn property_names_are_not_references() {
+    analyze(
+        "const a = { b: 1 };
+        a.b;
+        class C {
+            b = 1;
+        }
+        new C();",
+        |analysis| {
+            assert!(bindings(analysis, "a")[0].is_read());
+            assert!(analysis.unresolved().is_empty());
+        },
+    );
+}
+
+#[test]
+fn redeclarations() {
+    analyze(
+        "var a;
+        function a() {}
+        a();",
+        |analysis| {
+            let a = bindings(analysis, "a");
+            assert_eq!(a.len(), 1);
+            assert_eq!(a[0].kind, BindingKind::Var);
+            assert_eq!(
+                a[0].decls.iter().map(|d| d.kind).collect::<Vec<_>>(),
+                vec![BindingKind::Var, BindingKind::Function]
+            );
+            assert!(!a[0].decls[1].kind.is_lexical());
+        },
+    );
+}
@@ -448,6 +448,7 @@ define!({
         pub readonly: bool,
         pub declare: bool,
         pub definite: bool,
+        pub is_accessor: bool,
     }
     pub struct PrivateProp {
         pub span: Span,
@@ -463,6 +464,7 @@ define!({
         pub is_override: bool,
         pub readonly: bool,
         pub definite: bool,
+        pub is_accessor: bool,
     }
     pub struct ClassMethod {
         pub span: Span,
@@ -574,6 +576,7 @@ define!({
         TsAs(TsAsExpr),
         PrivateName(PrivateName),
         OptChain(OptChainExpr),
+        TopicRef(TopicRef),
         Invalid(Invalid),
     }
     pub struct ThisExpr {
@@ -721,6 +724,14 @@ define!({
         pub question_dot_token: Span,
         pub expr: Box<Expr>,
     }
+    pub struct TopicRef {
+        pub span: Span,
+        pub token: TopicToken,
+    }
+    pub enum TopicToken {
+        Percent,
+        Caret,
+    }
     pub struct Function {
         pub params: Vec<Param>,
         pub decorators: Vec<Decorator>,
@@ -1013,6 +1024,7 @@ define!({
         InstanceOf,
         Exp,
         NullishCoalescing,
+        Pipeline,
     }
     pub enum AssignOp {
         Assign,
@@ -8,6 +8,7 @@ use napi::{CallContext, Env, JsObject, Status, Task};
 use serde::Deserialize;
 use spack::resolvers::NodeResolver;
 use std::{
+    collections::{HashMap, HashSet},
     panic::{catch_unwind, AssertUnwindSafe},
     sync::Arc,
 };
@@ -15,7 +16,7 @@ use swc::{config::SourceMapsConfig, Compiler, TransformOutput};
 use swc_atoms::js_word;
 use swc_atoms::JsWord;
 use swc_bundler::{BundleKind, Bundler, Load, ModuleRecord, Resolve};
-use swc_common::Span;
+use swc_common::{FileName, Span};
 use swc_ecma_ast::{
     Bool, Expr, ExprOrSuper, Ident, KeyValueProp, Lit, MemberExpr, MetaPropExpr, PropName, Str,
 };
@@ -112,44 +113,82 @@ impl Task for BundleTask {
                 Box::new(Hook),
             );
 
-            let result = bundler
-                .bundle(self.config.static_items.config.entry.clone().into())
-                .convert_err()?;
+            let entries: HashMap<String, FileName> =
+                self.config.static_items.config.entry.clone().into();
+            let entry_names = entries.keys().cloned().collect::<HashSet<_>>();
 
-            let result = result
-                .into_iter()
-                .map(|bundle| match bundle.kind {
-                    BundleKind::Named { name } | BundleKind::Lib { name } => {
-                        Ok((name, bundle.module))
-                    }
-                    BundleKind::Dynamic => bail!("unimplemented: dynamic code splitting"),
-                })
-                .map(|res| {
-                    res.and_then(|(k, m)| {
-                        // TODO: Source map
-                        let minify = self
-                            .config
-                            .static_items
-                            .config
-                            .options
-                            .as_ref()
-                            .and_then(|v| v.config.minify)
-                            .unwrap_or(false);
-
-                        let output = self.swc.print(
-                            &m,
-                            codegen_target,
-                            SourceMapsConfig::Bool(true),
-                            None,
-                            minify,
-                        )?;
-
-                        Ok((k, output))
+            let result = bundler.bundle(entries).convert_err()?;
+
+            let outputs = match &self.config.static_items.config.output {
+                Some(output) => spack::output::name_outputs(
+                    self.swc.cm.clone(),
+                    output,
+                    &entry_names,
+                    &*self.config.resolver,
+                    result,
+                )
+                .convert_err()?,
+                None => result
+                    .into_iter()
+                    .map(|bundle| match bundle.kind {
+                        BundleKind::Named { name } | BundleKind::Lib { name } => {
+                            Ok(spack::output::Output {
+                                is_entry: entry_names.contains(&name),
+                                file_name: name.clone(),
+                                name,
+                                module: bundle.module,
+                            })
+                        }
+                        BundleKind::Dynamic => bail!("unimplemented: dynamic code splitting"),
                     })
+                    .collect::<Result<_, _>>()
+                    .convert_err()?,
+            };
+
+            let mut result = outputs
+                .iter()
+                .map(|output| {
+                    // TODO: Source map
+                    let minify = self
+                        .config
+                        .static_items
+                        .config
+                        .options
+                        .as_ref()
+                        .and_then(|v| v.config.minify)
+                        .unwrap_or(false);
+
+                    let code = self.swc.print(
+                        &output.module,
+                        codegen_target,
+                        SourceMapsConfig::Bool(true),
+                        None,
+                        minify,
+                    )?;
+
+                    Ok((output.file_name.clone(), code))
                 })
-                .collect::<Result<_, _>>()
+                .collect::<Result<FxHashMap<_, _>, Error>>()
                 .convert_err()?;
 
+            if self
+                .config
+                .static_items
+                .config
+                .output
+                .as_ref()
+                .map_or(false, |output| output.manifest)
+            {
+                let manifest = spack::output::manifest(&outputs).convert_err()?;
+                result.insert(
+                    spack::output::MANIFEST_FILE_NAME.to_string(),
+                    TransformOutput {
+                        code: manifest,
+                        map: None,
+                    },
+                );
+            }
+
             Ok(result)
         }));
 
@@ -49,14 +49,23 @@ fn init(mut exports: JsObject) -> napi::Result<()> {
     exports.create_named_method("transformSync", transform::transform_sync)?;
     exports.create_named_method("transformFile", transform::transform_file)?;
     exports.create_named_method("transformFileSync", transform::transform_file_sync)?;
+    exports.create_named_method("transformBinary", transform::transform_binary)?;
+    exports.create_named_method("transformBinarySync", transform::transform_binary_sync)?;
 
     exports.create_named_method("parse", parse::parse)?;
     exports.create_named_method("parseSync", parse::parse_sync)?;
     exports.create_named_method("parseFile", parse::parse_file)?;
     exports.create_named_method("parseFileSync", parse::parse_file_sync)?;
+    exports.create_named_method("parseBinary", parse::parse_binary)?;
+    exports.create_named_method("parseBinarySync", parse::parse_binary_sync)?;
+
+    exports.create_named_method("tokenize", parse::tokenize)?;
+    exports.create_named_method("tokenizeSync", parse::tokenize_sync)?;
 
     exports.create_named_method("print", print::print)?;
     exports.create_named_method("printSync", print::print_sync)?;
+    exports.create_named_method("printBinary", print::print_binary)?;
+    exports.create_named_method("printBinarySync", print::print_binary_sync)?;
 
     exports.create_named_method("bundle", bundle::bundle)?;
 
@@ -3,14 +3,15 @@ use crate::{
     util::{CtxtExt, MapErr},
 };
 use anyhow::Context as _;
-use napi::{CallContext, Env, JsObject, JsString, Task};
+use napi::{CallContext, Env, JsBuffer, JsObject, JsString, JsUnknown, Task};
 use std::{
     path::{Path, PathBuf},
     sync::Arc,
 };
 use swc::{config::ParseOptions, Compiler};
 use swc_common::{FileName, SourceFile};
-use swc_ecma_ast::Program;
+use swc_ecma_ast::{binary, Program};
+use swc_ecma_parser::lexer::lossless::{to_utf16_offsets, RawToken};
 
 // ----- Parsing -----
 
@@ -18,6 +19,8 @@ pub struct ParseTask {
     pub c: Arc<Compiler>,
     pub fm: Arc<SourceFile>,
     pub options: ParseOptions,
+    /// Resolves to a buffer encoded by `swc_ecma_ast::binary` if true.
+    pub binary: bool,
 }
 
 pub struct ParseFileTask {
@@ -26,16 +29,59 @@ pub struct ParseFileTask {
     pub options: ParseOptions,
 }
 
-pub fn complete_parse<'a>(env: &Env, program: Program, _c: &Compiler) -> napi::Result<JsString> {
-    let s = serde_json::to_string(&program)
+/// Returns the json of `program` with the `jsdoc` field.
+fn with_jsdoc(program: &Program, c: &Compiler) -> napi::Result<serde_json::Value> {
+    let docs = c.collect_jsdoc(program);
+    let mut json = serde_json::to_value(program)
         .context("failed to serialize Program")
         .convert_err()?;
+    if let Some(obj) = json.as_object_mut() {
+        obj.insert(
+            "jsdoc".into(),
+            serde_json::to_value(&docs)
+                .context("failed to serialize jsdoc")
+                .convert_err()?,
+        );
+    }
+    Ok(json)
+}
+
+pub fn complete_parse<'a>(
+    env: &Env,
+    program: Program,
+    c: &Compiler,
+    options: &ParseOptions,
+) -> napi::Result<JsString> {
+    let s = if options.jsdoc {
+        with_jsdoc(&program, c)?.to_string()
+    } else {
+        serde_json::to_string(&program)
+            .context("failed to serialize Program")
+            .convert_err()?
+    };
     env.create_string_from_std(s)
 }
 
+pub fn complete_parse_binary(
+    env: &Env,
+    program: Program,
+    c: &Compiler,
+    options: &ParseOptions,
+) -> napi::Result<JsBuffer> {
+    let data = if options.jsdoc {
+        binary::to_vec(&with_jsdoc(&program, c)?)
+    } else {
+        binary::to_vec(&program)
+    }
+    .context("failed to encode Program")
+    .convert_err()?;
+
+    Ok(env.create_buffer_with_data(data)?.into_raw())
+}
+
 impl Task for ParseTask {
     type Output = Program;
-    type JsValue = JsString;
+    type JsValue = JsUnknown;
 
     fn compute(&mut self) -> napi::Result<Self::Output> {
         let program = self
@@ -45,7 +91,7 @@ impl Task for ParseTask {
                 self.options.target,
                 self.options.syntax,
                 self.options.is_module,
-                self.options.comments,
+                self.options.comments || self.options.jsdoc,
             )
             .convert_err()?;
 
@@ -53,7 +99,11 @@ impl Task for ParseTask {
     }
 
     fn resolve(self, env: Env, result: Self::Output) -> napi::Result<Self::JsValue> {
-        complete_parse(&env, result, &self.c)
+        if self.binary {
+            complete_parse_binary(&env, result, &self.c, &self.options).map(|v| v.into_unknown())
+        } else {
+            complete_parse(&env, result, &self.c, &self.options).map(|v| v.into_unknown())
+        }
     }
 }
 
@@ -76,14 +126,14 @@ impl Task for ParseFileTask {
                     self.options.target,
                     self.options.syntax,
                     self.options.is_module,
-                    self.options.comments,
+                    self.options.comments || self.options.jsdoc,
                 )
                 .convert_err()
         })
     }
 
     fn resolve(self, env: Env, result: Self::Output) -> napi::Result<Self::JsValue> {
-        complete_parse(&env, result, &self.c)
+        complete_parse(&env, result, &self.c, &self.options)
     }
 }
 
@@ -101,10 +151,54 @@ pub fn parse(ctx: CallContext) -> napi::Result<JsObject> {
             c: c.clone(),
             fm,
             options,
+            binary: false,
+        })
+        .map(|t| t.promise_object())
+}
+
+#[js_function(2)]
+pub fn parse_binary(ctx: CallContext) -> napi::Result<JsObject> {
+    let c = get_compiler(&ctx);
+    let src = ctx.get::<JsString>(0)?.into_utf8()?;
+    let options: ParseOptions = ctx.get_deserialized(1)?;
+
+    let fm =
+        c.cm.new_source_file(FileName::Anon, src.as_str()?.to_string());
+
+    ctx.env
+        .spawn(ParseTask {
+            c: c.clone(),
+            fm,
+            options,
+            binary: true,
         })
         .map(|t| t.promise_object())
 }
 
+#[js_function(2)]
+pub fn parse_binary_sync(cx: CallContext) -> napi::Result<JsBuffer> {
+    let c = get_compiler(&cx);
+
+    c.run(|| {
+        let src = cx.get::<JsString>(0)?.into_utf8()?.as_str()?.to_owned();
+        let options: ParseOptions = cx.get_deserialized(1)?;
+
+        let program = {
+            let fm = c.cm.new_source_file(FileName::Anon, src);
+            c.parse_js(
+                fm,
+                options.target,
+                options.syntax,
+                options.is_module,
+                options.comments || options.jsdoc,
+            )
+        }
+        .convert_err()?;
+
+        complete_parse_binary(&cx.env, program, &c, &options)
+    })
+}
+
 #[js_function(2)]
 pub fn parse_sync(cx: CallContext) -> napi::Result<JsString> {
     let c = get_compiler(&cx);
@@ -120,12 +214,12 @@ pub fn parse_sync(cx: CallContext) -> napi::Result<JsString> {
                 options.target,
                 options.syntax,
                 options.is_module,
-                options.comments,
+                options.comments || options.jsdoc,
             )
         }
         .convert_err()?;
 
-        complete_parse(&cx.env, program, &c)
+        complete_parse(&cx.env, program, &c, &options)
     })
 }
 
@@ -145,12 +239,12 @@ pub fn parse_file_sync(cx: CallContext) -> napi::Result<JsString> {
             options.target,
             options.syntax,
             options.is_module,
-            options.comments,
+            options.comments || options.jsdoc,
         )
     }
     .convert_err()?;
 
-    complete_parse(cx.env, program, &c)
+    complete_parse(cx.env, program, &c, &options)
 }
 
 #[js_function(2)]
@@ -163,3 +257,64 @@ pub fn parse_file(cx: CallContext) -> napi::Result<JsObject> {
         .spawn(ParseFileTask { c, path, options })
         .map(|t| t.promise_object())
 }
+
+// ----- Tokenizing -----
+
+pub struct TokenizeTask {
+    pub c: Arc<Compiler>,
+    pub fm: Arc<SourceFile>,
+    pub options: ParseOptions,
+}
+
+fn complete_tokenize(env: &Env, mut tokens: Vec<RawToken>) -> napi::Result<JsString> {
+    to_utf16_offsets(&mut tokens);
+    let s = serde_json::to_string(&tokens)
+        .context("failed to serialize tokens")
+        .convert_err()?;
+    env.create_string_from_std(s)
+}
+
+impl Task for TokenizeTask {
+    type Output = Vec<RawToken>;
+    type JsValue = JsString;
+
+    fn compute(&mut self) -> napi::Result<Self::Output> {
+        Ok(self
+            .c
+            .tokenize(self.fm.clone(), self.options.target, self.options.syntax))
+    }
+
+    fn resolve(self, env: Env, result: Self::Output) -> napi::Result<Self::JsValue> {
+        complete_tokenize(&env, result)
+    }
+}
+
+#[js_function(2)]
+pub fn tokenize(ctx: CallContext) -> napi::Result<JsObject> {
+    let c = get_compiler(&ctx);
+    let src = ctx.get::<JsString>(0)?.into_utf8()?;
+    let options: ParseOptions = ctx.get_deserialized(1)?;
+
+    let fm =
+        c.cm.new_source_file(FileName::Anon, src.as_str()?.to_string());
+
+    ctx.env
+        .spawn(TokenizeTask {
+            c: c.clone(),
+            fm,
+            options,
+        })
+        .map(|t| t.promise_object())
+}
+
+#[js_function(2)]
+pub fn tokenize_sync(cx: CallContext) -> napi::Result<JsString> {
+    let c = get_compiler(&cx);
+    let src = cx.get::<JsString>(0)?.into_utf8()?.as_str()?.to_owned();
+    let options: ParseOptions = cx.get_deserialized(1)?;
+
+    let fm = c.cm.new_source_file(FileName::Anon, src);
+    let tokens = c.tokenize(fm, options.target, options.syntax);
+
+    complete_tokenize(cx.env, tokens)
+}
@@ -93,3 +93,39 @@ pub fn print_sync(cx: CallContext) -> napi::Result<JsObject> {
     .convert_err()?;
     complete_output(cx.env, result)
 }
+
+#[js_function(2)]
+pub fn print_binary(cx: CallContext) -> napi::Result<JsObject> {
+    let c = get_compiler(&cx);
+    let program = cx.get_binary_program(0)?;
+    let options: Options = cx.get_deserialized(1)?;
+
+    cx.env
+        .spawn(PrintTask {
+            c: c.clone(),
+            program,
+            options,
+        })
+        .map(|t| t.promise_object())
+}
+
+#[js_function(2)]
+pub fn print_binary_sync(cx: CallContext) -> napi::Result<JsObject> {
+    let c = get_compiler(&cx);
+    let program = cx.get_binary_program(0)?;
+    let options: Options = cx.get_deserialized(1)?;
+
+    let result = c
+        .print(
+            &program,
+            options.codegen_target().unwrap_or_default(),
+            options
+                .source_maps
+                .clone()
+                .unwrap_or(SourceMapsConfig::Bool(false)),
+            None,
+            options.config.minify.unwrap_or(false),
+        )
+        .convert_err()?;
+    complete_output(cx.env, result)
+}
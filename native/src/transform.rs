@@ -3,7 +3,7 @@ use crate::{
     util::{CtxtExt, MapErr},
 };
 use anyhow::{Context as _, Error};
-use napi::{CallContext, Env, JsBoolean, JsObject, JsString, Task};
+use napi::{CallContext, Env, JsBoolean, JsBuffer, JsObject, JsString, Task};
 use path_clean::clean;
 use std::{
     path::{Path, PathBuf},
@@ -11,13 +11,15 @@ use std::{
 };
 use swc::{config::Options, Compiler, TransformOutput};
 use swc_common::{FileName, SourceFile};
-use swc_ecma_ast::Program;
+use swc_ecma_ast::{binary, Program};
 
 /// Input to transform
 #[derive(Debug)]
 pub enum Input {
     /// json string
     Program(String),
+    /// Program encoded by `swc_ecma_ast::binary`.
+    BinaryProgram(Vec<u8>),
     /// Raw source code.
     Source(Arc<SourceFile>),
     /// File
@@ -44,6 +46,12 @@ impl Task for TransformTask {
                     self.c.process_js(program, &self.options)
                 }
 
+                Input::BinaryProgram(ref data) => {
+                    let program: Program =
+                        binary::from_slice(data).context("failed to decode Program")?;
+                    self.c.process_js(program, &self.options)
+                }
+
                 Input::File(ref path) => {
                     let fm = self.c.cm.load_file(path).context("failed to read module")?;
                     self.c.process_js_file(fm, &self.options)
@@ -158,3 +166,29 @@ pub fn transform_file_sync(cx: CallContext) -> napi::Result<JsObject> {
             .expect("failed to load file"))
     })
 }
+
+#[js_function(2)]
+pub fn transform_binary(cx: CallContext) -> napi::Result<JsObject> {
+    let c = get_compiler(&cx);
+    let program = cx.get::<JsBuffer>(0)?.into_value()?.to_vec();
+    let options: Options = cx.get_deserialized(1)?;
+
+    cx.env
+        .spawn(TransformTask {
+            c,
+            input: Input::BinaryProgram(program),
+            options,
+        })
+        .map(|t| t.promise_object())
+}
+
+#[js_function(2)]
+pub fn transform_binary_sync(cx: CallContext) -> napi::Result<JsObject> {
+    let c = get_compiler(&cx);
+    let program = cx.get_binary_program(0)?;
+    let options: Options = cx.get_deserialized(1)?;
+
+    let output = c.run(|| c.process_js(program, &options)).convert_err()?;
+
+    complete_output(cx.env, output)
+}
@@ -1,6 +1,7 @@
 use anyhow::Context;
 use napi::{CallContext, JsBuffer, Status};
 use serde::de::DeserializeOwned;
+use swc_ecma_ast::{binary, Program};
 
 pub trait MapErr<T>: Into<Result<T, anyhow::Error>> {
     fn convert_err(self) -> napi::Result<T> {
@@ -16,6 +17,9 @@ pub trait CtxtExt {
     fn get_deserialized<T>(&self, index: usize) -> napi::Result<T>
     where
         T: DeserializeOwned;
+
+    /// Decodes a buffer encoded by `swc_ecma_ast::binary`.
+    fn get_binary_program(&self, index: usize) -> napi::Result<Program>;
 }
 
 impl CtxtExt for CallContext<'_> {
@@ -30,4 +34,13 @@ impl CtxtExt for CallContext<'_> {
 
         Ok(v)
     }
+
+    fn get_binary_program(&self, index: usize) -> napi::Result<Program> {
+        let buffer = self.get::<JsBuffer>(index)?.into_value()?;
+        let program = binary::from_slice(&buffer)
+            .context("failed to decode Program")
+            .convert_err()?;
+
+        Ok(program)
+    }
 }
@@ -0,0 +1,38 @@
+const swc = require("../../../");
+
+const src = `
+import foo from "foo";
+export const a = (b) => b ?? -1.5 + 2n;
+class Foo { #bar = "baz"; }
+`;
+
+it("should decode to the same program as json", () => {
+  const program = swc.decodeAst(swc.parseBinarySync(src));
+
+  expect(program).toEqual(swc.parseSync(src));
+});
+
+it("should print decoded and encoded programs", async () => {
+  const program = swc.decodeAst(await swc.parseBinary(src));
+  const data = swc.encodeAst(program);
+
+  expect(swc.decodeAst(data)).toEqual(program);
+  expect(swc.printBinarySync(data).code).toBe(swc.printSync(program).code);
+  expect((await swc.printBinary(data)).code).toBe(swc.printSync(program).code);
+});
+
+it("should transform binary programs", async () => {
+  const data = swc.parseBinarySync(src);
+  const options = { jsc: { target: "es5" } };
+
+  const expected = swc.transformSync(swc.decodeAst(data), options).code;
+  expect(swc.transformBinarySync(data, options).code).toBe(expected);
+  expect((await swc.transformBinary(data, options)).code).toBe(expected);
+});
+
+it("should reject invalid data", () => {
+  expect(() => swc.decodeAst(Buffer.from("SWCAÿ"))).toThrow(
+    /unsupported version/
+  );
+  expect(() => swc.printBinarySync(Buffer.from("{}"))).toThrow();
+});
@@ -0,0 +1,39 @@
+const swc = require("../../../");
+
+it("should be lossless", () => {
+  const src = `/* a */ foo(\`x\${1}\`); // b\n`;
+  const tokens = swc.tokenizeSync(src);
+
+  expect(tokens.map((t) => t.text).join("")).toBe(src);
+  expect(tokens[0]).toEqual({
+    kind: "blockComment",
+    start: 0,
+    end: 7,
+    text: "/* a */",
+  });
+  expect(tokens.filter((t) => t.kind === "lineComment")).toHaveLength(1);
+});
+
+it("should return offsets of javascript strings", () => {
+  const src = `'한글' + "😀"`;
+  const tokens = swc.tokenizeSync(src);
+
+  for (const t of tokens) {
+    expect(src.slice(t.start, t.end)).toBe(t.text);
+  }
+});
+
+it("should work asynchronously", async () => {
+  const tokens = await swc.tokenize(`let a = 1;`);
+
+  expect(tokens.map((t) => t.kind)).toEqual([
+    "keyword",
+    "whitespace",
+    "ident",
+    "whitespace",
+    "punct",
+    "whitespace",
+    "num",
+    "punct",
+  ]);
+});
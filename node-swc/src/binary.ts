@@ -0,0 +1,256 @@
+/**
+ * Decoder and encoder of the binary ast format of `swc_ecma_ast::binary`.
+ *
+ * A decoded value has the same shape as the json of the ast.
+ */
+
+/**
+ * Version of the binary format supported by this module.
+ */
+export const BINARY_AST_VERSION = 1;
+
+const MAGIC = [0x53, 0x57, 0x43, 0x41]; // SWCA
+
+const enum Tag {
+  Null = 0x00,
+  False = 0x01,
+  True = 0x02,
+  UInt = 0x03,
+  NegInt = 0x04,
+  F64 = 0x05,
+  Str = 0x06,
+  StrRef = 0x07,
+  Bytes = 0x08,
+  Array = 0x09,
+  Map = 0x0a,
+  End = 0x0b,
+}
+
+/**
+ * Decodes the output of `parseBinary`.
+ */
+export function decodeAst(data: Uint8Array): any {
+  const buf = Buffer.from(data.buffer, data.byteOffset, data.byteLength);
+  for (let i = 0; i < MAGIC.length; i++) {
+    if (buf[i] !== MAGIC[i]) {
+      throw new Error("invalid binary ast: invalid header");
+    }
+  }
+  if (buf[MAGIC.length] !== BINARY_AST_VERSION) {
+    throw new Error(
+      `invalid binary ast: unsupported version ${buf[MAGIC.length]} (expected ${BINARY_AST_VERSION})`
+    );
+  }
+
+  const decoder = new Decoder(buf, MAGIC.length + 1);
+  const value = decoder.value();
+  if (decoder.pos !== buf.length) {
+    throw new Error(`invalid binary ast: trailing bytes at ${decoder.pos}`);
+  }
+  return value;
+}
+
+/**
+ * Encodes a program so that it can be passed to `printBinary` or
+ * `transformBinary`.
+ */
+export function encodeAst(value: any): Buffer {
+  const encoder = new Encoder();
+  for (const b of MAGIC) {
+    encoder.byte(b);
+  }
+  encoder.byte(BINARY_AST_VERSION);
+  encoder.value(value);
+  return encoder.finish();
+}
+
+class Decoder {
+  private strings: string[] = [];
+
+  constructor(private buf: Buffer, public pos: number) {}
+
+  private byte(): number {
+    if (this.pos >= this.buf.length) {
+      throw new Error("invalid binary ast: unexpected eof");
+    }
+    return this.buf[this.pos++];
+  }
+
+  private varint(): number {
+    let value = 0;
+    let mul = 1;
+    for (;;) {
+      const b = this.byte();
+      value += (b & 0x7f) * mul;
+      if ((b & 0x80) === 0) {
+        return value;
+      }
+      mul *= 0x80;
+    }
+  }
+
+  private bytes(len: number): Buffer {
+    const end = this.pos + len;
+    if (end > this.buf.length) {
+      throw new Error("invalid binary ast: unexpected eof");
+    }
+    const bytes = this.buf.subarray(this.pos, end);
+    this.pos = end;
+    return bytes;
+  }
+
+  value(): any {
+    const tag = this.byte();
+    switch (tag) {
+      case Tag.Null:
+        return null;
+      case Tag.False:
+        return false;
+      case Tag.True:
+        return true;
+      case Tag.UInt:
+        return this.varint();
+      case Tag.NegInt:
+        return -1 - this.varint();
+      case Tag.F64: {
+        const v = this.buf.readDoubleLE(this.pos);
+        this.pos += 8;
+        return v;
+      }
+      case Tag.Str: {
+        const s = this.bytes(this.varint()).toString("utf8");
+        this.strings.push(s);
+        return s;
+      }
+      case Tag.StrRef: {
+        const idx = this.varint();
+        if (idx >= this.strings.length) {
+          throw new Error(`invalid binary ast: invalid string index ${idx}`);
+        }
+        return this.strings[idx];
+      }
+      case Tag.Bytes:
+        return Uint8Array.from(this.bytes(this.varint()));
+      case Tag.Array: {
+        const arr = [];
+        while (this.buf[this.pos] !== Tag.End) {
+          arr.push(this.value());
+        }
+        this.pos++;
+        return arr;
+      }
+      case Tag.Map: {
+        const obj: any = {};
+        while (this.buf[this.pos] !== Tag.End) {
+          const key = this.value();
+          obj[key] = this.value();
+        }
+        this.pos++;
+        return obj;
+      }
+      default:
+        throw new Error(`invalid binary ast: invalid tag ${tag} at ${this.pos - 1}`);
+    }
+  }
+}
+
+class Encoder {
+  private buf = Buffer.allocUnsafe(1024);
+  private pos = 0;
+  private strings = new Map<string, number>();
+
+  private reserve(len: number) {
+    if (this.pos + len <= this.buf.length) {
+      return;
+    }
+    let size = this.buf.length * 2;
+    while (size < this.pos + len) {
+      size *= 2;
+    }
+    const buf = Buffer.allocUnsafe(size);
+    this.buf.copy(buf, 0, 0, this.pos);
+    this.buf = buf;
+  }
+
+  byte(b: number) {
+    this.reserve(1);
+    this.buf[this.pos++] = b;
+  }
+
+  private varint(v: number) {
+    while (v >= 0x80) {
+      this.byte((v % 0x80) | 0x80);
+      v = Math.floor(v / 0x80);
+    }
+    this.byte(v);
+  }
+
+  private str(s: string) {
+    const idx = this.strings.get(s);
+    if (idx !== undefined) {
+      this.byte(Tag.StrRef);
+      this.varint(idx);
+      return;
+    }
+    this.strings.set(s, this.strings.size);
+
+    const len = Buffer.byteLength(s);
+    this.byte(Tag.Str);
+    this.varint(len);
+    this.reserve(len);
+    this.pos += this.buf.write(s, this.pos, "utf8");
+  }
+
+  value(v: any) {
+    if (v === null || v === undefined) {
+      this.byte(Tag.Null);
+    } else if (typeof v === "boolean") {
+      this.byte(v ? Tag.True : Tag.False);
+    } else if (typeof v === "number") {
+      if (Number.isSafeInteger(v) && !Object.is(v, -0)) {
+        if (v >= 0) {
+          this.byte(Tag.UInt);
+          this.varint(v);
+        } else {
+          this.byte(Tag.NegInt);
+          this.varint(-1 - v);
+        }
+      } else {
+        this.byte(Tag.F64);
+        this.reserve(8);
+        this.pos = this.buf.writeDoubleLE(v, this.pos);
+      }
+    } else if (typeof v === "string") {
+      this.str(v);
+    } else if (v instanceof Uint8Array) {
+      this.byte(Tag.Bytes);
+      this.varint(v.length);
+      this.reserve(v.length);
+      this.buf.set(v, this.pos);
+      this.pos += v.length;
+    } else if (Array.isArray(v)) {
+      this.byte(Tag.Array);
+      for (const elem of v) {
+        this.value(elem);
+      }
+      this.byte(Tag.End);
+    } else if (typeof v === "object") {
+      this.byte(Tag.Map);
+      for (const key of Object.keys(v)) {
+        // Same as `JSON.stringify`.
+        if (v[key] === undefined || typeof v[key] === "function") {
+          continue;
+        }
+        this.str(key);
+        this.value(v[key]);
+      }
+      this.byte(Tag.End);
+    } else {
+      throw new Error(`cannot encode ${typeof v} in binary ast`);
+    }
+  }
+
+  finish(): Buffer {
+    return this.buf.subarray(0, this.pos);
+  }
+}
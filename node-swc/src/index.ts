@@ -6,8 +6,10 @@ import {
   Options,
   Script,
   Program,
+  RawToken,
 } from "./types";
 export * from "./types";
+export { BINARY_AST_VERSION, decodeAst, encodeAst } from "./binary";
 import { BundleInput, compileBundleOptions } from "./spack";
 import { loadBinding } from "@node-rs/helper";
 import * as babel from '@babel/types';
@@ -113,6 +115,42 @@ export class Compiler {
     return JSON.parse(bindings.parseFileSync(path, toBuffer(options)));
   }
 
+  /**
+   * Parses `src` into the binary ast format, which is faster than `parse`
+   * for large files. Use `decodeAst` to get a `Program`.
+   */
+  async parseBinary(src: string, options?: ParseOptions): Promise<Buffer> {
+    options = options || { syntax: "ecmascript" };
+    options.syntax = options.syntax || "ecmascript";
+
+    return bindings.parseBinary(src, toBuffer(options));
+  }
+
+  parseBinarySync(src: string, options?: ParseOptions): Buffer {
+    options = options || { syntax: "ecmascript" };
+    options.syntax = options.syntax || "ecmascript";
+
+    return bindings.parseBinarySync(src, toBuffer(options));
+  }
+
+  /**
+   * Splits `src` into tokens, including whitespaces and comments.
+   */
+  async tokenize(src: string, options?: ParseOptions): Promise<RawToken[]> {
+    options = options || { syntax: "ecmascript" };
+    options.syntax = options.syntax || "ecmascript";
+
+    const res = await bindings.tokenize(src, toBuffer(options));
+    return JSON.parse(res);
+  }
+
+  tokenizeSync(src: string, options?: ParseOptions): RawToken[] {
+    options = options || { syntax: "ecmascript" };
+    options.syntax = options.syntax || "ecmascript";
+
+    return JSON.parse(bindings.tokenizeSync(src, toBuffer(options)));
+  }
+
   /**
    * Note: this method should be invoked on the compiler instance used
    *  for `parse()` / `parseSync()`.
@@ -133,6 +171,36 @@ export class Compiler {
     return bindings.printSync(JSON.stringify(m), toBuffer(options));
   }
 
+  /**
+   * Same as `print`, but takes a program in the binary ast format.
+   */
+  async printBinary(m: Uint8Array, options?: Options): Promise<Output> {
+    options = options || {};
+
+    return bindings.printBinary(asBuffer(m), toBuffer(options));
+  }
+
+  printBinarySync(m: Uint8Array, options?: Options): Output {
+    options = options || {};
+
+    return bindings.printBinarySync(asBuffer(m), toBuffer(options));
+  }
+
+  /**
+   * Same as `transform`, but takes a program in the binary ast format.
+   */
+  async transformBinary(m: Uint8Array, options?: Options): Promise<Output> {
+    options = options || {};
+
+    return bindings.transformBinary(asBuffer(m), toBuffer(options));
+  }
+
+  transformBinarySync(m: Uint8Array, options?: Options): Output {
+    options = options || {};
+
+    return bindings.transformBinarySync(asBuffer(m), toBuffer(options));
+  }
+
   async transform(src: string | Program, options?: Options): Promise<Output> {
     const isModule = typeof src !== "string";
     options = options || {};
@@ -283,6 +351,28 @@ export function parseFileSync(path: string, options?: ParseOptions): Program {
   return compiler.parseFileSync(path, options);
 }
 
+export function tokenize(
+  src: string,
+  options?: ParseOptions
+): Promise<RawToken[]> {
+  return compiler.tokenize(src, options);
+}
+
+export function tokenizeSync(src: string, options?: ParseOptions): RawToken[] {
+  return compiler.tokenizeSync(src, options);
+}
+
+export function parseBinary(
+  src: string,
+  options?: ParseOptions
+): Promise<Buffer> {
+  return compiler.parseBinary(src, options);
+}
+
+export function parseBinarySync(src: string, options?: ParseOptions): Buffer {
+  return compiler.parseBinarySync(src, options);
+}
+
 export function print(m: Program, options?: Options): Promise<Output> {
   return compiler.print(m, options);
 }
@@ -291,6 +381,17 @@ export function printSync(m: Program, options?: Options): Output {
   return compiler.printSync(m, options);
 }
 
+export function printBinary(
+  m: Uint8Array,
+  options?: Options
+): Promise<Output> {
+  return compiler.printBinary(m, options);
+}
+
+export function printBinarySync(m: Uint8Array, options?: Options): Output {
+  return compiler.printBinarySync(m, options);
+}
+
 export function transform(
   src: string | Program,
   options?: Options
@@ -305,6 +406,17 @@ export function transformSync(
   return compiler.transformSync(src, options);
 }
 
+export function transformBinary(
+  m: Uint8Array,
+  options?: Options
+): Promise<Output> {
+  return compiler.transformBinary(m, options);
+}
+
+export function transformBinarySync(m: Uint8Array, options?: Options): Output {
+  return compiler.transformBinarySync(m, options);
+}
+
 export function transformFile(
   path: string,
   options?: Options
@@ -335,3 +447,9 @@ export const DEFAULT_EXTENSIONS = Object.freeze([
 function toBuffer(t: any): Buffer {
   return Buffer.from(JSON.stringify(t))
 }
+
+function asBuffer(data: Uint8Array): Buffer {
+  return Buffer.isBuffer(data)
+    ? data
+    : Buffer.from(data.buffer, data.byteOffset, data.byteLength);
+}
@@ -79,6 +79,24 @@ export interface SpackConfig {
 export interface OutputConfig {
     name: string
     path: string
+
+    /**
+     * Template for file names of entries.
+     *
+     * Supports `[name]`, `[hash]` and `[contenthash]`. Hashes can be
+     * truncated like `[hash:8]`.
+     */
+    filename?: string
+
+    /**
+     * Template for file names of shared chunks.
+     */
+    chunkFilename?: string
+
+    /**
+     * Emit `manifest.json`, which maps bundle names to emitted files.
+     */
+    manifest?: boolean
 }
 
 
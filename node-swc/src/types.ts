@@ -10,8 +10,47 @@ export type ParseOptions = ParserConfig & {
    * Defaults to es3.
    */
   target?: JscTarget;
+  /**
+   * If true, jsdoc comments of declarations are returned as `jsdoc`.
+   * Implies `comments`.
+   */
+  jsdoc?: boolean;
 };
 
+export type RawTokenKind =
+  | "whitespace"
+  | "lineComment"
+  | "blockComment"
+  | "shebang"
+  | "keyword"
+  | "ident"
+  | "punct"
+  | "str"
+  | "num"
+  | "bigInt"
+  | "regex"
+  | "template"
+  | "jsxName"
+  | "jsxText"
+  | "error"
+  | "unknown";
+
+/**
+ * A token or trivia returned by `tokenize()`.
+ *
+ * Concatenating `text` of all tokens produces the original source.
+ */
+export interface RawToken {
+  kind: RawTokenKind;
+  /**
+   * Offset from the start of the source, in UTF-16 code units like indices
+   * of javascript strings, so `src.slice(start, end) === text`.
+   */
+  start: number;
+  end: number;
+  text: string;
+}
+
 /**
  * Programmatic options.
  */
@@ -175,6 +214,25 @@ export interface Options extends Config {
   plugin?: Plugin;
 
   isModule?: boolean;
+
+  /**
+   * Caches outputs of `transform` and `transformFile` on disk.
+   */
+  cache?: CacheOptions;
+}
+
+export interface CacheOptions {
+  /**
+   * Directory to store cached outputs in.
+   */
+  dir: string;
+
+  /**
+   * Maximum total size of cached outputs, in bytes.
+   *
+   * Defaults to 256 MiB.
+   */
+  maxSize?: number;
 }
 
 export interface CallerOptions {
@@ -196,11 +254,26 @@ export interface Config {
    * Note: The type is string beacuse it follow rust's regex syntax.
    */
   exclude?: string | string[];
-  env?: EnvConfig;
+  /**
+   * Options of preset-env. Other keys are names of envs, and configs for
+   * them are merged into this config if the key is equal to `envName`.
+   */
+  env?: EnvConfig | { [envName: string]: Config };
   jsc?: JscConfig;
   module?: ModuleConfig;
   minify?: boolean;
 
+  /**
+   * Path to a config file this config is based on, relative to this file.
+   *
+   * Only allowed in the top-level configs of a file.
+   */
+  extends?: string;
+  /**
+   * Configs merged into this config if `test` and `exclude` match the file.
+   */
+  overrides?: Config[];
+
   /**
    * - true to generate a sourcemap for the code and include it in the result object.
    * - "inline" to generate a sourcemap and append it as a data URL to the end of the code, but not include it in the result object.
@@ -270,6 +343,52 @@ export interface JscConfig {
    * Defaults to `es3` (which enableds **all** pass).
    */
   target?: JscTarget;
+
+  /**
+   * Lint rules. All rules are disabled by default.
+   */
+  lints?: LintConfig;
+
+  experimental?: {
+    /**
+     * Wasm plugins, which are applied in order.
+     */
+    plugins?: PluginConfig[];
+  };
+}
+
+export interface PluginConfig {
+  /**
+   * Path to the `.wasm` file, relative to `cwd`.
+   */
+  path: string;
+
+  /**
+   * Passed to the plugin as json.
+   */
+  config?: any;
+
+  /**
+   * Defaults to `beforeTransforms`, which applies the plugin before all
+   * built-in passes.
+   */
+  order?: "beforeTransforms" | "afterTransforms";
+}
+
+export type LintRuleReaction = "off" | "warn" | "error";
+
+export interface LintConfig {
+  "no-unused-vars"?: LintRuleReaction;
+  "no-dupe-keys"?: LintRuleReaction;
+  "no-undef"?: LintRuleReaction;
+  "no-unreachable"?: LintRuleReaction;
+  "no-redeclare"?: LintRuleReaction;
+  "no-const-assign"?: LintRuleReaction;
+  /**
+   * Global variables used by `no-undef`, in addition to the ecmascript
+   * builtins.
+   */
+  globals?: string[];
 }
 
 export type JscTarget =
@@ -359,8 +478,21 @@ export interface EsParserConfig {
    * Defaults to `false`
    */
   importMeta?: boolean;
+  /**
+   * Defaults to `null`, which disables the pipeline operator.
+   */
+  pipelineOperator?: PipelineOperatorConfig;
 }
 
+/**
+ * The `fsharp` proposal is not supported yet, and pipelines are reported as
+ * an error.
+ */
+export type PipelineOperatorConfig =
+  | { proposal: "minimal" }
+  | { proposal: "fsharp" }
+  | { proposal: "hack"; topicToken: "%" | "^" };
+
 /**
  * Options for trasnform.
  */
@@ -386,6 +518,15 @@ export interface TransformConfig {
    * https://swc.rs/docs/configuring-swc.html#jsctransformdecoratormetadata
    */
   decoratorMetadata?: boolean;
+
+  /**
+   * Version of the decorators proposal. Ignored if `legacyDecorator` is true.
+   *
+   * `2022-03` also enables parsing of `accessor` class members.
+   *
+   * Defaults to `2018-09`.
+   */
+  decoratorVersion?: "2018-09" | "2022-03";
 }
 
 export interface ReactConfig {
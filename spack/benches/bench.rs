@@ -91,6 +91,7 @@ impl Load for Loader {
             fm,
             module,
             helpers: Default::default(),
+            side_effects: true,
         })
     }
 }
@@ -9,4 +9,23 @@ pub struct OutputConfig {
 
     #[serde(default)]
     pub name: String,
+
+    /// Template for the file name of entries.
+    ///
+    /// Supported placeholders are `[name]`, `[hash]` and `[contenthash]`.
+    /// Hashes can be truncated like `[hash:8]`.
+    ///
+    /// Bundles are emitted using their name if this is [None].
+    #[serde(default)]
+    pub filename: Option<String>,
+
+    /// Template for the file name of shared chunks. Same as
+    /// [OutputConfig::filename].
+    #[serde(default)]
+    pub chunk_filename: Option<String>,
+
+    /// If true, `manifest.json` which maps names of bundles to emitted files
+    /// is emitted.
+    #[serde(default)]
+    pub manifest: bool,
 }
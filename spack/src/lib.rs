@@ -8,4 +8,5 @@ extern crate swc_node_base;
 
 pub mod config;
 pub mod loaders;
+pub mod output;
 pub mod resolvers;
@@ -1,2 +1,3 @@
 mod json;
+mod side_effects;
 pub mod swc;
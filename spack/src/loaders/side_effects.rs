@@ -0,0 +1,172 @@
+//! Support for `sideEffects` of `package.json`.
+
+use dashmap::DashMap;
+use regex::Regex;
+use serde::Deserialize;
+use std::{
+    fs::File,
+    io::BufReader,
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+
+#[derive(Deserialize)]
+struct PackageJson {
+    #[serde(rename = "sideEffects", default)]
+    side_effects: Option<SideEffectsField>,
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum SideEffectsField {
+    Bool(bool),
+    Globs(Vec<String>),
+}
+
+#[derive(Debug)]
+enum SideEffects {
+    Bool(bool),
+    /// Files matching one of the patterns have side effects.
+    Patterns(Vec<Regex>),
+}
+
+#[derive(Debug)]
+struct Package {
+    dir: PathBuf,
+    side_effects: SideEffects,
+}
+
+/// Caches `package.json` files by directory.
+#[derive(Debug, Default)]
+pub(crate) struct SideEffectsCache {
+    packages: DashMap<PathBuf, Option<Arc<Package>>>,
+}
+
+impl SideEffectsCache {
+    /// Returns false if the closest `package.json` of `path` says that the
+    /// file does not have any side effect.
+    pub fn has_side_effects(&self, path: &Path) -> bool {
+        let pkg = match path.parent().and_then(|dir| self.find_package(dir)) {
+            Some(v) => v,
+            None => return true,
+        };
+
+        match &pkg.side_effects {
+            SideEffects::Bool(v) => *v,
+            SideEffects::Patterns(patterns) => {
+                let relative = match path.strip_prefix(&pkg.dir) {
+                    Ok(v) => v.to_string_lossy().replace('\\', "/"),
+                    Err(_) => return true,
+                };
+
+                patterns.iter().any(|p| p.is_match(&relative))
+            }
+        }
+    }
+
+    fn find_package(&self, dir: &Path) -> Option<Arc<Package>> {
+        if let Some(cached) = self.packages.get(dir) {
+            return cached.clone();
+        }
+
+        let pkg = match read_package(dir) {
+            Some(pkg) => Some(Arc::new(pkg)),
+            None => dir.parent().and_then(|parent| self.find_package(parent)),
+        };
+
+        self.packages.insert(dir.to_path_buf(), pkg.clone());
+        pkg
+    }
+}
+
+/// Returns [None] if there's no `package.json` in `dir` or it does not have
+/// `sideEffects`.
+fn read_package(dir: &Path) -> Option<Package> {
+    let path = dir.join("package.json");
+    if !path.is_file() {
+        return None;
+    }
+
+    let file = File::open(&path).ok()?;
+    let pkg: PackageJson = match serde_json::from_reader(BufReader::new(file)) {
+        Ok(v) => v,
+        Err(err) => {
+            log::warn!("failed to parse {}: {}", path.display(), err);
+            return None;
+        }
+    };
+
+    let side_effects = match pkg.side_effects? {
+        SideEffectsField::Bool(v) => SideEffects::Bool(v),
+        SideEffectsField::Globs(globs) => SideEffects::Patterns(
+            globs
+                .iter()
+                .filter_map(|glob| match glob_to_regex(glob) {
+                    Ok(v) => Some(v),
+                    Err(err) => {
+                        log::warn!("invalid glob in {}: {}: {}", path.display(), glob, err);
+                        None
+                    }
+                })
+                .collect(),
+        ),
+    };
+
+    Some(Package {
+        dir: dir.to_path_buf(),
+        side_effects,
+    })
+}
+
+/// Patterns without a slash match files in any directory, like webpack.
+fn glob_to_regex(glob: &str) -> Result<Regex, regex::Error> {
+    let glob = glob.trim_start_matches("./");
+    let mut s = String::from("^");
+    if !glob.contains('/') {
+        s.push_str("(?:.*/)?");
+    }
+
+    let mut chars = glob.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '*' if chars.peek() == Some(&'*') => {
+                chars.next();
+                if chars.peek() == Some(&'/') {
+                    chars.next();
+                    s.push_str("(?:.*/)?");
+                } else {
+                    s.push_str(".*");
+                }
+            }
+            '*' => s.push_str("[^/]*"),
+            '?' => s.push_str("[^/]"),
+            _ => s.push_str(&regex::escape(c.encode_utf8(&mut [0; 4]))),
+        }
+    }
+    s.push('$');
+
+    Regex::new(&s)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::glob_to_regex;
+
+    #[test]
+    fn glob() {
+        let re = glob_to_regex("*.css").unwrap();
+        assert!(re.is_match("a.css"));
+        assert!(re.is_match("src/a.css"));
+        assert!(!re.is_match("a.js"));
+
+        let re = glob_to_regex("./src/polyfills/**").unwrap();
+        assert!(re.is_match("src/polyfills/a.js"));
+        assert!(re.is_match("src/polyfills/b/c.js"));
+        assert!(!re.is_match("src/a.js"));
+
+        let re = glob_to_regex("src/**/side-effect.js").unwrap();
+        assert!(re.is_match("src/side-effect.js"));
+        assert!(re.is_match("src/a/side-effect.js"));
+        assert!(!re.is_match("lib/side-effect.js"));
+    }
+}
@@ -1,11 +1,11 @@
-use crate::loaders::json::load_json_as_module;
+use crate::loaders::{json::load_json_as_module, side_effects::SideEffectsCache};
 use anyhow::{bail, Context, Error};
 use helpers::Helpers;
 use std::{collections::HashMap, env, sync::Arc};
 use swc::config::{InputSourceMap, JscConfig, TransformConfig};
 use swc_atoms::JsWord;
 use swc_bundler::{Load, ModuleData};
-use swc_common::{FileName, DUMMY_SP};
+use swc_common::{comments::Comments, FileName, DUMMY_SP};
 use swc_ecma_ast::Module;
 use swc_ecma_ast::{Expr, Lit, Program, Str};
 use swc_ecma_parser::JscTarget;
@@ -22,15 +22,24 @@ use swc_ecma_visit::FoldWith;
 pub struct SwcLoader {
     compiler: Arc<swc::Compiler>,
     options: swc::config::Options,
+    side_effects: SideEffectsCache,
 }
 
 impl SwcLoader {
     pub fn new(compiler: Arc<swc::Compiler>, options: swc::config::Options) -> Self {
-        SwcLoader { compiler, options }
+        SwcLoader {
+            compiler,
+            options,
+            side_effects: Default::default(),
+        }
     }
 }
 
 impl Load for SwcLoader {
+    fn comments(&self) -> Option<&dyn Comments> {
+        Some(self.compiler.comments())
+    }
+
     fn load(&self, name: &FileName) -> Result<ModuleData, Error> {
         log::debug!("JsLoader.load({})", name);
         let helpers = Helpers::new(false);
@@ -50,6 +59,7 @@ impl Load for SwcLoader {
                         shebang: Default::default(),
                     },
                     helpers: Default::default(),
+                    side_effects: true,
                 });
             }
             _ => {}
@@ -74,6 +84,7 @@ impl Load for SwcLoader {
                             fm: fm.clone(),
                             module,
                             helpers: Default::default(),
+                            side_effects: false,
                         });
                     }
                 }
@@ -118,6 +129,7 @@ impl Load for SwcLoader {
                                             optimizer: None,
                                             legacy_decorator: c.legacy_decorator,
                                             decorator_metadata: c.decorator_metadata,
+                                            decorator_version: c.decorator_version,
                                             hidden: Default::default(),
                                         })
                                     } else {
@@ -205,6 +217,10 @@ impl Load for SwcLoader {
                 fm,
                 module,
                 helpers,
+                side_effects: match name {
+                    FileName::Real(path) => self.side_effects.has_side_effects(path),
+                    _ => true,
+                },
             }),
             _ => unreachable!(),
         }
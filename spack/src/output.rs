@@ -0,0 +1,576 @@
+//! File names of emitted bundles.
+
+use crate::config::OutputConfig;
+use anyhow::{bail, Context, Error};
+use crc::{crc64, Hasher64};
+use std::{
+    collections::{BTreeMap, HashMap, HashSet},
+    path::Path,
+};
+use swc_bundler::{calc_hash, Bundle, BundleKind, Resolve};
+use swc_common::{sync::Lrc, FileName, SourceMap, DUMMY_SP};
+use swc_ecma_ast::*;
+use swc_ecma_visit::{
+    noop_visit_mut_type, noop_visit_type, Node, Visit, VisitMut, VisitMutWith, VisitWith,
+};
+
+/// Name of the manifest file.
+pub const MANIFEST_FILE_NAME: &str = "manifest.json";
+
+#[derive(Debug)]
+pub struct Output {
+    /// Name of the bundle.
+    pub name: String,
+    /// Name of the file to emit.
+    pub file_name: String,
+    pub is_entry: bool,
+    pub module: Module,
+}
+
+/// Renders file names of `bundles` using templates in `config`, and changes
+/// import paths to use the new names.
+///
+/// `[contenthash]` is the hash of the bundle itself, while `[hash]` also
+/// depends on the file names of imported chunks. So `[hash]` of a bundle is
+/// changed if one of its dependencies is changed. Bundles which import each
+/// other share the hash of their contents.
+///
+/// `resolver` is used to find the bundles imported by a bundle.
+pub fn name_outputs(
+    cm: Lrc<SourceMap>,
+    config: &OutputConfig,
+    entries: &HashSet<String>,
+    resolver: &dyn Resolve,
+    bundles: Vec<Bundle>,
+) -> Result<Vec<Output>, Error> {
+    let modules = bundles
+        .into_iter()
+        .map(|bundle| match bundle.kind {
+            BundleKind::Named { name } | BundleKind::Lib { name } => {
+                Ok((name, bundle.path, bundle.module))
+            }
+            BundleKind::Dynamic => bail!("unimplemented: dynamic code splitting"),
+        })
+        .collect::<Result<Vec<_>, Error>>()?;
+
+    name_modules(cm, config, entries, resolver, modules)
+}
+
+/// [name_outputs] for `(name, path, module)` tuples.
+fn name_modules(
+    cm: Lrc<SourceMap>,
+    config: &OutputConfig,
+    entries: &HashSet<String>,
+    resolver: &dyn Resolve,
+    modules: Vec<(String, FileName, Module)>,
+) -> Result<Vec<Output>, Error> {
+    let mut outputs = vec![];
+    let mut paths = vec![];
+    let mut content_hashes = vec![];
+
+    for (name, path, module) in modules {
+        content_hashes.push(
+            calc_hash(cm.clone(), &module)
+                .with_context(|| format!("failed to calculate hash of {}", name))?,
+        );
+        paths.push(path);
+        outputs.push(Output {
+            is_entry: entries.contains(&name),
+            file_name: name.clone(),
+            name,
+            module,
+        });
+    }
+
+    // Import sources of each bundle, with the bundles they refer to.
+    let imports = outputs
+        .iter()
+        .zip(&paths)
+        .map(|(output, path)| {
+            let mut v = ImportFinder::default();
+            output
+                .module
+                .visit_with(&Invalid { span: DUMMY_SP }, &mut v);
+
+            v.srcs
+                .into_iter()
+                .filter_map(|src| {
+                    let resolved = resolver.resolve(path, &src).ok()?;
+                    let dep = paths.iter().position(|path| *path == resolved)?;
+                    Some((src, dep))
+                })
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+
+    let mut deps = imports
+        .iter()
+        .map(|imports| imports.iter().map(|&(_, dep)| dep).collect::<Vec<_>>())
+        .collect::<Vec<_>>();
+    for deps in &mut deps {
+        deps.sort_by(|&a, &b| outputs[a].name.cmp(&outputs[b].name));
+        deps.dedup();
+    }
+
+    let mut file_names = vec![None; outputs.len()];
+    for scc in sccs(&deps) {
+        render(
+            config,
+            &outputs,
+            &content_hashes,
+            &deps,
+            &mut file_names,
+            scc,
+        )?;
+    }
+    let file_names = file_names
+        .into_iter()
+        .map(Option::unwrap)
+        .collect::<Vec<_>>();
+
+    Ok(outputs
+        .into_iter()
+        .zip(imports)
+        .enumerate()
+        .map(|(idx, (mut output, imports))| {
+            let renamed = imports
+                .into_iter()
+                .map(|(src, dep)| (src, file_names[dep].clone()))
+                .collect();
+            output
+                .module
+                .visit_mut_with(&mut Renamer { renamed: &renamed });
+            Output {
+                file_name: file_names[idx].clone(),
+                ..output
+            }
+        })
+        .collect())
+}
+
+/// Creates the content of `manifest.json`.
+pub fn manifest(outputs: &[Output]) -> Result<String, Error> {
+    let map = outputs
+        .iter()
+        .map(|output| (&*output.name, &*output.file_name))
+        .collect::<BTreeMap<_, _>>();
+
+    serde_json::to_string_pretty(&map).context("failed to serialize manifest")
+}
+
+/// Returns strongly connected components of the dependency graph, where
+/// dependencies come before dependants.
+fn sccs(deps: &[Vec<usize>]) -> Vec<Vec<usize>> {
+    struct Tarjan<'a> {
+        deps: &'a [Vec<usize>],
+        index: Vec<Option<usize>>,
+        low_link: Vec<usize>,
+        stack: Vec<usize>,
+        on_stack: Vec<bool>,
+        next: usize,
+        sccs: Vec<Vec<usize>>,
+    }
+
+    impl Tarjan<'_> {
+        fn visit(&mut self, v: usize) {
+            self.index[v] = Some(self.next);
+            self.low_link[v] = self.next;
+            self.next += 1;
+            self.stack.push(v);
+            self.on_stack[v] = true;
+
+            for &w in &self.deps[v] {
+                match self.index[w] {
+                    None => {
+                        self.visit(w);
+                        self.low_link[v] = self.low_link[v].min(self.low_link[w]);
+                    }
+                    Some(index) if self.on_stack[w] => {
+                        self.low_link[v] = self.low_link[v].min(index);
+                    }
+                    Some(..) => {}
+                }
+            }
+
+            if Some(self.low_link[v]) == self.index[v] {
+                let mut scc = vec![];
+                loop {
+                    let w = self.stack.pop().unwrap();
+                    self.on_stack[w] = false;
+                    scc.push(w);
+                    if w == v {
+                        break;
+                    }
+                }
+                self.sccs.push(scc);
+            }
+        }
+    }
+
+    let mut t = Tarjan {
+        deps,
+        index: vec![None; deps.len()],
+        low_link: vec![0; deps.len()],
+        stack: vec![],
+        on_stack: vec![false; deps.len()],
+        next: 0,
+        sccs: vec![],
+    };
+    for v in 0..deps.len() {
+        if t.index[v].is_none() {
+            t.visit(v);
+        }
+    }
+    t.sccs
+}
+
+/// Renders file names of bundles in `scc`. File names of their dependencies
+/// outside of `scc` should be rendered already.
+fn render(
+    config: &OutputConfig,
+    outputs: &[Output],
+    content_hashes: &[String],
+    deps: &[Vec<usize>],
+    file_names: &mut [Option<String>],
+    mut scc: Vec<usize>,
+) -> Result<(), Error> {
+    scc.sort_by(|&a, &b| outputs[a].name.cmp(&outputs[b].name));
+
+    // Hash of the component, which does not depend on the order of traversal.
+    let mut unit = crc64::Digest::new(crc64::ECMA);
+    for &idx in &scc {
+        unit.write(outputs[idx].name.as_bytes());
+        unit.write(content_hashes[idx].as_bytes());
+        for &dep in &deps[idx] {
+            if let Some(dep_name) = &file_names[dep] {
+                unit.write(dep_name.as_bytes());
+            }
+        }
+    }
+    let unit = unit.sum64();
+
+    for &idx in &scc {
+        let mut digest = crc64::Digest::new(crc64::ECMA);
+        digest.write(&unit.to_le_bytes());
+        digest.write(content_hashes[idx].as_bytes());
+        let hash = radix_fmt::radix(digest.sum64(), 36).to_string();
+
+        let output = &outputs[idx];
+        let template = if output.is_entry {
+            &config.filename
+        } else {
+            &config.chunk_filename
+        };
+        let file_name = match template {
+            Some(template) => render_file_name(template, &output.name, &hash, &content_hashes[idx])
+                .with_context(|| format!("failed to render file name of {}", output.name))?,
+            None => output.name.clone(),
+        };
+
+        file_names[idx] = Some(file_name);
+    }
+
+    Ok(())
+}
+
+/// `[name]` is the name of the bundle without extension.
+fn render_file_name(
+    template: &str,
+    name: &str,
+    hash: &str,
+    content_hash: &str,
+) -> Result<String, Error> {
+    let stem = Path::new(name)
+        .file_stem()
+        .map(|s| s.to_string_lossy().to_string())
+        .unwrap_or_else(|| name.to_string());
+
+    let mut buf = String::with_capacity(template.len());
+    let mut rest = template;
+    while let Some(start) = rest.find('[') {
+        buf.push_str(&rest[..start]);
+        let end = match rest[start..].find(']') {
+            Some(end) => start + end,
+            None => bail!("unclosed placeholder in `{}`", template),
+        };
+
+        let placeholder = &rest[start + 1..end];
+        let (key, len) = match placeholder.find(':') {
+            Some(idx) => (
+                &placeholder[..idx],
+                Some(
+                    placeholder[idx + 1..]
+                        .parse::<usize>()
+                        .with_context(|| format!("invalid length in `{}`", template))?,
+                ),
+            ),
+            None => (placeholder, None),
+        };
+
+        let value = match key {
+            "name" => &*stem,
+            "hash" => hash,
+            "contenthash" => content_hash,
+            _ => bail!("unknown placeholder `[{}]` in `{}`", key, template),
+        };
+        match len {
+            Some(len) => buf.push_str(&value[..len.min(value.len())]),
+            None => buf.push_str(value),
+        }
+
+        rest = &rest[end + 1..];
+    }
+    buf.push_str(rest);
+
+    Ok(buf)
+}
+
+#[derive(Default)]
+struct ImportFinder {
+    srcs: Vec<String>,
+}
+
+impl Visit for ImportFinder {
+    noop_visit_type!();
+
+    fn visit_module_decl(&mut self, decl: &ModuleDecl, _: &dyn Node) {
+        match decl {
+            ModuleDecl::Import(ImportDecl { src, .. })
+            | ModuleDecl::ExportAll(ExportAll { src, .. })
+            | ModuleDecl::ExportNamed(NamedExport { src: Some(src), .. }) => {
+                self.srcs.push(src.value.to_string());
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Changes import paths to the rendered file names.
+struct Renamer<'a> {
+    /// Import sources to the file names of the bundles they refer to.
+    renamed: &'a HashMap<String, String>,
+}
+
+impl Renamer<'_> {
+    fn rename(&self, src: &mut Str) {
+        if let Some(file_name) = self.renamed.get(&*src.value) {
+            let dir = match src.value.rfind('/') {
+                Some(idx) => &src.value[..idx + 1],
+                None => "",
+            };
+            src.value = format!("{}{}", dir, file_name).into();
+        }
+    }
+}
+
+impl VisitMut for Renamer<'_> {
+    noop_visit_mut_type!();
+
+    fn visit_mut_module_decl(&mut self, decl: &mut ModuleDecl) {
+        match decl {
+            ModuleDecl::Import(ImportDecl { src, .. })
+            | ModuleDecl::ExportAll(ExportAll { src, .. })
+            | ModuleDecl::ExportNamed(NamedExport { src: Some(src), .. }) => self.rename(src),
+            _ => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::{Component, PathBuf};
+    use swc_ecma_parser::{lexer::Lexer, Parser, StringInput};
+
+    /// Resolves relative paths without accessing the file system.
+    struct PathResolver;
+
+    impl Resolve for PathResolver {
+        fn resolve(&self, base: &FileName, specifier: &str) -> Result<FileName, Error> {
+            let mut path = match base {
+                FileName::Real(base) => base.parent().unwrap().to_path_buf(),
+                _ => bail!("cannot resolve {} from {}", specifier, base),
+            };
+            for c in Path::new(specifier).components() {
+                match c {
+                    Component::CurDir => {}
+                    Component::ParentDir => {
+                        path.pop();
+                    }
+                    c => path.push(c),
+                }
+            }
+            Ok(FileName::Real(path))
+        }
+    }
+
+    fn config() -> OutputConfig {
+        OutputConfig {
+            path: PathBuf::from("dist"),
+            name: Default::default(),
+            filename: Some("[name].[hash].js".into()),
+            chunk_filename: Some("chunk-[name].[hash].js".into()),
+            manifest: true,
+        }
+    }
+
+    /// Names `(name, path, source)` bundles. `main.js` is the entry.
+    fn name_all(modules: &[(&str, &str, &str)]) -> Vec<Output> {
+        testing::run_test(false, |cm, _| {
+            let modules = modules
+                .iter()
+                .map(|&(name, path, src)| {
+                    (
+                        name.to_string(),
+                        FileName::Real(path.into()),
+                        parse(&cm, src),
+                    )
+                })
+                .collect();
+            let entries = vec!["main.js".to_string()].into_iter().collect();
+
+            Ok(name_modules(cm.clone(), &config(), &entries, &PathResolver, modules).unwrap())
+        })
+        .unwrap()
+    }
+
+    /// Names `main.js`, which imports `dep.js`, and `dep.js`.
+    fn name(dep: &str) -> Vec<Output> {
+        name_all(&[
+            (
+                "main.js",
+                "/src/main.js",
+                "import { a } from './dep.js'; console.log(a);",
+            ),
+            ("dep.js", "/src/dep.js", dep),
+        ])
+    }
+
+    fn srcs(outputs: &[Output], name: &str) -> Vec<String> {
+        let output = outputs.iter().find(|o| o.name == name).unwrap();
+        let mut v = ImportFinder::default();
+        output
+            .module
+            .visit_with(&Invalid { span: DUMMY_SP }, &mut v);
+        v.srcs
+    }
+
+    fn parse(cm: &SourceMap, src: &str) -> Module {
+        let fm = cm.new_source_file(FileName::Anon, src.into());
+        let lexer = Lexer::new(
+            Default::default(),
+            Default::default(),
+            StringInput::from(&*fm),
+            None,
+        );
+        Parser::new_from(lexer).parse_module().unwrap()
+    }
+
+    fn file_name<'a>(outputs: &'a [Output], name: &str) -> &'a str {
+        &outputs.iter().find(|o| o.name == name).unwrap().file_name
+    }
+
+    #[test]
+    fn hash_depends_on_file_names_of_deps() {
+        let outputs = name("export const a = 1;");
+        let same = name("export const a = 1;");
+        let changed = name("export const a = 2;");
+
+        assert_eq!(file_name(&outputs, "main.js"), file_name(&same, "main.js"));
+        assert_ne!(file_name(&outputs, "dep.js"), file_name(&changed, "dep.js"));
+        assert_ne!(
+            file_name(&outputs, "main.js"),
+            file_name(&changed, "main.js"),
+            "main.js should be renamed because the file name of dep.js is changed"
+        );
+    }
+
+    #[test]
+    fn imports_use_rendered_file_names() {
+        let outputs = name("export const a = 1;");
+
+        let dep = file_name(&outputs, "dep.js");
+        assert!(dep.starts_with("chunk-dep."), "{}", dep);
+
+        let main = outputs.iter().find(|o| o.name == "main.js").unwrap();
+        assert!(main.is_entry);
+        assert_eq!(srcs(&outputs, "main.js"), vec![format!("./{}", dep)]);
+    }
+
+    #[test]
+    fn imports_are_matched_by_path() {
+        let outputs = name_all(&[
+            (
+                "main.js",
+                "/src/main.js",
+                "import './b/index.js'; import './a/index.js';",
+            ),
+            ("a.js", "/src/a/index.js", "console.log('a');"),
+            ("b.js", "/src/b/index.js", "console.log('b');"),
+        ]);
+
+        assert_eq!(
+            srcs(&outputs, "main.js"),
+            vec![
+                format!("./b/{}", file_name(&outputs, "b.js")),
+                format!("./a/{}", file_name(&outputs, "a.js")),
+            ]
+        );
+    }
+
+    #[test]
+    fn hashes_of_cycles_are_deterministic() {
+        let a = ("a.js", "/src/a.js", "import './b.js'; console.log('a');");
+        let b = ("b.js", "/src/b.js", "import './a.js'; console.log('b');");
+        let main = ("main.js", "/src/main.js", "import './a.js';");
+
+        let first = name_all(&[main, a, b]);
+        let second = name_all(&[b, a, main]);
+        for name in &["main.js", "a.js", "b.js"] {
+            assert_eq!(file_name(&first, name), file_name(&second, name));
+        }
+        assert_ne!(file_name(&first, "a.js"), file_name(&first, "b.js"));
+
+        let changed = name_all(&[
+            main,
+            a,
+            ("b.js", "/src/b.js", "import './a.js'; console.log('c');"),
+        ]);
+        assert_ne!(
+            file_name(&first, "a.js"),
+            file_name(&changed, "a.js"),
+            "a.js should be renamed because b.js in the same cycle is changed"
+        );
+    }
+
+    #[test]
+    fn manifest_maps_names_to_file_names() {
+        let outputs = name("export const a = 1;");
+
+        let map: BTreeMap<String, String> =
+            serde_json::from_str(&manifest(&outputs).unwrap()).unwrap();
+        assert_eq!(map.len(), 2);
+        for output in &outputs {
+            assert_ne!(output.name, output.file_name);
+            assert_eq!(map[&output.name], output.file_name);
+        }
+    }
+
+    #[test]
+    fn placeholders() {
+        assert_eq!(
+            render_file_name("[name].js", "main.js", "abc", "def").unwrap(),
+            "main.js"
+        );
+        assert_eq!(
+            render_file_name("[name]-[hash].js", "main", "abc", "def").unwrap(),
+            "main-abc.js"
+        );
+        assert_eq!(
+            render_file_name("[contenthash:2]/[name].[hash:1].js", "main", "abc", "def").unwrap(),
+            "de/main.a.js"
+        );
+        assert!(render_file_name("[id].js", "main", "abc", "def").is_err());
+        assert!(render_file_name("[name.js", "main", "abc", "def").is_err());
+    }
+}
@@ -0,0 +1,307 @@
+//! Persistent cache for [Compiler::process_js_file](crate::Compiler).
+//!
+//! Entries are stored as json files named after the hash of the input, and
+//! written atomically so the cache can be shared by threads and processes.
+
+use crate::{
+    config::{Config, InputSourceMap, JscConfig, ModuleConfig, Options, SourceMapsConfig},
+    TransformOutput,
+};
+use anyhow::{Context, Error};
+use filetime::FileTime;
+use serde::{Deserialize, Serialize, Serializer};
+use sha2::{Digest, Sha256};
+use std::{
+    fmt::Debug,
+    fs, io,
+    path::PathBuf,
+    process,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Mutex,
+    },
+    time::{SystemTime, UNIX_EPOCH},
+};
+use swc_common::SourceFile;
+
+/// Configures the transform cache.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "camelCase")]
+pub struct CacheConfig {
+    /// Directory to store cached outputs in.
+    pub dir: PathBuf,
+
+    /// Maximum total size of cached outputs, in bytes.
+    #[serde(default = "default_max_size")]
+    pub max_size: u64,
+}
+
+fn default_max_size() -> u64 {
+    256 * 1024 * 1024
+}
+
+/// Hash of everything which affects the output of `process_js_file`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CacheKey(String);
+
+/// Inputs of the transform except the source text.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct KeyData<'a> {
+    version: &'static str,
+    filename: String,
+    config: ResolvedConfig<'a>,
+    skip_helper_injection: bool,
+    disable_hygiene: bool,
+    disable_fixer: bool,
+    is_module: bool,
+    source_maps: &'a Option<SourceMapsConfig>,
+    input_source_map: &'a InputSourceMap,
+    /// Sizes and modification times of plugins.
+    plugins: Vec<Option<(u64, u64, u32)>>,
+}
+
+/// Fields of [Config] used by [Options::build].
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ResolvedConfig<'a> {
+    /// `swc_ecma_preset_env::Config` does not implement `Serialize`.
+    ///
+    /// Its debug output is stable because it only uses `FxHashMap`.
+    #[serde(serialize_with = "serialize_debug")]
+    env: &'a Option<swc_ecma_preset_env::Config>,
+    /// Browser versions resolved from `env`, which may depend on browserslist
+    /// config files.
+    targets: Option<String>,
+    jsc: &'a JscConfig,
+    module: &'a Option<ModuleConfig>,
+    minify: Option<bool>,
+    source_maps: &'a Option<SourceMapsConfig>,
+}
+
+fn serialize_debug<T: Debug, S: Serializer>(v: &T, s: S) -> Result<S::Ok, S::Error> {
+    s.serialize_str(&format!("{:?}", v))
+}
+
+impl CacheKey {
+    /// `config` should be the result of [Compiler::read_config], merged with
+    /// `opts.config`.
+    ///
+    /// [Compiler::read_config]: crate::Compiler::read_config
+    pub fn new(
+        fm: &SourceFile,
+        opts: &Options,
+        config: &Config,
+        orig: Option<&sourcemap::SourceMap>,
+    ) -> Result<Self, Error> {
+        let data = KeyData {
+            version: env!("CARGO_PKG_VERSION"),
+            filename: fm.name.to_string(),
+            config: ResolvedConfig {
+                env: &config.env,
+                targets: config.env.as_ref().and_then(|env| {
+                    env.resolved_targets()
+                        .ok()
+                        .map(|versions| format!("{:?}", versions))
+                }),
+                jsc: &config.jsc,
+                module: &config.module,
+                minify: config.minify,
+                source_maps: &config.source_maps,
+            },
+            skip_helper_injection: opts.skip_helper_injection,
+            disable_hygiene: opts.disable_hygiene,
+            disable_fixer: opts.disable_fixer,
+            is_module: opts.is_module,
+            source_maps: &opts.source_maps,
+            input_source_map: &opts.input_source_map,
+            plugins: config
+                .jsc
+                .experimental
+                .plugins
+                .iter()
+                .flatten()
+                .map(|plugin| {
+                    let meta = fs::metadata(opts.plugin_dir().join(&plugin.path)).ok()?;
+                    let modified = meta.modified().ok()?.duration_since(UNIX_EPOCH).ok()?;
+                    Some((meta.len(), modified.as_secs(), modified.subsec_nanos()))
+                })
+                .collect(),
+        };
+        // Converting to a `Value` first sorts the keys of hash maps.
+        let data = serde_json::to_value(&data)
+            .and_then(|v| serde_json::to_vec(&v))
+            .context("failed to serialize config for the transform cache")?;
+
+        let mut orig_map = vec![];
+        if let Some(orig) = orig {
+            orig.to_writer(&mut orig_map)
+                .context("failed to serialize input source map for the transform cache")?;
+        }
+
+        // The hash should not depend on the version of rustc, because the cache
+        // is stored on disk.
+        let mut h = Sha256::new();
+        for bytes in &[&*data, fm.src.as_bytes(), &*orig_map] {
+            h.update((bytes.len() as u64).to_le_bytes());
+            h.update(bytes);
+        }
+
+        Ok(CacheKey(format!("{:x}", h.finalize())))
+    }
+}
+
+/// Stores outputs of `process_js_file` in a directory.
+///
+/// Entries are evicted from the least recently used one when the total size
+/// exceeds `max_size`. Modification times of entries are updated when they
+/// are read.
+#[derive(Debug)]
+pub struct TransformCache {
+    dir: PathBuf,
+    max_size: u64,
+    /// Total size of entries. [None] if not computed yet.
+    size: Mutex<Option<u64>>,
+}
+
+/// Used to make names of temporary files unique in a process.
+static TMP_ID: AtomicUsize = AtomicUsize::new(0);
+
+impl TransformCache {
+    pub fn new(config: &CacheConfig) -> Self {
+        TransformCache {
+            dir: config.dir.clone(),
+            max_size: config.max_size,
+            size: Default::default(),
+        }
+    }
+
+    fn path(&self, key: &CacheKey) -> PathBuf {
+        self.dir.join(format!("{}.json", key.0))
+    }
+
+    /// Returns [None] if there's no entry for `key`, or if the entry is
+    /// corrupted.
+    pub fn get(&self, key: &CacheKey) -> Option<TransformOutput> {
+        let path = self.path(key);
+        let data = match fs::read(&path) {
+            Ok(v) => v,
+            Err(err) => {
+                if err.kind() != io::ErrorKind::NotFound {
+                    log::debug!("failed to read cache entry at {}: {}", path.display(), err);
+                }
+                return None;
+            }
+        };
+
+        match serde_json::from_slice(&data) {
+            Ok(v) => {
+                // Used by `evict`.
+                if let Err(err) = filetime::set_file_mtime(&path, FileTime::now()) {
+                    log::debug!("failed to touch cache entry at {}: {}", path.display(), err);
+                }
+                Some(v)
+            }
+            Err(err) => {
+                log::warn!(
+                    "removing invalid cache entry at {}: {}",
+                    path.display(),
+                    err
+                );
+                let _ = fs::remove_file(&path);
+                None
+            }
+        }
+    }
+
+    /// Stores `output`, and evicts old entries if the cache is too large.
+    pub fn put(&self, key: &CacheKey, output: &TransformOutput) -> Result<(), Error> {
+        fs::create_dir_all(&self.dir).with_context(|| {
+            format!("failed to create cache directory at {}", self.dir.display())
+        })?;
+
+        let data = serde_json::to_vec(output).context("failed to serialize cache entry")?;
+        let path = self.path(key);
+
+        // Renaming is atomic, so readers never see a partially written entry.
+        let tmp = self.dir.join(format!(
+            "{}.{}.{}.tmp",
+            key.0,
+            process::id(),
+            TMP_ID.fetch_add(1, Ordering::Relaxed)
+        ));
+        fs::write(&tmp, &data)
+            .and_then(|_| fs::rename(&tmp, &path))
+            .map_err(|err| {
+                let _ = fs::remove_file(&tmp);
+                err
+            })
+            .with_context(|| format!("failed to write cache entry at {}", path.display()))?;
+
+        let mut size = self.size.lock().unwrap();
+        let total = match *size {
+            // This may count an overwritten entry twice, which only makes
+            // eviction rescan the directory earlier.
+            Some(size) => size + data.len() as u64,
+            None => self.evict(u64::MAX)?,
+        };
+        *size = Some(if total > self.max_size {
+            // Evict more than required so that we don't scan the directory on
+            // every write.
+            self.evict(self.max_size / 4 * 3)?
+        } else {
+            total
+        });
+
+        Ok(())
+    }
+
+    /// Removes the least recently used entries until the total size is not
+    /// greater than `limit`, and returns the total size.
+    ///
+    /// Other processes may remove entries at the same time.
+    fn evict(&self, limit: u64) -> Result<u64, Error> {
+        let mut entries = vec![];
+        let dir = fs::read_dir(&self.dir)
+            .with_context(|| format!("failed to read cache directory at {}", self.dir.display()))?;
+        for entry in dir {
+            let entry = match entry {
+                Ok(v) => v,
+                Err(..) => continue,
+            };
+            let path = entry.path();
+            if path.extension().map(|ext| ext != "json").unwrap_or(true) {
+                continue;
+            }
+            let meta = match entry.metadata() {
+                Ok(v) => v,
+                Err(..) => continue,
+            };
+            let modified = meta.modified().unwrap_or(SystemTime::UNIX_EPOCH);
+            entries.push((modified, meta.len(), path));
+        }
+
+        let mut total: u64 = entries.iter().map(|e| e.1).sum();
+        if total <= limit {
+            return Ok(total);
+        }
+
+        entries.sort_by(|a, b| a.0.cmp(&b.0));
+        for (_, len, path) in entries {
+            if total <= limit {
+                break;
+            }
+            match fs::remove_file(&path) {
+                Ok(()) => {}
+                Err(err) if err.kind() == io::ErrorKind::NotFound => {}
+                Err(err) => {
+                    log::debug!("failed to evict cache entry at {}: {}", path.display(), err);
+                    continue;
+                }
+            }
+            total -= len;
+        }
+
+        Ok(total)
+    }
+}
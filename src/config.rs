@@ -1,13 +1,13 @@
-use crate::builder::PassBuilder;
+use crate::{builder::PassBuilder, cache::CacheConfig, plugin::Plugins};
 use anyhow::{bail, Context, Error};
 use dashmap::DashMap;
 use either::Either;
 use once_cell::sync::Lazy;
 use regex::Regex;
-use serde::{Deserialize, Serialize};
+use serde::{de::Error as _, Deserialize, Deserializer, Serialize};
 use std::{
     collections::{HashMap, HashSet},
-    env,
+    env, mem,
     path::{Path, PathBuf},
     sync::Arc,
     usize,
@@ -15,20 +15,26 @@ use std::{
 use swc_atoms::JsWord;
 pub use swc_common::chain;
 use swc_common::{comments::Comments, errors::Handler, FileName, Mark, SourceMap};
-use swc_ecma_ast::{Expr, ExprStmt, ModuleItem, Stmt};
+use swc_ecma_ast::{Expr, ExprStmt, Module, ModuleItem, Script, Stmt};
 use swc_ecma_ext_transforms::jest;
+use swc_ecma_lints::{
+    config::{LintConfig, LintRuleReaction},
+    Rule,
+};
 pub use swc_ecma_parser::JscTarget;
-use swc_ecma_parser::{lexer::Lexer, Parser, StringInput, Syntax, TsConfig};
+use swc_ecma_parser::{lexer::Lexer, EsConfig, Parser, StringInput, Syntax, TsConfig};
 use swc_ecma_transforms::hygiene;
 use swc_ecma_transforms::{
     modules,
     optimization::const_modules,
     optimization::{inline_globals, json_parse, simplifier},
     pass::{noop, Optional},
-    proposals::{decorators, export_default_from},
-    react, resolver_with_mark, typescript,
+    proposals::{decorators, export_default_from, pipeline_operator},
+    react,
+    resolver::ts_resolver,
+    resolver_with_mark, typescript,
 };
-use swc_ecma_visit::Fold;
+use swc_ecma_visit::{noop_fold_type, Fold, FoldWith};
 
 #[cfg(test)]
 mod tests;
@@ -46,6 +52,11 @@ pub struct ParseOptions {
 
     #[serde(default)]
     pub target: JscTarget,
+
+    /// If true, jsdoc comments of declarations are parsed and returned with
+    /// the program. This implies `comments`.
+    #[serde(default)]
+    pub jsdoc: bool,
 }
 
 #[cfg(target_arch = "wasm32")]
@@ -119,12 +130,30 @@ pub struct Options {
 
     #[serde(default = "default_is_module")]
     pub is_module: bool,
+
+    /// Caches the output of [Compiler::process_js_file] on disk.
+    ///
+    /// [Compiler::process_js_file]: crate::Compiler::process_js_file
+    #[serde(default)]
+    pub cache: Option<CacheConfig>,
 }
 
 impl Options {
     pub fn codegen_target(&self) -> Option<JscTarget> {
         self.config.jsc.target
     }
+
+    /// Directory which paths of plugins are relative to.
+    pub(crate) fn plugin_dir(&self) -> &Path {
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            &self.cwd
+        }
+        #[cfg(target_arch = "wasm32")]
+        {
+            Path::new("")
+        }
+    }
 }
 
 fn default_is_module() -> bool {
@@ -189,15 +218,25 @@ impl Options {
             target,
             loose,
             keep_class_names,
+            lints,
+            experimental,
         } = config.jsc;
         let target = target.unwrap_or_default();
 
-        let syntax = syntax.unwrap_or_default();
+        let mut syntax = syntax.unwrap_or_default();
         let mut transform = transform.unwrap_or_default();
 
         if syntax.typescript() {
             transform.legacy_decorator = true;
         }
+        if !transform.legacy_decorator
+            && transform.decorator_version == decorators::DecoratorVersion::V202203
+        {
+            match &mut syntax {
+                Syntax::Es(EsConfig { auto_accessors, .. })
+                | Syntax::Typescript(TsConfig { auto_accessors, .. }) => *auto_accessors = true,
+            }
+        }
         let optimizer = transform.optimizer;
         let enable_optimizer = optimizer.is_some();
 
@@ -228,11 +267,23 @@ impl Options {
             pass
         };
 
+        let (before_plugins, after_plugins): (Vec<_>, Vec<_>) = experimental
+            .plugins
+            .unwrap_or_default()
+            .into_iter()
+            .partition(|plugin| plugin.order == PluginOrder::BeforeTransforms);
+
         let root_mark = self
             .global_mark
             .unwrap_or_else(|| Mark::fresh(Mark::root()));
 
         let pass = chain!(
+            // Lints should see the program written by the user.
+            Lints {
+                config: lints,
+                typescript: syntax.typescript(),
+            },
+            Plugins::new(self.plugin_dir(), before_plugins),
             // handle jsx
             Optional::new(
                 react::react(cm.clone(), comments, transform.react),
@@ -243,6 +294,7 @@ impl Options {
                 decorators(decorators::Config {
                     legacy: transform.legacy_decorator,
                     emit_metadata: transform.decorator_metadata,
+                    decorator_version: transform.decorator_version,
                 }),
                 syntax.decorators()
             ),
@@ -251,7 +303,8 @@ impl Options {
             const_modules,
             optimization,
             Optional::new(export_default_from(), syntax.export_default_from()),
-            Optional::new(simplifier(Default::default()), enable_optimizer),
+            Optional::new(pipeline_operator(), syntax.pipeline_operator().is_some()),
+            Optional::new(simplifier(Default::default(), comments), enable_optimizer),
             json_parse_pass
         );
 
@@ -267,7 +320,11 @@ impl Options {
             .preset_env(config.env)
             .finalize(syntax, config.module, comments);
 
-        let pass = chain!(pass, Optional::new(jest::jest(), transform.hidden.jest));
+        let pass = chain!(
+            pass,
+            Optional::new(jest::jest(), transform.hidden.jest),
+            Plugins::new(self.plugin_dir(), after_plugins)
+        );
 
         BuiltConfig {
             minify: config.minify.unwrap_or(false),
@@ -286,6 +343,56 @@ impl Options {
     }
 }
 
+/// Runs lint rules on a resolved copy of the program.
+///
+/// The copy is resolved separately, because the resolver is applied to the
+/// program only after typescript types are stripped.
+struct Lints {
+    config: Option<LintConfig>,
+    typescript: bool,
+}
+
+impl Lints {
+    fn rules(&self, top_level_mark: Mark) -> Vec<Box<dyn Rule>> {
+        self.config
+            .as_ref()
+            .map(|config| swc_ecma_lints::rules::all(config, top_level_mark))
+            .unwrap_or_default()
+    }
+
+    fn resolver(&self, top_level_mark: Mark) -> impl Fold {
+        if self.typescript {
+            Either::Left(ts_resolver(top_level_mark))
+        } else {
+            Either::Right(resolver_with_mark(top_level_mark))
+        }
+    }
+}
+
+impl Fold for Lints {
+    noop_fold_type!();
+
+    fn fold_module(&mut self, module: Module) -> Module {
+        let top_level_mark = Mark::fresh(Mark::root());
+        let mut rules = self.rules(top_level_mark);
+        if !rules.is_empty() {
+            rules.lint_module(&module.clone().fold_with(&mut self.resolver(top_level_mark)));
+        }
+
+        module
+    }
+
+    fn fold_script(&mut self, script: Script) -> Script {
+        let top_level_mark = Mark::fresh(Mark::root());
+        let mut rules = self.rules(top_level_mark);
+        if !rules.is_empty() {
+            rules.lint_script(&script.clone().fold_with(&mut self.resolver(top_level_mark)));
+        }
+
+        script
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 pub enum RootMode {
     #[serde(rename = "root")]
@@ -351,10 +458,14 @@ impl Default for Rc {
                     target: Default::default(),
                     loose: false,
                     keep_class_names: false,
+                    lints: None,
                 },
                 module: None,
                 minify: None,
                 source_maps: None,
+                extends: None,
+                envs: Default::default(),
+                overrides: vec![],
             },
             Config {
                 env: None,
@@ -370,10 +481,14 @@ impl Default for Rc {
                     target: Default::default(),
                     loose: false,
                     keep_class_names: false,
+                    lints: None,
                 },
                 module: None,
                 minify: None,
                 source_maps: None,
+                extends: None,
+                envs: Default::default(),
+                overrides: vec![],
             },
             Config {
                 env: None,
@@ -389,10 +504,14 @@ impl Default for Rc {
                     target: Default::default(),
                     loose: false,
                     keep_class_names: false,
+                    lints: None,
                 },
                 module: None,
                 minify: None,
                 source_maps: None,
+                extends: None,
+                envs: Default::default(),
+                overrides: vec![],
             },
         ])
     }
@@ -400,20 +519,27 @@ impl Default for Rc {
 
 impl Rc {
     /// This method returns `Ok(None)` if the file should be ignored.
-    pub fn into_config(self, filename: Option<&Path>) -> Result<Option<Config>, Error> {
+    ///
+    /// `env` matching `env_name` and `overrides` matching `filename` are
+    /// merged into the returned config.
+    pub fn into_config(
+        self,
+        filename: Option<&Path>,
+        env_name: &str,
+    ) -> Result<Option<Config>, Error> {
         let cs = match self {
             Rc::Single(mut c) => match filename {
                 Some(filename) => {
                     if c.matches(filename)? {
                         c.adjust(filename);
 
-                        return Ok(Some(c));
+                        return c.resolve(Some(filename), env_name).map(Some);
                     } else {
                         return Ok(None);
                     }
                 }
                 // TODO
-                None => return Ok(Some(c)),
+                None => return c.resolve(None, env_name).map(Some),
             },
             Rc::Multi(cs) => cs,
         };
@@ -424,7 +550,7 @@ impl Rc {
                     if c.matches(filename)? {
                         c.adjust(filename);
 
-                        return Ok(Some(c));
+                        return c.resolve(Some(filename), env_name).map(Some);
                     }
                 }
             }
@@ -437,29 +563,141 @@ impl Rc {
 
 /// A single object in the `.swcrc` file
 #[derive(Debug, Default, Clone, Deserialize)]
-#[serde(deny_unknown_fields, rename_all = "camelCase")]
+#[serde(from = "RawConfig")]
 pub struct Config {
-    #[serde(default)]
     pub env: Option<swc_ecma_preset_env::Config>,
 
-    #[serde(default)]
     pub test: Option<FileMatcher>,
 
-    #[serde(default)]
     pub exclude: Option<FileMatcher>,
 
-    #[serde(default)]
     pub jsc: JscConfig,
 
-    #[serde(default)]
     pub module: Option<ModuleConfig>,
 
-    #[serde(default)]
     pub minify: Option<bool>,
 
     /// Possible values are: `'inline'`, `true`, `false`.
-    #[serde(default)]
     pub source_maps: Option<SourceMapsConfig>,
+
+    /// Path to a config file this config is based on, relative to the file
+    /// containing this config.
+    ///
+    /// Only allowed in the top-level configs of a file.
+    pub extends: Option<String>,
+
+    /// Configs merged into this config if the key is equal to `envName`,
+    /// which defaults to `SWC_ENV` or `NODE_ENV`.
+    ///
+    /// In `.swcrc`, these are the keys of `env` which are not options of
+    /// `swc_ecma_preset_env`, like babel's `env`.
+    pub envs: HashMap<String, Config>,
+
+    /// Configs merged into this config if their `test` and `exclude` match
+    /// the file.
+    pub overrides: Vec<Config>,
+}
+
+/// [Config] as written in `.swcrc`.
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "camelCase")]
+struct RawConfig {
+    #[serde(default)]
+    env: Option<RawEnv>,
+
+    #[serde(default)]
+    test: Option<FileMatcher>,
+
+    #[serde(default)]
+    exclude: Option<FileMatcher>,
+
+    #[serde(default)]
+    jsc: JscConfig,
+
+    #[serde(default)]
+    module: Option<ModuleConfig>,
+
+    #[serde(default)]
+    minify: Option<bool>,
+
+    #[serde(default)]
+    source_maps: Option<SourceMapsConfig>,
+
+    #[serde(default)]
+    extends: Option<String>,
+
+    #[serde(default)]
+    overrides: Vec<Config>,
+}
+
+impl From<RawConfig> for Config {
+    fn from(c: RawConfig) -> Self {
+        let (env, envs) = match c.env {
+            Some(env) => (env.preset_env, env.named),
+            None => (None, Default::default()),
+        };
+
+        Config {
+            env,
+            test: c.test,
+            exclude: c.exclude,
+            jsc: c.jsc,
+            module: c.module,
+            minify: c.minify,
+            source_maps: c.source_maps,
+            extends: c.extends,
+            envs,
+            overrides: c.overrides,
+        }
+    }
+}
+
+/// The `env` of `.swcrc`, which contains both the options of
+/// `swc_ecma_preset_env` and the configs for each env.
+struct RawEnv {
+    preset_env: Option<swc_ecma_preset_env::Config>,
+    named: HashMap<String, Config>,
+}
+
+/// Splits `env` into the options of `swc_ecma_preset_env` and the other keys,
+/// which are names of envs.
+#[derive(Deserialize)]
+struct SplitEnv {
+    #[serde(flatten)]
+    preset_env: swc_ecma_preset_env::Config,
+    #[serde(flatten)]
+    named: HashMap<String, serde_json::Value>,
+}
+
+impl<'de> Deserialize<'de> for RawEnv {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let env = serde_json::Map::<String, serde_json::Value>::deserialize(deserializer)?;
+        let len = env.len();
+        let split: SplitEnv =
+            serde_json::from_value(serde_json::Value::Object(env)).map_err(D::Error::custom)?;
+
+        // `"env": {}` enables `swc_ecma_preset_env` with the default options.
+        let preset_env = if split.named.len() == len && len != 0 {
+            None
+        } else {
+            Some(split.preset_env)
+        };
+
+        let named = split
+            .named
+            .into_iter()
+            .map(|(name, c)| {
+                serde_json::from_value(c)
+                    .map(|c| (name.clone(), c))
+                    .map_err(|err| D::Error::custom(format!("invalid env `{}`: {}", name, err)))
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(RawEnv { preset_env, named })
+    }
 }
 
 impl Config {
@@ -479,6 +717,40 @@ impl Config {
             _ => {}
         }
     }
+
+    /// Merges `envs` and `overrides` into `self`.
+    fn resolve(mut self, filename: Option<&Path>, env_name: &str) -> Result<Config, Error> {
+        let mut envs = mem::take(&mut self.envs);
+        let overrides = mem::take(&mut self.overrides);
+
+        for (name, env) in &envs {
+            if env.extends.is_some() {
+                bail!("`extends` is not allowed in env `{}`", name)
+            }
+        }
+        if overrides.iter().any(|c| c.extends.is_some()) {
+            bail!("`extends` is not allowed in `overrides`")
+        }
+
+        if let Some(env) = envs.remove(env_name) {
+            let env = env.resolve(filename, env_name)?;
+            self.merge(&env);
+        }
+
+        for c in overrides {
+            let matches = match filename {
+                Some(filename) => c.matches(filename)?,
+                None => c.test.is_none() && c.exclude.is_none(),
+            };
+
+            if matches {
+                let c = c.resolve(filename, env_name)?;
+                self.merge(&c);
+            }
+        }
+
+        Ok(self)
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -584,6 +856,53 @@ pub struct JscConfig {
 
     #[serde(default)]
     pub keep_class_names: bool,
+
+    /// Lint rules applied to the program before transforms.
+    #[serde(default)]
+    pub lints: Option<LintConfig>,
+
+    #[serde(default)]
+    pub experimental: JscExperimental,
+}
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "camelCase")]
+pub struct JscExperimental {
+    /// Wasm plugins, which are applied in order.
+    ///
+    /// Requires the `plugin` feature.
+    #[serde(default)]
+    pub plugins: Option<Vec<PluginConfig>>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "camelCase")]
+pub struct PluginConfig {
+    /// Path to the `.wasm` file, relative to `cwd`.
+    pub path: PathBuf,
+
+    /// Passed to the plugin as json.
+    #[serde(default)]
+    pub config: serde_json::Value,
+
+    #[serde(default)]
+    pub order: PluginOrder,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum PluginOrder {
+    /// Applied before built-in passes, so typescript types and jsx are
+    /// preserved.
+    BeforeTransforms,
+    /// Applied after built-in passes, including hygiene and fixer.
+    AfterTransforms,
+}
+
+impl Default for PluginOrder {
+    fn default() -> Self {
+        PluginOrder::BeforeTransforms
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -635,6 +954,9 @@ pub struct TransformConfig {
     #[serde(default)]
     pub decorator_metadata: bool,
 
+    #[serde(default)]
+    pub decorator_version: decorators::DecoratorVersion,
+
     #[serde(default)]
     pub hidden: HiddenTransformConfig,
 }
@@ -819,6 +1141,28 @@ impl Merge for swc_ecma_preset_env::Config {
     }
 }
 
+impl Merge for LintConfig {
+    fn merge(&mut self, from: &Self) {
+        self.no_unused_vars.merge(&from.no_unused_vars);
+        self.no_dupe_keys.merge(&from.no_dupe_keys);
+        self.no_undef.merge(&from.no_undef);
+        self.no_unreachable.merge(&from.no_unreachable);
+        self.no_redeclare.merge(&from.no_redeclare);
+        self.no_const_assign.merge(&from.no_const_assign);
+        for global in &from.globals {
+            if !self.globals.contains(global) {
+                self.globals.push(global.clone());
+            }
+        }
+    }
+}
+
+impl Merge for LintRuleReaction {
+    fn merge(&mut self, from: &Self) {
+        *self = *from;
+    }
+}
+
 impl Merge for JscConfig {
     fn merge(&mut self, from: &Self) {
         self.syntax.merge(&from.syntax);
@@ -826,6 +1170,16 @@ impl Merge for JscConfig {
         self.target.merge(&from.target);
         self.external_helpers.merge(&from.external_helpers);
         self.keep_class_names.merge(&from.keep_class_names);
+        self.lints.merge(&from.lints);
+        self.experimental.merge(&from.experimental);
+    }
+}
+
+impl Merge for JscExperimental {
+    fn merge(&mut self, from: &Self) {
+        if from.plugins.is_some() {
+            self.plugins = from.plugins.clone();
+        }
     }
 }
 
@@ -1,6 +1,8 @@
-use super::Rc;
+use super::{ModuleConfig, Rc};
 use crate::Options;
 use serde_json;
+use std::path::Path;
+use swc_ecma_lints::config::LintRuleReaction;
 
 #[test]
 fn object() {
@@ -19,3 +21,107 @@ fn issue_1532() {
     let err = res.expect_err("should fail");
     assert!(err.to_string().contains("unknown variant `esnext`"));
 }
+
+#[test]
+fn envs_and_overrides() {
+    let rc: Rc = serde_json::from_str(
+        r#"{
+            "module": { "type": "commonjs" },
+            "env": {
+                "targets": "chrome 79",
+                "loose": true,
+                "production": { "minify": true }
+            },
+            "overrides": [
+                {
+                    "test": "legacy/.*\\.js$",
+                    "module": { "type": "amd" }
+                }
+            ]
+        }"#,
+    )
+    .expect("failed to parse");
+
+    let c = rc
+        .clone()
+        .into_config(Some(Path::new("src/index.js")), "development")
+        .unwrap()
+        .unwrap();
+    assert_eq!(c.minify, None);
+    assert!(c.env.as_ref().map_or(false, |env| env.loose));
+    assert!(matches!(c.module, Some(ModuleConfig::CommonJs(..))));
+
+    let c = rc
+        .into_config(Some(Path::new("src/legacy/index.js")), "production")
+        .unwrap()
+        .unwrap();
+    assert_eq!(c.minify, Some(true));
+    assert!(matches!(c.module, Some(ModuleConfig::Amd(..))));
+}
+
+#[test]
+fn env_without_preset_env_options() {
+    let rc: Rc = serde_json::from_str(r#"{ "env": { "production": { "minify": true } } }"#)
+        .expect("failed to parse");
+
+    let c = rc.into_config(None, "production").unwrap().unwrap();
+    assert!(c.env.is_none());
+    assert_eq!(c.minify, Some(true));
+}
+
+#[test]
+fn invalid_env() {
+    let res = serde_json::from_str::<Rc>(r#"{ "env": { "production": { "minify": 1 } } }"#);
+
+    assert!(res.is_err());
+}
+
+#[test]
+fn extends_in_env_and_overrides() {
+    let rc: Rc =
+        serde_json::from_str(r#"{ "env": { "production": { "extends": "./base.swcrc" } } }"#)
+            .expect("failed to parse");
+
+    let err = rc.into_config(None, "production").unwrap_err();
+    assert!(err.to_string().contains("not allowed in env `production`"));
+
+    let rc: Rc = serde_json::from_str(r#"{ "overrides": [{ "extends": "./base.swcrc" }] }"#)
+        .expect("failed to parse");
+
+    let err = rc
+        .into_config(Some(Path::new("index.js")), "development")
+        .unwrap_err();
+    assert!(err.to_string().contains("not allowed in `overrides`"));
+}
+
+#[test]
+fn lints_are_merged_per_rule() {
+    let rc: Rc = serde_json::from_str(
+        r#"{
+            "jsc": {
+                "lints": { "no-undef": "error", "no-unused-vars": "error", "globals": ["window"] }
+            },
+            "overrides": [
+                {
+                    "test": "legacy/.*\\.js$",
+                    "jsc": {
+                        "lints": { "no-unused-vars": "off", "globals": ["jQuery"] }
+                    }
+                }
+            ]
+        }"#,
+    )
+    .expect("failed to parse");
+
+    let lints = rc
+        .into_config(Some(Path::new("src/legacy/index.js")), "development")
+        .unwrap()
+        .unwrap()
+        .jsc
+        .lints
+        .unwrap();
+    assert_eq!(lints.no_undef, Some(LintRuleReaction::Error));
+    assert_eq!(lints.no_unused_vars, Some(LintRuleReaction::Off));
+    assert_eq!(lints.no_dupe_keys, None);
+    assert_eq!(lints.globals, vec!["window", "jQuery"]);
+}
@@ -1,13 +1,16 @@
 #![deny(unused)]
 
 pub use crate::builder::PassBuilder;
-use crate::config::{
-    BuiltConfig, Config, ConfigFile, InputSourceMap, JscTarget, Merge, Options, Rc, RootMode,
-    SourceMapsConfig,
+use crate::{
+    cache::{CacheKey, TransformCache},
+    config::{
+        BuiltConfig, Config, ConfigFile, InputSourceMap, JscTarget, Merge, Options, Rc, RootMode,
+        SourceMapsConfig,
+    },
 };
 use anyhow::{bail, Context, Error};
 use dashmap::DashMap;
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 use serde_json::error::Category;
 pub use sourcemap;
 use std::{
@@ -24,7 +27,13 @@ use swc_common::{
 };
 use swc_ecma_ast::Program;
 use swc_ecma_codegen::{self, Emitter, Node};
-use swc_ecma_parser::{lexer::Lexer, Parser, Syntax};
+use swc_ecma_parser::{
+    lexer::{
+        lossless::{self, RawToken},
+        Lexer,
+    },
+    Parser, Syntax,
+};
 use swc_ecma_transforms::{
     helpers::{self, Helpers},
     pass::noop,
@@ -32,7 +41,9 @@ use swc_ecma_transforms::{
 use swc_ecma_visit::FoldWith;
 
 mod builder;
+pub mod cache;
 pub mod config;
+mod plugin;
 
 pub struct Compiler {
     /// swc uses rustc's span interning.
@@ -43,9 +54,11 @@ pub struct Compiler {
     pub cm: Arc<SourceMap>,
     pub handler: Arc<Handler>,
     comments: SwcComments,
+    /// Transform caches, keyed by their directories.
+    caches: DashMap<PathBuf, Arc<TransformCache>>,
 }
 
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct TransformOutput {
     pub code: String,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -225,6 +238,35 @@ impl Compiler {
         })
     }
 
+    /// Splits `fm` into tokens, including whitespaces and comments.
+    ///
+    /// Errors from the lexer are emitted, but they are not fatal.
+    pub fn tokenize(
+        &self,
+        fm: Arc<SourceFile>,
+        target: JscTarget,
+        syntax: Syntax,
+    ) -> Vec<RawToken> {
+        self.run(|| {
+            let (tokens, errors) = lossless::tokenize(syntax, target, &fm);
+            for e in errors {
+                e.into_diagnostic(&self.handler).emit();
+            }
+
+            tokens
+        })
+    }
+
+    /// Collects jsdoc comments of declarations in `program`.
+    ///
+    /// `program` should be parsed with comments enabled.
+    pub fn collect_jsdoc(&self, program: &Program) -> Vec<jsdoc::DocumentedItem> {
+        self.run(|| match program {
+            Program::Module(m) => jsdoc::collect_docs(m, &self.comments),
+            Program::Script(s) => jsdoc::collect_script_docs(s, &self.comments),
+        })
+    }
+
     /// Converts ast node to source string and sourcemap.
     ///
     /// TODO: Receive target file path to fix https://github.com/swc-project/swc/issues/1255
@@ -317,6 +359,7 @@ impl Compiler {
             handler,
             globals: Globals::new(),
             comments: Default::default(),
+            caches: Default::default(),
         }
     }
 
@@ -327,6 +370,7 @@ impl Compiler {
                 root_mode,
                 swcrc,
                 config_file,
+                ref env_name,
                 ..
             } = opts;
 
@@ -339,7 +383,7 @@ impl Compiler {
             });
 
             let config_file = match config_file {
-                Some(ConfigFile::Str(ref s)) => Some(load_swcrc(Path::new(&s))?),
+                Some(ConfigFile::Str(ref s)) => Some(Path::new(s)),
                 _ => None,
             };
 
@@ -351,14 +395,11 @@ impl Compiler {
                             let swcrc = dir.join(".swcrc");
 
                             if swcrc.exists() {
-                                let config = load_swcrc(&swcrc)?;
-
-                                let mut config = config
-                                    .into_config(Some(path))
+                                let mut config = load_config(&swcrc, Some(path), env_name)
                                     .context("failed to process config file")?;
 
                                 if let Some(config_file) = config_file {
-                                    config.merge(&config_file.into_config(Some(path))?)
+                                    config.merge(&load_config(config_file, Some(path), env_name)?)
                                 }
 
                                 return Ok(config);
@@ -371,8 +412,10 @@ impl Compiler {
                         }
                     }
 
-                    let config_file = config_file.unwrap_or_else(|| Rc::default());
-                    let config = config_file.into_config(Some(path))?;
+                    let config = match config_file {
+                        Some(config_file) => load_config(config_file, Some(path), env_name)?,
+                        None => Rc::default().into_config(Some(path), env_name)?,
+                    };
 
                     return Ok(config);
                 }
@@ -380,8 +423,8 @@ impl Compiler {
             }
 
             let config = match config_file {
-                Some(config_file) => config_file.into_config(None)?,
-                None => Rc::default().into_config(None)?,
+                Some(config_file) => load_config(config_file, None, env_name)?,
+                None => Rc::default().into_config(None, env_name)?,
             };
 
             match config {
@@ -487,12 +530,56 @@ impl Compiler {
         .context("failed to process js file")
     }
 
+    /// If `opts.cache` is set, the output is read from or stored to the cache.
     pub fn process_js_file(
         &self,
         fm: Arc<SourceFile>,
         opts: &Options,
     ) -> Result<TransformOutput, Error> {
-        self.process_js_with_custom_pass(fm, opts, noop())
+        let cache = match opts.cache {
+            Some(ref config) => self
+                .caches
+                .entry(config.dir.clone())
+                .or_insert_with(|| Arc::new(TransformCache::new(config)))
+                .clone(),
+            None => return self.process_js_with_custom_pass(fm, opts, noop()),
+        };
+
+        // Errors are reported by `process_js_with_custom_pass` below.
+        let key = match self.cache_key(&fm, opts) {
+            Ok(Some(key)) => key,
+            _ => return self.process_js_with_custom_pass(fm, opts, noop()),
+        };
+        if let Some(output) = cache.get(&key) {
+            return Ok(output);
+        }
+
+        let diagnostic_count = self.handler.diagnostic_count();
+        let output = self.process_js_with_custom_pass(fm, opts, noop())?;
+
+        // Diagnostics emitted to the handler, including warnings, would be lost
+        // on cache hits.
+        if self.handler.diagnostic_count() == diagnostic_count {
+            if let Err(err) = cache.put(&key, &output) {
+                log::warn!("failed to store transform output: {:?}", err);
+            }
+        }
+
+        Ok(output)
+    }
+
+    /// Returns [None] if the file is ignored by `.swcrc`.
+    fn cache_key(&self, fm: &SourceFile, opts: &Options) -> Result<Option<CacheKey>, Error> {
+        self.run(|| {
+            let mut config = match self.read_config(opts, &fm.name)? {
+                Some(v) => v,
+                None => return Ok(None),
+            };
+            config.merge(&opts.config);
+
+            let orig = self.get_orig_src_map(fm, &opts.input_source_map)?;
+            CacheKey::new(fm, opts, &config, orig.as_ref()).map(Some)
+        })
     }
 
     /// You can use custom pass with this method.
@@ -552,6 +639,55 @@ impl Compiler {
     }
 }
 
+/// Loads the config for `filename` from the config file at `path`, including
+/// the config files it `extends`.
+fn load_config(
+    path: &Path,
+    filename: Option<&Path>,
+    env_name: &str,
+) -> Result<Option<Config>, Error> {
+    fn load(
+        path: &Path,
+        filename: Option<&Path>,
+        env_name: &str,
+        seen: &mut Vec<PathBuf>,
+    ) -> Result<Option<Config>, Error> {
+        let canonical = path.canonicalize().unwrap_or_else(|_| path.to_path_buf());
+        if seen.contains(&canonical) {
+            bail!("config file extends itself: {}", path.display())
+        }
+        seen.push(canonical);
+
+        let config = load_swcrc(path)?.into_config(filename, env_name)?;
+        let mut config = match config {
+            Some(v) => v,
+            None => return Ok(None),
+        };
+
+        let base = match config.extends.take() {
+            Some(v) => v,
+            None => return Ok(Some(config)),
+        };
+        let base_path = match path.parent() {
+            Some(dir) => dir.join(&base),
+            None => PathBuf::from(&base),
+        };
+
+        let base = load(&base_path, filename, env_name, seen)
+            .with_context(|| format!("failed to load {} extended by {}", base, path.display()))?;
+
+        Ok(Some(match base {
+            Some(mut base) => {
+                base.merge(&config);
+                base
+            }
+            None => config,
+        }))
+    }
+
+    load(path, filename, env_name, &mut vec![])
+}
+
 fn load_swcrc(path: &Path) -> Result<Rc, Error> {
     fn convert_json_err(e: serde_json::Error) -> Error {
         let line = e.line();
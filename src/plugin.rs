@@ -0,0 +1,268 @@
+//! Host for wasm plugins listed in `jsc.experimental.plugins`.
+//!
+//! # Abi
+//!
+//! A plugin is a wasm module which exports
+//!
+//!  - `memory`
+//!  - `__swc_plugin_alloc(len: i32) -> i32`, which allocates `len` bytes.
+//!  - `__swc_plugin_transform(program: i32, program_len: i32, config: i32,
+//!    config_len: i32) -> i64`
+//!
+//! `program` is a json-serialized [Program], and `config` is the json of
+//! `config` of the plugin. `__swc_plugin_transform` returns the pointer and
+//! the length of the transformed program in json, as `(ptr << 32) | len`.
+//!
+//! If the plugin exports `__swc_plugin_binary_ast`, programs are encoded using
+//! [swc_ecma_ast::binary] instead of json.
+//!
+//! A plugin can report an error by calling `set_error(msg: i32, msg_len: i32)`
+//! imported from the `swc` module, in which case the output is ignored.
+//!
+//! Each file is processed by a fresh instance, so plugins don't need to free
+//! memory.
+//!
+//! The memory of a plugin is limited to 512MiB, and a plugin is aborted if it
+//! executes too many instructions for the size of its input.
+
+use crate::config::PluginConfig;
+use anyhow::Error;
+use std::{
+    mem,
+    path::{Path, PathBuf},
+};
+use swc_common::Spanned;
+use swc_ecma_ast::{Module, Program, Script};
+use swc_ecma_utils::HANDLER;
+use swc_ecma_visit::Fold;
+
+/// Applies wasm plugins in order.
+///
+/// If a plugin fails, an error is emitted and the program is passed to the
+/// next plugin as-is.
+pub struct Plugins {
+    plugins: Vec<(PathBuf, serde_json::Value)>,
+}
+
+impl Plugins {
+    pub fn new(dir: &Path, plugins: Vec<PluginConfig>) -> Self {
+        Plugins {
+            plugins: plugins
+                .into_iter()
+                .map(|plugin| (dir.join(plugin.path), plugin.config))
+                .collect(),
+        }
+    }
+
+    fn apply(&self, mut program: Program) -> Program {
+        for (path, config) in &self.plugins {
+            let res = host::transform(path, config, &program).and_then(|transformed| {
+                if mem::discriminant(&transformed) != mem::discriminant(&program) {
+                    anyhow::bail!("plugin changed the type of the program")
+                }
+                Ok(transformed)
+            });
+
+            match res {
+                Ok(transformed) => program = transformed,
+                Err(err) => HANDLER.with(|handler| {
+                    handler
+                        .struct_span_err(
+                            program.span(),
+                            &format!("failed to run plugin at {}: {:?}", path.display(), err),
+                        )
+                        .emit()
+                }),
+            }
+        }
+
+        program
+    }
+}
+
+impl Fold for Plugins {
+    fn fold_module(&mut self, m: Module) -> Module {
+        if self.plugins.is_empty() {
+            return m;
+        }
+
+        match self.apply(Program::Module(m)) {
+            Program::Module(m) => m,
+            Program::Script(..) => unreachable!(),
+        }
+    }
+
+    fn fold_script(&mut self, s: Script) -> Script {
+        if self.plugins.is_empty() {
+            return s;
+        }
+
+        match self.apply(Program::Script(s)) {
+            Program::Script(s) => s,
+            Program::Module(..) => unreachable!(),
+        }
+    }
+}
+
+#[cfg(feature = "plugin")]
+mod host {
+    use super::*;
+    use anyhow::{bail, Context};
+    use dashmap::DashMap;
+    use once_cell::sync::Lazy;
+    use std::{convert::TryFrom, fs, time::SystemTime};
+    use wasmtime::{
+        AsContextMut, Caller, Engine, Extern, Linker, Memory, Store, StoreLimits,
+        StoreLimitsBuilder, Trap, TypedFunc,
+    };
+
+    static ENGINE: Lazy<Engine> = Lazy::new(|| {
+        let mut config = wasmtime::Config::new();
+        config.consume_fuel(true);
+        Engine::new(&config).expect("failed to create wasm engine")
+    });
+
+    /// Maximum number of wasm pages (64KiB) of the memory of a plugin.
+    const MAX_MEMORY_PAGES: u32 = 8192;
+
+    /// Fuel given to a plugin regardless of the size of its input. An
+    /// instruction consumes roughly one unit of fuel.
+    const BASE_FUEL: u64 = 100_000_000;
+
+    /// Fuel given to a plugin for each byte of its input.
+    const FUEL_PER_BYTE: u64 = 10_000;
+
+    struct HostState {
+        /// The error reported by the plugin.
+        error: Option<String>,
+        limits: StoreLimits,
+    }
+
+    /// Compiled modules, with modification times of the files.
+    static MODULES: Lazy<DashMap<PathBuf, (Option<SystemTime>, wasmtime::Module)>> =
+        Lazy::new(Default::default);
+
+    fn load(path: &Path) -> Result<wasmtime::Module, Error> {
+        let modified = fs::metadata(path)
+            .with_context(|| format!("failed to read plugin at {}", path.display()))?
+            .modified()
+            .ok();
+
+        if let Some(entry) = MODULES.get(path) {
+            if entry.0.is_some() && entry.0 == modified {
+                return Ok(entry.1.clone());
+            }
+        }
+
+        let module = wasmtime::Module::from_file(&ENGINE, path)
+            .with_context(|| format!("failed to compile plugin at {}", path.display()))?;
+        MODULES.insert(path.to_path_buf(), (modified, module.clone()));
+        Ok(module)
+    }
+
+    fn read(memory: &[u8], ptr: u32, len: u32) -> Option<&[u8]> {
+        let ptr = ptr as usize;
+        memory.get(ptr..ptr.checked_add(len as usize)?)
+    }
+
+    fn write<T>(
+        mut store: impl AsContextMut<Data = T>,
+        memory: &Memory,
+        alloc: &TypedFunc<i32, i32>,
+        data: &[u8],
+    ) -> Result<(i32, i32), Error> {
+        let len = i32::try_from(data.len()).context("input is too large")?;
+        let ptr = alloc.call(&mut store, len)?;
+        memory
+            .write(&mut store, ptr as u32 as usize, data)
+            .context("plugin allocated an invalid range")?;
+        Ok((ptr, len))
+    }
+
+    pub(super) fn transform(
+        path: &Path,
+        config: &serde_json::Value,
+        program: &Program,
+    ) -> Result<Program, Error> {
+        let module = load(path)?;
+        let binary = module.get_export("__swc_plugin_binary_ast").is_some();
+        let program = if binary {
+            swc_ecma_ast::binary::to_vec(program).context("failed to encode program")?
+        } else {
+            serde_json::to_vec(program).context("failed to serialize program")?
+        };
+        let config = serde_json::to_vec(config).context("failed to serialize config")?;
+
+        let mut store = Store::new(
+            &ENGINE,
+            HostState {
+                error: None,
+                limits: StoreLimitsBuilder::new()
+                    .memory_pages(MAX_MEMORY_PAGES)
+                    .build(),
+            },
+        );
+        store.limiter(|state| &mut state.limits);
+        let input_len = (program.len() + config.len()) as u64;
+        store.add_fuel(BASE_FUEL.saturating_add(input_len.saturating_mul(FUEL_PER_BYTE)))?;
+
+        let mut linker = Linker::new(&ENGINE);
+        linker.func_wrap(
+            "swc",
+            "set_error",
+            |mut caller: Caller<'_, HostState>, ptr: i32, len: i32| -> Result<(), Trap> {
+                let memory = caller
+                    .get_export("memory")
+                    .and_then(Extern::into_memory)
+                    .ok_or_else(|| Trap::new("plugin does not export `memory`"))?;
+                let msg = read(memory.data(&caller), ptr as u32, len as u32)
+                    .map(|msg| String::from_utf8_lossy(msg).into_owned())
+                    .ok_or_else(|| Trap::new("invalid error message"))?;
+                caller.data_mut().error = Some(msg);
+                Ok(())
+            },
+        )?;
+
+        let instance = linker.instantiate(&mut store, &module)?;
+        let memory = instance
+            .get_memory(&mut store, "memory")
+            .context("plugin does not export `memory`")?;
+        let alloc = instance.get_typed_func::<i32, i32, _>(&mut store, "__swc_plugin_alloc")?;
+        let transform = instance
+            .get_typed_func::<(i32, i32, i32, i32), i64, _>(&mut store, "__swc_plugin_transform")?;
+
+        let (program_ptr, program_len) = write(&mut store, &memory, &alloc, &program)?;
+        let (config_ptr, config_len) = write(&mut store, &memory, &alloc, &config)?;
+        let ret = transform.call(
+            &mut store,
+            (program_ptr, program_len, config_ptr, config_len),
+        )?;
+
+        if let Some(msg) = store.data_mut().error.take() {
+            bail!("{}", msg)
+        }
+
+        let (ptr, len) = ((ret as u64 >> 32) as u32, ret as u32);
+        let output =
+            read(memory.data(&store), ptr, len).context("plugin returned an invalid range")?;
+        if binary {
+            swc_ecma_ast::binary::from_slice(output)
+                .context("failed to decode the output of plugin")
+        } else {
+            serde_json::from_slice(output).context("failed to deserialize the output of plugin")
+        }
+    }
+}
+
+#[cfg(not(feature = "plugin"))]
+mod host {
+    use super::*;
+
+    pub(super) fn transform(
+        _: &Path,
+        _: &serde_json::Value,
+        _: &Program,
+    ) -> Result<Program, Error> {
+        anyhow::bail!("swc is built without the `plugin` feature")
+    }
+}
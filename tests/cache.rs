@@ -0,0 +1,185 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    process,
+    sync::Arc,
+    thread,
+    time::Duration,
+};
+use swc::{
+    cache::CacheConfig,
+    config::{Config, JscConfig, Options},
+    Compiler,
+};
+use swc_common::FileName;
+use swc_ecma_ast::EsVersion;
+use swc_ecma_lints::config::{LintConfig, LintRuleReaction};
+use testing::Tester;
+
+fn cache_dir(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("swc-cache-{}-{}", name, process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    dir
+}
+
+fn entries(dir: &Path) -> Vec<PathBuf> {
+    match fs::read_dir(dir) {
+        Ok(entries) => entries.map(|e| e.unwrap().path()).collect(),
+        Err(..) => vec![],
+    }
+}
+
+fn compile(src: &str, dir: &Path, max_size: u64, target: EsVersion) -> String {
+    compile_with(
+        src,
+        dir,
+        max_size,
+        JscConfig {
+            target: Some(target),
+            ..Default::default()
+        },
+    )
+}
+
+fn compile_with(src: &str, dir: &Path, max_size: u64, jsc: JscConfig) -> String {
+    Tester::new()
+        .print_errors(|cm, handler| {
+            let c = Compiler::new(cm.clone(), Arc::new(handler));
+
+            let fm = cm.new_source_file(FileName::Real("input.js".into()), src.into());
+            let s = c.process_js_file(
+                fm,
+                &Options {
+                    config: Config {
+                        jsc,
+                        ..Default::default()
+                    },
+                    swcrc: false,
+                    cache: Some(CacheConfig {
+                        dir: dir.to_path_buf(),
+                        max_size,
+                    }),
+                    ..Default::default()
+                },
+            );
+
+            match s {
+                Ok(v) => Ok(v.code),
+                Err(..) => Err(()),
+            }
+        })
+        .unwrap()
+}
+
+#[test]
+fn cache_hit() {
+    let dir = cache_dir("hit");
+
+    let code = compile("const a = () => 1;", &dir, 1 << 20, EsVersion::Es5);
+    let files = entries(&dir);
+    assert_eq!(files.len(), 1);
+
+    // Outputs are read from the cache if the input is not changed.
+    fs::write(&files[0], r#"{"code":"cached"}"#).unwrap();
+    assert_eq!(
+        compile("const a = () => 1;", &dir, 1 << 20, EsVersion::Es5),
+        "cached"
+    );
+
+    // Changing the source or the config invalidates the cache.
+    assert_ne!(
+        compile("const a = () => 2;", &dir, 1 << 20, EsVersion::Es5),
+        "cached"
+    );
+    assert_ne!(
+        compile("const a = () => 1;", &dir, 1 << 20, EsVersion::Es2015),
+        "cached"
+    );
+    assert_eq!(entries(&dir).len(), 3);
+    assert!(code.contains("function"));
+
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn invalid_entry() {
+    let dir = cache_dir("invalid");
+
+    let code = compile("let a = 1;", &dir, 1 << 20, EsVersion::Es5);
+    let files = entries(&dir);
+    assert_eq!(files.len(), 1);
+
+    fs::write(&files[0], "{").unwrap();
+    assert_eq!(compile("let a = 1;", &dir, 1 << 20, EsVersion::Es5), code);
+
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn eviction() {
+    let dir = cache_dir("eviction");
+
+    for i in 0..10 {
+        compile(&format!("let a = {};", i), &dir, 200, EsVersion::Es5);
+    }
+
+    let size: u64 = entries(&dir)
+        .iter()
+        .map(|path| fs::metadata(path).unwrap().len())
+        .sum();
+    assert!(size <= 200, "cache size should be bounded: {}", size);
+    assert!(!entries(&dir).is_empty());
+
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn warnings_are_not_cached() {
+    let dir = cache_dir("warnings");
+
+    compile_with(
+        "let a = 1;",
+        &dir,
+        1 << 20,
+        JscConfig {
+            lints: Some(LintConfig {
+                no_unused_vars: Some(LintRuleReaction::Warning),
+                ..Default::default()
+            }),
+            ..Default::default()
+        },
+    );
+    assert_eq!(entries(&dir), Vec::<PathBuf>::new());
+}
+
+#[test]
+fn eviction_is_lru() {
+    let dir = cache_dir("lru");
+
+    compile("let a = 1;", &dir, 1 << 20, EsVersion::Es5);
+    let first = entries(&dir).remove(0);
+    let size = fs::metadata(&first).unwrap().len();
+    // Two entries fit, and evicting one is enough for the third one.
+    let max_size = size * 14 / 5;
+
+    // Makes modification times distinct.
+    let tick = || thread::sleep(Duration::from_millis(20));
+
+    tick();
+    compile("let a = 2;", &dir, max_size, EsVersion::Es5);
+    let second = entries(&dir).into_iter().find(|p| *p != first).unwrap();
+
+    tick();
+    compile("let a = 1;", &dir, max_size, EsVersion::Es5);
+
+    tick();
+    compile("let a = 3;", &dir, max_size, EsVersion::Es5);
+
+    assert!(first.exists(), "recently used entry should be kept");
+    assert!(
+        !second.exists(),
+        "least recently used entry should be evicted"
+    );
+
+    fs::remove_dir_all(&dir).unwrap();
+}
@@ -0,0 +1,53 @@
+use std::sync::Arc;
+use swc::{
+    config::{Config, JscConfig, Options},
+    Compiler,
+};
+use swc_common::FileName;
+use swc_ecma_lints::config::{LintConfig, LintRuleReaction};
+use swc_ecma_parser::{Syntax, TsConfig};
+use testing::Tester;
+
+/// Returns messages reported while compiling `src` as typescript.
+fn lint_ts(src: &str) -> Vec<String> {
+    let errors = Tester::new()
+        .errors(|cm, handler| {
+            let c = Compiler::new(cm.clone(), Arc::new(handler));
+            let fm = cm.new_source_file(FileName::Real("input.ts".into()), src.into());
+
+            let _ = c.process_js_file(
+                fm,
+                &Options {
+                    config: Config {
+                        jsc: JscConfig {
+                            syntax: Some(Syntax::Typescript(TsConfig::default())),
+                            lints: Some(LintConfig {
+                                no_unused_vars: Some(LintRuleReaction::Error),
+                                ..Default::default()
+                            }),
+                            ..Default::default()
+                        },
+                        ..Default::default()
+                    },
+                    is_module: true,
+                    ..Default::default()
+                },
+            );
+
+            Err::<(), _>(())
+        })
+        .unwrap_err();
+
+    errors.into_iter().map(|err| err.message()).collect()
+}
+
+#[test]
+fn unused_import_is_reported_before_strip() {
+    assert_eq!(
+        lint_ts(
+            "import { a, b } from 'foo';
+            export const c: number = b;"
+        ),
+        vec!["'a' is defined but never used"]
+    );
+}
@@ -0,0 +1,128 @@
+#![cfg(feature = "plugin")]
+
+use std::{path::PathBuf, sync::Arc};
+use swc::{
+    config::{Config, JscConfig, JscExperimental, Options, PluginConfig, PluginOrder},
+    Compiler,
+};
+use swc_common::FileName;
+use swc_ecma_parser::{Syntax, TsConfig};
+use testing::Tester;
+
+fn compile(src: &str, plugins: Vec<PluginConfig>) -> Result<String, ()> {
+    Tester::new().print_errors(|cm, handler| {
+        let c = Compiler::new(cm.clone(), Arc::new(handler));
+
+        let fm = cm.new_source_file(FileName::Real("input.ts".into()), src.into());
+        let s = c.process_js_file(
+            fm,
+            &Options {
+                config: Config {
+                    jsc: JscConfig {
+                        syntax: Some(Syntax::Typescript(TsConfig::default())),
+                        experimental: JscExperimental {
+                            plugins: Some(plugins),
+                        },
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                },
+                cwd: PathBuf::from(env!("CARGO_MANIFEST_DIR")),
+                swcrc: false,
+                is_module: true,
+                ..Default::default()
+            },
+        );
+
+        match s {
+            Ok(v) => {
+                if c.handler.has_errors() {
+                    Err(())
+                } else {
+                    Ok(v.code)
+                }
+            }
+            Err(..) => Err(()),
+        }
+    })
+}
+
+fn plugin(name: &str, order: PluginOrder) -> PluginConfig {
+    PluginConfig {
+        path: format!("tests/plugin/{}.wat", name).into(),
+        config: Default::default(),
+        order,
+    }
+}
+
+const SRC: &str = "
+class Foo<T> {
+    constructor(private foo: T) {}
+}
+export const a = (v: number) => new Foo(v);
+";
+
+#[test]
+fn echo_before_transforms() {
+    assert_eq!(
+        compile(SRC, vec![plugin("echo", PluginOrder::BeforeTransforms)]),
+        compile(SRC, vec![])
+    );
+}
+
+#[test]
+fn echo_after_transforms() {
+    assert_eq!(
+        compile(SRC, vec![plugin("echo", PluginOrder::AfterTransforms)]),
+        compile(SRC, vec![])
+    );
+}
+
+#[test]
+fn echo_binary() {
+    assert_eq!(
+        compile(
+            SRC,
+            vec![plugin("echo_binary", PluginOrder::BeforeTransforms)]
+        ),
+        compile(SRC, vec![])
+    );
+}
+
+#[test]
+fn plugin_error() {
+    assert_eq!(
+        compile(SRC, vec![plugin("error", PluginOrder::BeforeTransforms)]),
+        Err(())
+    );
+}
+
+#[test]
+fn missing_plugin() {
+    assert_eq!(
+        compile(SRC, vec![plugin("missing", PluginOrder::BeforeTransforms)]),
+        Err(())
+    );
+}
+
+#[test]
+fn plugin_runs_out_of_fuel() {
+    assert_eq!(
+        compile(
+            SRC,
+            vec![plugin("infinite_loop", PluginOrder::BeforeTransforms)]
+        ),
+        Err(())
+    );
+}
+
+#[test]
+fn plugin_memory_is_limited() {
+    assert_eq!(
+        compile(
+            SRC,
+            vec![plugin("grow_memory", PluginOrder::BeforeTransforms)]
+        ),
+        Err(())
+    );
+}
@@ -0,0 +1,14 @@
+;; Always reports an error.
+(module
+  (import "swc" "set_error" (func $set_error (param i32 i32)))
+  (memory (export "memory") 1)
+  (data (i32.const 0) "unsupported syntax")
+
+  (func (export "__swc_plugin_alloc") (param $len i32) (result i32)
+    (i32.const 1024))
+
+  (func (export "__swc_plugin_transform")
+    (param $program i32) (param $program_len i32) (param $config i32) (param $config_len i32)
+    (result i64)
+    (call $set_error (i32.const 0) (i32.const 18))
+    (i64.const 0)))
@@ -0,0 +1,27 @@
+;; Grows the memory to 1GiB and returns the input program as-is.
+(module
+  (memory (export "memory") 1)
+  (global $next (mut i32) (i32.const 1024))
+
+  (func (export "__swc_plugin_alloc") (param $len i32) (result i32)
+    (local $ptr i32)
+    (local.set $ptr (global.get $next))
+    (global.set $next (i32.add (local.get $ptr) (local.get $len)))
+    (if (i32.gt_u (global.get $next) (i32.mul (memory.size) (i32.const 65536)))
+      (then
+        (drop (memory.grow
+          (i32.add
+            (i32.div_u
+              (i32.sub (global.get $next) (i32.mul (memory.size) (i32.const 65536)))
+              (i32.const 65536))
+            (i32.const 1))))))
+    (local.get $ptr))
+
+  (func (export "__swc_plugin_transform")
+    (param $program i32) (param $program_len i32) (param $config i32) (param $config_len i32)
+    (result i64)
+    (if (i32.eq (memory.grow (i32.const 16384)) (i32.const -1))
+      (then unreachable))
+    (i64.or
+      (i64.shl (i64.extend_i32_u (local.get $program)) (i64.const 32))
+      (i64.extend_i32_u (local.get $program_len)))))
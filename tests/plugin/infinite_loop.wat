@@ -0,0 +1,13 @@
+;; Never returns.
+(module
+  (memory (export "memory") 1)
+
+  (func (export "__swc_plugin_alloc") (param $len i32) (result i32)
+    (i32.const 1024))
+
+  (func (export "__swc_plugin_transform")
+    (param $program i32) (param $program_len i32) (param $config i32) (param $config_len i32)
+    (result i64)
+    (loop $continue
+      (br $continue))
+    (i64.const 0)))
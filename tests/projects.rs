@@ -277,6 +277,16 @@ fn issue_414() {
     assert!(s2.contains("function(_bar) {"));
 }
 
+/// should merge the config extended by .swcrc
+#[test]
+fn swcrc_extends() {
+    let s = file("tests/projects/swcrc-extends/packages/a/input.js").unwrap();
+    println!("{}", s);
+
+    assert!(s.contains("define("));
+    assert!(!s.contains("=>"));
+}
+
 /// should handle comments in return statement
 #[test]
 fn issue_415() {
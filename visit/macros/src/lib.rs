@@ -7,10 +7,10 @@ use std::{collections::HashSet, mem::replace};
 use swc_macros_common::{call_site, def_site};
 use syn::{
     parse_quote::parse, punctuated::Punctuated, spanned::Spanned, Arm, AttrStyle, Attribute, Block,
-    Expr, ExprBlock, ExprMatch, FieldValue, Fields, FnArg, GenericArgument, ImplItem,
-    ImplItemMethod, Index, Item, ItemImpl, ItemTrait, Member, Path, PathArguments, ReturnType,
-    Signature, Stmt, Token, TraitItem, TraitItemMethod, Type, TypePath, TypeReference, VisPublic,
-    Visibility,
+    Expr, ExprBlock, ExprMatch, Field, FieldValue, Fields, FnArg, GenericArgument, ImplItem,
+    ImplItemMethod, Index, Item, ItemEnum, ItemImpl, ItemTrait, Member, Path, PathArguments,
+    ReturnType, Signature, Stmt, Token, TraitItem, TraitItemMethod, Type, TypePath, TypeReference,
+    Variant, VisPublic, Visibility,
 };
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -19,6 +19,8 @@ enum Mode {
     VisitAll,
     VisitMut,
     Fold,
+    VisitAstPath,
+    VisitMutAstPath,
 }
 
 impl Mode {
@@ -28,14 +30,16 @@ impl Mode {
             Mode::VisitAll => "VisitAll",
             Mode::Visit => "Visit",
             Mode::VisitMut => "VisitMut",
+            Mode::VisitAstPath => "VisitAstPath",
+            Mode::VisitMutAstPath => "VisitMutAstPath",
         }
     }
 
     fn prefix(self) -> &'static str {
         match self {
             Mode::Fold => "fold",
-            Mode::Visit | Mode::VisitAll => "visit",
-            Mode::VisitMut => "visit_mut",
+            Mode::Visit | Mode::VisitAll | Mode::VisitAstPath => "visit",
+            Mode::VisitMut | Mode::VisitMutAstPath => "visit_mut",
         }
     }
 }
@@ -47,6 +51,10 @@ impl Mode {
 ///  - highly extensible and used to create Visitor for any types
 ///
 ///  - create `Visit`, `VisitAll`, `VisitMut`, `Fold`
+///
+///  - create `VisitAstPath` and `VisitMutAstPath` in the module `path` if the
+///    feature `path` of the calling crate is enabled. They receive the kinds of
+///    ancestor nodes as `AstKindPath`.
 #[proc_macro]
 pub fn define(tts: proc_macro::TokenStream) -> proc_macro::TokenStream {
     let block: Block = parse(tts.into());
@@ -57,6 +65,28 @@ pub fn define(tts: proc_macro::TokenStream) -> proc_macro::TokenStream {
     q.push_tokens(&make(Mode::VisitAll, &block.stmts));
     q.push_tokens(&make(Mode::VisitMut, &block.stmts));
 
+    let mut path = Quote::new_call_site();
+    path.push_tokens(&make_ast_kind(&block.stmts));
+    path.push_tokens(&make(Mode::VisitAstPath, &block.stmts));
+    path.push_tokens(&make(Mode::VisitMutAstPath, &block.stmts));
+    let items = proc_macro2::TokenStream::from(path);
+
+    q.push_tokens(&q!(Vars { items }, {
+        /// Visitors which receive the kinds of ancestor nodes.
+        #[cfg(feature = "path")]
+        pub mod path {
+            use super::*;
+
+            items
+        }
+
+        #[cfg(feature = "path")]
+        pub use self::path::{
+            AstKindPath, AstParentKind, VisitAstPath, VisitMutAstPath, VisitMutWithPath,
+            VisitWithPath,
+        };
+    }));
+
     proc_macro2::TokenStream::from(q).into()
 }
 
@@ -133,6 +163,9 @@ fn make(mode: Mode, stmts: &[Stmt]) -> Quote {
                 Mode::Fold | Mode::VisitMut => {
                     q!(Vars { visit: &name }, ({ (**self).visit(n) })).parse()
                 }
+                Mode::VisitAstPath | Mode::VisitMutAstPath => {
+                    q!(Vars { visit: &name }, ({ (**self).visit(n, ast_path) })).parse()
+                }
             };
 
             ref_methods.push(ImplItemMethod {
@@ -173,6 +206,16 @@ fn make(mode: Mode, stmts: &[Stmt]) -> Quote {
                         })
                     )
                     .parse(),
+                    Mode::VisitAstPath | Mode::VisitMutAstPath => q!(
+                        Vars { visit: &name },
+                        ({
+                            match self {
+                                swc_visit::Either::Left(v) => v.visit(n, ast_path),
+                                swc_visit::Either::Right(v) => v.visit(n, ast_path),
+                            }
+                        })
+                    )
+                    .parse(),
                 },
             });
         }
@@ -215,6 +258,15 @@ fn make(mode: Mode, stmts: &[Stmt]) -> Quote {
                         })
                     )
                     .parse(),
+                    Mode::VisitAstPath | Mode::VisitMutAstPath => q!(
+                        Vars { visit: &name },
+                        ({
+                            if self.enabled {
+                                self.visitor.visit(n, ast_path)
+                            }
+                        })
+                    )
+                    .parse(),
                 },
             });
         }
@@ -268,6 +320,12 @@ fn make(mode: Mode, stmts: &[Stmt]) -> Quote {
                     brace_token: def_site(),
                     stmts: Default::default(),
                 },
+                Mode::VisitAstPath | Mode::VisitMutAstPath => q!(Vars { fn_name: &fn_name }, {
+                    {
+                        fn_name(self, n, ast_path)
+                    }
+                })
+                .parse(),
             }),
         )
         .clone();
@@ -335,6 +393,25 @@ fn make(mode: Mode, stmts: &[Stmt]) -> Quote {
             )),
 
             Mode::VisitAll => {}
+
+            Mode::VisitAstPath | Mode::VisitMutAstPath => tokens.push_tokens(&q!(
+                Vars {
+                    fn_name,
+                    default_body,
+                    Type: arg_ty,
+                    Trait: Ident::new(mode.trait_name(), call_site()),
+                },
+                {
+                    #[allow(unused_variables)]
+                    pub fn fn_name<V: ?Sized + Trait>(
+                        _visitor: &mut V,
+                        n: Type,
+                        ast_path: &mut AstKindPath,
+                    ) {
+                        default_body
+                    }
+                }
+            )),
         }
     });
 
@@ -542,6 +619,58 @@ fn make(mode: Mode, stmts: &[Stmt]) -> Quote {
                     }
                 }
             }),
+            Mode::VisitAstPath => q!({
+                pub trait VisitWithPath<V: VisitAstPath> {
+                    fn visit_with_path(&self, v: &mut V, ast_path: &mut AstKindPath);
+
+                    /// Visit children nodes of self with `v`
+                    fn visit_children_with_path(&self, v: &mut V, ast_path: &mut AstKindPath);
+                }
+
+                impl<V, T> VisitWithPath<V> for Box<T>
+                where
+                    V: VisitAstPath,
+                    T: 'static + VisitWithPath<V>,
+                {
+                    fn visit_with_path(&self, v: &mut V, ast_path: &mut AstKindPath) {
+                        (**self).visit_with_path(v, ast_path)
+                    }
+
+                    /// Visit children nodes of self with `v`
+                    fn visit_children_with_path(&self, v: &mut V, ast_path: &mut AstKindPath) {
+                        (**self).visit_children_with_path(v, ast_path)
+                    }
+                }
+            }),
+            Mode::VisitMutAstPath => q!({
+                pub trait VisitMutWithPath<V: VisitMutAstPath> {
+                    fn visit_mut_with_path(&mut self, v: &mut V, ast_path: &mut AstKindPath);
+
+                    fn visit_mut_children_with_path(
+                        &mut self,
+                        v: &mut V,
+                        ast_path: &mut AstKindPath,
+                    );
+                }
+
+                impl<V, T> VisitMutWithPath<V> for Box<T>
+                where
+                    V: VisitMutAstPath,
+                    T: 'static + VisitMutWithPath<V>,
+                {
+                    fn visit_mut_with_path(&mut self, v: &mut V, ast_path: &mut AstKindPath) {
+                        (**self).visit_mut_with_path(v, ast_path);
+                    }
+
+                    fn visit_mut_children_with_path(
+                        &mut self,
+                        v: &mut V,
+                        ast_path: &mut AstKindPath,
+                    ) {
+                        (**self).visit_mut_children_with_path(v, ast_path);
+                    }
+                }
+            }),
         };
         tokens.push_tokens(&trait_decl);
 
@@ -688,6 +817,82 @@ fn make(mode: Mode, stmts: &[Stmt]) -> Quote {
                         }
                     ));
                 }
+
+                Mode::VisitAstPath => {
+                    let default_body = adjust_expr(mode, ty, q!({ self }).parse(), |expr| {
+                        q!(
+                            Vars {
+                                expr,
+                                method_name: &method_name
+                            },
+                            { method_name(_visitor, expr, ast_path) }
+                        )
+                        .parse()
+                    });
+
+                    tokens.push_tokens(&q!(
+                        Vars {
+                            Type: ty,
+                            expr,
+                            default_body,
+                        },
+                        {
+                            impl<V: VisitAstPath> VisitWithPath<V> for Type {
+                                fn visit_with_path(&self, v: &mut V, ast_path: &mut AstKindPath) {
+                                    expr
+                                }
+
+                                fn visit_children_with_path(
+                                    &self,
+                                    _visitor: &mut V,
+                                    ast_path: &mut AstKindPath,
+                                ) {
+                                    default_body
+                                }
+                            }
+                        }
+                    ));
+                }
+
+                Mode::VisitMutAstPath => {
+                    let default_body = adjust_expr(mode, ty, q!({ self }).parse(), |expr| {
+                        q!(
+                            Vars {
+                                expr,
+                                method_name: &method_name
+                            },
+                            { method_name(_visitor, expr, ast_path) }
+                        )
+                        .parse()
+                    });
+
+                    tokens.push_tokens(&q!(
+                        Vars {
+                            default_body,
+                            Type: ty,
+                            expr,
+                        },
+                        {
+                            impl<V: VisitMutAstPath> VisitMutWithPath<V> for Type {
+                                fn visit_mut_with_path(
+                                    &mut self,
+                                    v: &mut V,
+                                    ast_path: &mut AstKindPath,
+                                ) {
+                                    expr
+                                }
+
+                                fn visit_mut_children_with_path(
+                                    &mut self,
+                                    _visitor: &mut V,
+                                    ast_path: &mut AstKindPath,
+                                ) {
+                                    default_body
+                                }
+                            }
+                        }
+                    ));
+                }
             }
         }
     }
@@ -703,24 +908,26 @@ where
         expr = if is_opt_vec(ty) {
             match mode {
                 Mode::Fold => expr,
-                Mode::VisitMut => expr,
-                Mode::Visit | Mode::VisitAll => {
+                Mode::VisitMut | Mode::VisitMutAstPath => expr,
+                Mode::Visit | Mode::VisitAll | Mode::VisitAstPath => {
                     q!(Vars { expr }, { expr.as_ref().map(|v| &**v) }).parse()
                 }
             }
         } else {
             match mode {
                 Mode::Fold => expr,
-                Mode::VisitMut => expr,
-                Mode::Visit | Mode::VisitAll => q!(Vars { expr }, { expr.as_ref() }).parse(),
+                Mode::VisitMut | Mode::VisitMutAstPath => expr,
+                Mode::Visit | Mode::VisitAll | Mode::VisitAstPath => {
+                    q!(Vars { expr }, { expr.as_ref() }).parse()
+                }
             }
         };
     }
 
     if as_box(ty).is_some() {
         expr = match mode {
-            Mode::Visit | Mode::VisitAll => expr,
-            Mode::VisitMut => {
+            Mode::Visit | Mode::VisitAll | Mode::VisitAstPath => expr,
+            Mode::VisitMut | Mode::VisitMutAstPath => {
                 // TODO
                 expr
             }
@@ -732,8 +939,8 @@ where
 
     if as_box(ty).is_some() {
         expr = match mode {
-            Mode::Visit | Mode::VisitAll => expr,
-            Mode::VisitMut => {
+            Mode::Visit | Mode::VisitAll | Mode::VisitAstPath => expr,
+            Mode::VisitMut | Mode::VisitMutAstPath => {
                 // TODO
                 expr
             }
@@ -771,10 +978,27 @@ fn visit_expr(mode: Mode, ty: &Type, visitor: &Expr, expr: Expr) -> Expr {
             { visitor.visit_name(expr, _parent as _) }
         )
         .parse(),
+
+        Mode::VisitAstPath | Mode::VisitMutAstPath => q!(
+            Vars {
+                visitor,
+                expr,
+                visit_name
+            },
+            { visitor.visit_name(expr, ast_path) }
+        )
+        .parse(),
     })
 }
 
-fn make_arm_from_struct(mode: Mode, path: &Path, variant: &Fields) -> Arm {
+/// `kind` creates the `AstParentKind` of a field, which is used by
+/// `VisitAstPath` and `VisitMutAstPath`.
+fn make_arm_from_struct(
+    mode: Mode,
+    path: &Path,
+    variant: &Fields,
+    kind: &dyn Fn(usize, &Field) -> Expr,
+) -> Arm {
     let mut stmts = vec![];
     let mut fields: Punctuated<FieldValue, Token![,]> = Default::default();
 
@@ -798,6 +1022,26 @@ fn make_arm_from_struct(mode: Mode, path: &Path, variant: &Fields) -> Arm {
             let expr = visit_expr(mode, ty, &q!({ _visitor }).parse(), expr);
             stmts.push(match mode {
                 Mode::VisitAll | Mode::Visit | Mode::VisitMut => Stmt::Semi(expr, call_site()),
+                Mode::VisitAstPath | Mode::VisitMutAstPath => {
+                    let block = q!(
+                        Vars {
+                            kind: kind(i, field),
+                            expr
+                        },
+                        ({
+                            let mut ast_path = ast_path.with_guard(kind);
+                            let ast_path = &mut *ast_path;
+                            expr;
+                        })
+                    )
+                    .parse();
+
+                    Stmt::Expr(Expr::Block(ExprBlock {
+                        attrs: vec![],
+                        label: None,
+                        block,
+                    }))
+                }
                 Mode::Fold => q!(
                     Vars {
                         name: &binding_ident,
@@ -851,7 +1095,11 @@ fn make_arm_from_struct(mode: Mode, path: &Path, variant: &Fields) -> Arm {
                 .parse(),
             )
         }
-        Mode::VisitAll | Mode::Visit | Mode::VisitMut => {}
+        Mode::VisitAll
+        | Mode::Visit
+        | Mode::VisitMut
+        | Mode::VisitAstPath
+        | Mode::VisitMutAstPath => {}
     }
 
     let block = Block {
@@ -892,11 +1140,11 @@ fn method_sig(mode: Mode, ty: &Type) -> Signature {
                     p.push_value(q!(Vars { Type: ty }, { n: Type }).parse());
                 }
 
-                Mode::VisitMut => {
+                Mode::VisitMut | Mode::VisitMutAstPath => {
                     p.push_value(q!(Vars { Type: ty }, { n: &mut Type }).parse());
                 }
 
-                Mode::Visit | Mode::VisitAll => {
+                Mode::Visit | Mode::VisitAll | Mode::VisitAstPath => {
                     p.push_value(q!(Vars { Type: ty }, { n: &Type }).parse());
                 }
             }
@@ -909,6 +1157,10 @@ fn method_sig(mode: Mode, ty: &Type) -> Signature {
                     p.push_punct(def_site());
                     p.push_value(q!(Vars {}, { _parent: &dyn Node }).parse());
                 }
+                Mode::VisitAstPath | Mode::VisitMutAstPath => {
+                    p.push_punct(def_site());
+                    p.push_value(q!(Vars {}, { ast_path: &mut AstKindPath }).parse());
+                }
             }
 
             p
@@ -949,7 +1201,10 @@ fn make_method(mode: Mode, e: &Item, types: &mut Vec<Type>) -> Option<TraitItemM
             }
 
             let block = {
-                let arm = make_arm_from_struct(mode, &s.ident.clone().into(), &s.fields);
+                let arm =
+                    make_arm_from_struct(mode, &s.ident.clone().into(), &s.fields, &|i, f| {
+                        field_kind(type_name, &field_kind_name(i, f), is_vec_like(&f.ty))
+                    });
 
                 let mut match_expr: ExprMatch = q!((match n {})).parse();
                 match_expr.arms.push(arm);
@@ -994,6 +1249,7 @@ fn make_method(mode: Mode, e: &Item, types: &mut Vec<Type>) -> Option<TraitItemM
                         types.push(f.ty.clone());
                     }
 
+                    let indexed = variant.fields.iter().any(|f| is_vec_like(&f.ty));
                     let arm = make_arm_from_struct(
                         mode,
                         &q!(
@@ -1005,6 +1261,7 @@ fn make_method(mode: Mode, e: &Item, types: &mut Vec<Type>) -> Option<TraitItemM
                         )
                         .parse(),
                         &variant.fields,
+                        &|_, _| field_kind(type_name, &variant.ident, indexed),
                     );
                     arms.push(arm);
                 }
@@ -1058,6 +1315,10 @@ fn create_method_sig(mode: Mode, ty: &Type) -> Signature {
                         p.push_punct(def_site());
                         p.push_value(q!(Vars {}, { _parent: &dyn Node }).parse());
                     }
+                    Mode::VisitAstPath | Mode::VisitMutAstPath => {
+                        p.push_punct(def_site());
+                        p.push_value(q!(Vars {}, { ast_path: &mut AstKindPath }).parse());
+                    }
                 }
 
                 p
@@ -1104,11 +1365,11 @@ fn create_method_sig(mode: Mode, ty: &Type) -> Signature {
                             return mk_exact(mode, ident, &arg);
                         }
 
-                        Mode::VisitMut => {
+                        Mode::VisitMut | Mode::VisitMutAstPath => {
                             return mk_ref(mode, ident, &arg, true);
                         }
 
-                        Mode::Visit | Mode::VisitAll => {
+                        Mode::Visit | Mode::VisitAll | Mode::VisitAstPath => {
                             return mk_ref(mode, ident, &arg, false);
                         }
                     }
@@ -1126,14 +1387,14 @@ fn create_method_sig(mode: Mode, ty: &Type) -> Signature {
                                     &q!(Vars { item }, { Option<Vec<item>> }).parse(),
                                 );
                             }
-                            Mode::VisitMut => {
+                            Mode::VisitMut | Mode::VisitMutAstPath => {
                                 return mk_exact(
                                     mode,
                                     ident,
                                     &q!(Vars { item }, { &mut Option<Vec<item>> }).parse(),
                                 );
                             }
-                            Mode::Visit | Mode::VisitAll => {
+                            Mode::Visit | Mode::VisitAll | Mode::VisitAstPath => {
                                 return mk_exact(
                                     mode,
                                     ident,
@@ -1151,14 +1412,14 @@ fn create_method_sig(mode: Mode, ty: &Type) -> Signature {
                                 &q!(Vars { arg }, { Option<arg> }).parse(),
                             );
                         }
-                        Mode::VisitMut => {
+                        Mode::VisitMut | Mode::VisitMutAstPath => {
                             return mk_exact(
                                 mode,
                                 ident,
                                 &q!(Vars { arg }, { &mut Option<arg> }).parse(),
                             );
                         }
-                        Mode::Visit | Mode::VisitAll => {
+                        Mode::Visit | Mode::VisitAll | Mode::VisitAstPath => {
                             return mk_exact(
                                 mode,
                                 ident,
@@ -1185,7 +1446,7 @@ fn create_method_sig(mode: Mode, ty: &Type) -> Signature {
                                                 &q!(Vars { arg }, { Vec<arg> }).parse(),
                                             );
                                         }
-                                        Mode::VisitMut => {
+                                        Mode::VisitMut | Mode::VisitMutAstPath => {
                                             return mk_ref(
                                                 mode,
                                                 ident,
@@ -1193,7 +1454,7 @@ fn create_method_sig(mode: Mode, ty: &Type) -> Signature {
                                                 true,
                                             );
                                         }
-                                        Mode::Visit | Mode::VisitAll => {
+                                        Mode::Visit | Mode::VisitAll | Mode::VisitAstPath => {
                                             return mk_ref(
                                                 mode,
                                                 ident,
@@ -1213,10 +1474,10 @@ fn create_method_sig(mode: Mode, ty: &Type) -> Signature {
 
             match mode {
                 Mode::Fold => return mk_exact(mode, ident, ty),
-                Mode::VisitMut => {
+                Mode::VisitMut | Mode::VisitMutAstPath => {
                     return mk_ref(mode, ident, ty, true);
                 }
-                Mode::Visit | Mode::VisitAll => {
+                Mode::Visit | Mode::VisitAll | Mode::VisitAstPath => {
                     return mk_ref(mode, ident, ty, false);
                 }
             }
@@ -1241,7 +1502,12 @@ fn create_method_body(mode: Mode, ty: &Type) -> Block {
 
                 return q!(Vars { visit }, ({ _visitor.visit(n, _parent) })).parse();
             }
-            Mode::VisitMut => {
+            Mode::VisitAstPath => {
+                let visit = method_name(mode, ty);
+
+                return q!(Vars { visit }, ({ _visitor.visit(n, ast_path) })).parse();
+            }
+            Mode::VisitMut | Mode::VisitMutAstPath => {
                 return Block {
                     brace_token: def_site(),
                     stmts: vec![],
@@ -1275,7 +1541,11 @@ fn create_method_body(mode: Mode, ty: &Type) -> Block {
                             )
                             .parse();
                         }
-                        Mode::VisitAll | Mode::Visit | Mode::VisitMut => {
+                        Mode::VisitAll
+                        | Mode::Visit
+                        | Mode::VisitMut
+                        | Mode::VisitAstPath
+                        | Mode::VisitMutAstPath => {
                             return create_method_body(mode, arg);
                         }
                     }
@@ -1346,6 +1616,17 @@ fn create_method_body(mode: Mode, ty: &Type) -> Block {
                                             })
                                         )
                                         .parse(),
+
+                                        Mode::VisitAstPath | Mode::VisitMutAstPath => q!(
+                                            Vars { ident },
+                                            ({
+                                                match n {
+                                                    Some(n) => _visitor.ident(n, ast_path),
+                                                    None => {}
+                                                }
+                                            })
+                                        )
+                                        .parse(),
                                     };
                                 }
                                 _ => unimplemented!("generic parameter other than type"),
@@ -1379,8 +1660,8 @@ fn create_method_body(mode: Mode, ty: &Type) -> Block {
                                                 .parse();
                                             }
                                         }
-                                        Mode::Visit | Mode::VisitAll => {}
-                                        Mode::VisitMut => {}
+                                        Mode::Visit | Mode::VisitAll | Mode::VisitAstPath => {}
+                                        Mode::VisitMut | Mode::VisitMutAstPath => {}
                                     }
 
                                     return if is_option(arg) {
@@ -1409,6 +1690,28 @@ fn create_method_body(mode: Mode, ty: &Type) -> Block {
                                                 })
                                             )
                                             .parse(),
+                                            Mode::VisitMutAstPath => q!(
+                                                Vars { ident },
+                                                ({
+                                                    n.iter_mut().enumerate().for_each(|(idx, v)| {
+                                                        let mut ast_path =
+                                                            ast_path.with_index_guard(idx);
+                                                        _visitor.ident(v, &mut *ast_path)
+                                                    })
+                                                })
+                                            )
+                                            .parse(),
+                                            Mode::VisitAstPath => q!(
+                                                Vars { ident },
+                                                ({
+                                                    n.iter().enumerate().for_each(|(idx, v)| {
+                                                        let mut ast_path =
+                                                            ast_path.with_index_guard(idx);
+                                                        _visitor.ident(v.as_ref(), &mut *ast_path)
+                                                    })
+                                                })
+                                            )
+                                            .parse(),
                                         }
                                     } else {
                                         match mode {
@@ -1437,6 +1740,30 @@ fn create_method_body(mode: Mode, ty: &Type) -> Block {
                                                 })
                                             )
                                             .parse(),
+
+                                            Mode::VisitMutAstPath => q!(
+                                                Vars { ident },
+                                                ({
+                                                    n.iter_mut().enumerate().for_each(|(idx, v)| {
+                                                        let mut ast_path =
+                                                            ast_path.with_index_guard(idx);
+                                                        _visitor.ident(v, &mut *ast_path)
+                                                    })
+                                                })
+                                            )
+                                            .parse(),
+
+                                            Mode::VisitAstPath => q!(
+                                                Vars { ident },
+                                                ({
+                                                    n.iter().enumerate().for_each(|(idx, v)| {
+                                                        let mut ast_path =
+                                                            ast_path.with_index_guard(idx);
+                                                        _visitor.ident(v, &mut *ast_path)
+                                                    })
+                                                })
+                                            )
+                                            .parse(),
                                         }
                                     };
                                 }
@@ -1450,7 +1777,11 @@ fn create_method_body(mode: Mode, ty: &Type) -> Block {
 
             match mode {
                 Mode::Fold => q!(({ return n })).parse(),
-                Mode::VisitAll | Mode::Visit | Mode::VisitMut => q!(({})).parse(),
+                Mode::VisitAll
+                | Mode::Visit
+                | Mode::VisitMut
+                | Mode::VisitAstPath
+                | Mode::VisitMutAstPath => q!(({})).parse(),
             }
         }
         Type::Ptr(_) => unimplemented!("type: pointer"),
@@ -1465,6 +1796,212 @@ fn create_method_body(mode: Mode, ty: &Type) -> Block {
     }
 }
 
+/// Creates `AstParentKind`, which has a variant for each node type, and an
+/// enum for the fields of each node type in the module `fields`.
+///
+/// Variants of an enum node type are used as its fields.
+fn make_ast_kind(stmts: &[Stmt]) -> Quote {
+    let mut tokens = q!({});
+    let mut field_enums = q!({});
+
+    let mut kind: ItemEnum = q!({
+        /// Kind of an ancestor node and its field which contains the current
+        /// node.
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        pub enum AstParentKind {}
+    })
+    .parse();
+    let mut kind_arms = vec![];
+
+    for stmt in stmts {
+        let item = match stmt {
+            Stmt::Item(item) => item,
+            _ => unimplemented!("error reporting for something other than Item"),
+        };
+
+        let (type_name, fields): (_, Vec<_>) = match item {
+            Item::Struct(s) => (
+                &s.ident,
+                s.fields
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, f)| !skip(&f.ty))
+                    .map(|(i, f)| (field_kind_name(i, f), is_vec_like(&f.ty)))
+                    .collect(),
+            ),
+            Item::Enum(e) => (
+                &e.ident,
+                e.variants
+                    .iter()
+                    .filter(|v| v.fields.iter().any(|f| !skip(&f.ty)))
+                    .map(|v| (v.ident.clone(), v.fields.iter().any(|f| is_vec_like(&f.ty))))
+                    .collect(),
+            ),
+            _ => unimplemented!(
+                "proper error reporting for item other than struct / enum: {:?}",
+                item
+            ),
+        };
+        let enum_name = field_enum_name(type_name);
+
+        let mut field_enum: ItemEnum = q!(Vars { Name: &enum_name }, {
+            #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+            pub enum Name {}
+        })
+        .parse();
+        let mut index_arms = vec![];
+
+        for (name, indexed) in &fields {
+            if *indexed {
+                field_enum
+                    .variants
+                    .push(q!(Vars { Name: name }, { Name(usize) }).parse::<Variant>());
+                index_arms.push(
+                    q!(
+                        Vars {
+                            Enum: &enum_name,
+                            Name: name
+                        },
+                        (Enum::Name(idx) => {
+                            *idx = index;
+                        })
+                    )
+                    .parse::<Arm>(),
+                );
+            } else {
+                field_enum
+                    .variants
+                    .push(q!(Vars { Name: name }, { Name }).parse::<Variant>());
+            }
+        }
+        // `self` is a reference, so an empty match is not allowed.
+        if fields.is_empty() || index_arms.len() != fields.len() {
+            index_arms.push(q!((_ => {})).parse());
+        }
+
+        let mut match_expr: ExprMatch = q!((match self {})).parse();
+        match_expr.arms = index_arms;
+
+        field_enums.push_tokens(&field_enum);
+        field_enums.push_tokens(&q!(
+            Vars {
+                Name: &enum_name,
+                match_expr
+            },
+            {
+                impl ::swc_visit::ParentKind for Name {
+                    #[allow(unused_variables)]
+                    fn set_index(&mut self, index: usize) {
+                        match_expr
+                    }
+                }
+            }
+        ));
+
+        kind.variants.push(
+            q!(
+                Vars {
+                    Type: type_name,
+                    Field: &enum_name
+                },
+                { Type(fields::Field) }
+            )
+            .parse(),
+        );
+        kind_arms.push(
+            q!(
+                Vars { Type: type_name },
+                (AstParentKind::Type(v) => {
+                    ::swc_visit::ParentKind::set_index(v, index);
+                })
+            )
+            .parse::<Arm>(),
+        );
+    }
+
+    let mut match_expr: ExprMatch = q!((match self {})).parse();
+    match_expr.arms = kind_arms;
+
+    tokens.push_tokens(&kind);
+    tokens.push_tokens(&q!(Vars { match_expr }, {
+        impl ::swc_visit::ParentKind for AstParentKind {
+            fn set_index(&mut self, index: usize) {
+                match_expr
+            }
+        }
+
+        pub type AstKindPath = ::swc_visit::AstKindPath<AstParentKind>;
+    }));
+
+    let field_enums = proc_macro2::TokenStream::from(field_enums);
+    tokens.push_tokens(&q!(Vars { field_enums }, {
+        /// Fields of node types, which are stored in `AstParentKind`.
+        pub mod fields {
+            field_enums
+        }
+    }));
+
+    tokens
+}
+
+/// Name of the enum for the fields of `type_name`.
+fn field_enum_name(type_name: &Ident) -> Ident {
+    Ident::new(&format!("{}Field", type_name), type_name.span())
+}
+
+/// Name of the variant for a field of a struct.
+fn field_kind_name(i: usize, f: &Field) -> Ident {
+    match &f.ident {
+        Some(ident) => {
+            let name = ident.to_string();
+            let name = name.trim_start_matches("r#").to_pascal_case();
+            Ident::new(&name, ident.span())
+        }
+        None => Ident::new(&format!("Field{}", i), f.ty.span()),
+    }
+}
+
+/// Creates `AstParentKind::Type(fields::TypeField::Name)`, with `usize::MAX`
+/// as the index if `indexed` is true.
+fn field_kind(type_name: &Ident, name: &Ident, indexed: bool) -> Expr {
+    let enum_name = field_enum_name(type_name);
+
+    if indexed {
+        q!(
+            Vars {
+                Type: type_name,
+                Field: &enum_name,
+                Name: name
+            },
+            { AstParentKind::Type(fields::Field::Name(usize::MAX)) }
+        )
+        .parse()
+    } else {
+        q!(
+            Vars {
+                Type: type_name,
+                Field: &enum_name,
+                Name: name
+            },
+            { AstParentKind::Type(fields::Field::Name) }
+        )
+        .parse()
+    }
+}
+
+/// Returns true if the node is stored in a list, which is indexed in
+/// `AstKindPath`.
+fn is_vec_like(ty: &Type) -> bool {
+    if let Some(ty) = extract_generic("Option", ty)
+        .or_else(|| extract_generic("Box", ty))
+        .or_else(|| extract_generic("Arc", ty))
+    {
+        return is_vec_like(ty);
+    }
+
+    extract_vec(ty).is_some()
+}
+
 fn add_required(types: &mut Vec<Type>, ty: &Type) {
     if let Some(ty) = extract_generic("Option", ty) {
         add_required(types, ty);
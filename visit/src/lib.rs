@@ -1,6 +1,8 @@
+pub use self::path::{AstKindPath, AstKindPathGuard, AstKindPathIndexGuard, ParentKind};
 pub use either::Either;
 pub use swc_visit_macros::define;
 
+mod path;
 pub mod util;
 
 /// Visit all children nodes. This converts `VisitAll` to `Visit`. The type
@@ -0,0 +1,148 @@
+use std::ops::{Deref, DerefMut};
+
+/// Kind of an ancestor node, which is stored in [AstKindPath].
+///
+/// Implemented by `AstParentKind` generated by [define](crate::define).
+pub trait ParentKind: Copy {
+    /// Sets the index of the current node if the field containing it is a
+    /// list.
+    fn set_index(&mut self, index: usize);
+}
+
+/// Kinds of ancestor nodes, from the root to the parent of the current node.
+///
+/// For a field which is a list, the index of the current node is stored in
+/// the kind. `usize::MAX` is used while visiting the list itself.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct AstKindPath<K>
+where
+    K: ParentKind,
+{
+    path: Vec<K>,
+}
+
+impl<K> Default for AstKindPath<K>
+where
+    K: ParentKind,
+{
+    fn default() -> Self {
+        Self { path: vec![] }
+    }
+}
+
+impl<K> AstKindPath<K>
+where
+    K: ParentKind,
+{
+    pub fn new(path: Vec<K>) -> Self {
+        Self { path }
+    }
+
+    /// Pushes `kind`, which is popped when the returned guard is dropped.
+    pub fn with_guard(&mut self, kind: K) -> AstKindPathGuard<K> {
+        self.path.push(kind);
+
+        AstKindPathGuard { path: self }
+    }
+
+    /// Stores `index` in the last kind, which is reset when the returned guard
+    /// is dropped.
+    pub fn with_index_guard(&mut self, index: usize) -> AstKindPathIndexGuard<K> {
+        if let Some(kind) = self.path.last_mut() {
+            kind.set_index(index);
+        }
+
+        AstKindPathIndexGuard { path: self }
+    }
+
+    pub fn into_inner(self) -> Vec<K> {
+        self.path
+    }
+}
+
+impl<K> Deref for AstKindPath<K>
+where
+    K: ParentKind,
+{
+    type Target = [K];
+
+    fn deref(&self) -> &[K] {
+        &self.path
+    }
+}
+
+/// See [AstKindPath::with_guard].
+pub struct AstKindPathGuard<'a, K>
+where
+    K: ParentKind,
+{
+    path: &'a mut AstKindPath<K>,
+}
+
+impl<K> Deref for AstKindPathGuard<'_, K>
+where
+    K: ParentKind,
+{
+    type Target = AstKindPath<K>;
+
+    fn deref(&self) -> &AstKindPath<K> {
+        self.path
+    }
+}
+
+impl<K> DerefMut for AstKindPathGuard<'_, K>
+where
+    K: ParentKind,
+{
+    fn deref_mut(&mut self) -> &mut AstKindPath<K> {
+        self.path
+    }
+}
+
+impl<K> Drop for AstKindPathGuard<'_, K>
+where
+    K: ParentKind,
+{
+    fn drop(&mut self) {
+        self.path.path.pop();
+    }
+}
+
+/// See [AstKindPath::with_index_guard].
+pub struct AstKindPathIndexGuard<'a, K>
+where
+    K: ParentKind,
+{
+    path: &'a mut AstKindPath<K>,
+}
+
+impl<K> Deref for AstKindPathIndexGuard<'_, K>
+where
+    K: ParentKind,
+{
+    type Target = AstKindPath<K>;
+
+    fn deref(&self) -> &AstKindPath<K> {
+        self.path
+    }
+}
+
+impl<K> DerefMut for AstKindPathIndexGuard<'_, K>
+where
+    K: ParentKind,
+{
+    fn deref_mut(&mut self) -> &mut AstKindPath<K> {
+        self.path
+    }
+}
+
+impl<K> Drop for AstKindPathIndexGuard<'_, K>
+where
+    K: ParentKind,
+{
+    fn drop(&mut self) {
+        if let Some(kind) = self.path.path.last_mut() {
+            kind.set_index(usize::MAX);
+        }
+    }
+}
@@ -0,0 +1,130 @@
+#![cfg(feature = "path")]
+
+use std::any::Any;
+use swc_visit::define;
+
+/// Visitable nodes.
+pub trait Node: Any {}
+
+impl<T: ?Sized> Node for T where T: Any {}
+
+pub struct Item {
+    pub value: usize,
+    pub inner: Option<Box<Item>>,
+    pub items: Vec<Enum>,
+}
+pub enum Enum {
+    Item(Item),
+    Items(Vec<Item>),
+}
+
+define!({
+    pub struct Item {
+        pub value: usize,
+        pub inner: Option<Box<Item>>,
+        pub items: Vec<Enum>,
+    }
+    pub enum Enum {
+        Item(Item),
+        Items(Vec<Item>),
+    }
+});
+
+use path::fields::{EnumField, ItemField};
+
+fn item(value: usize, inner: Option<Item>, items: Vec<Enum>) -> Item {
+    Item {
+        value,
+        inner: inner.map(Box::new),
+        items,
+    }
+}
+
+/// Records the path of each item.
+#[derive(Default)]
+struct Recorder {
+    paths: Vec<(usize, Vec<AstParentKind>)>,
+}
+
+impl VisitAstPath for Recorder {
+    fn visit_item(&mut self, n: &Item, ast_path: &mut AstKindPath) {
+        self.paths.push((n.value, ast_path.to_vec()));
+        n.visit_children_with_path(self, ast_path);
+    }
+}
+
+#[test]
+fn visit_path() {
+    let root = item(
+        0,
+        Some(item(1, None, vec![])),
+        vec![
+            Enum::Item(item(2, None, vec![])),
+            Enum::Items(vec![item(3, None, vec![]), item(4, None, vec![])]),
+        ],
+    );
+
+    let mut v = Recorder::default();
+    let mut ast_path = AstKindPath::default();
+    root.visit_with_path(&mut v, &mut ast_path);
+
+    assert!(ast_path.is_empty());
+    assert_eq!(
+        v.paths,
+        vec![
+            (0, vec![]),
+            (1, vec![AstParentKind::Item(ItemField::Inner)]),
+            (
+                2,
+                vec![
+                    AstParentKind::Item(ItemField::Items(0)),
+                    AstParentKind::Enum(EnumField::Item),
+                ]
+            ),
+            (
+                3,
+                vec![
+                    AstParentKind::Item(ItemField::Items(1)),
+                    AstParentKind::Enum(EnumField::Items(0)),
+                ]
+            ),
+            (
+                4,
+                vec![
+                    AstParentKind::Item(ItemField::Items(1)),
+                    AstParentKind::Enum(EnumField::Items(1)),
+                ]
+            ),
+        ]
+    );
+}
+
+/// Increments values of items in `items` of the root.
+struct Increment;
+
+impl VisitMutAstPath for Increment {
+    fn visit_mut_item(&mut self, n: &mut Item, ast_path: &mut AstKindPath) {
+        if let [AstParentKind::Item(ItemField::Items(..)), ..] = &**ast_path {
+            n.value += 1;
+        }
+        n.visit_mut_children_with_path(self, ast_path);
+    }
+}
+
+#[test]
+fn visit_mut_path() {
+    let mut root = item(
+        0,
+        Some(item(1, None, vec![])),
+        vec![Enum::Items(vec![item(2, None, vec![])])],
+    );
+
+    root.visit_mut_with_path(&mut Increment, &mut Default::default());
+
+    assert_eq!(root.value, 0);
+    assert_eq!(root.inner.as_ref().unwrap().value, 1);
+    match &root.items[0] {
+        Enum::Items(items) => assert_eq!(items[0].value, 3),
+        _ => unreachable!(),
+    }
+}
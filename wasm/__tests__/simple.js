@@ -3,3 +3,12 @@ const swc = require("../pkg");
 it("should be loadable", function () {
   const output = swc.transformSync("class Foo {}", {});
 });
+
+it("should return jsdoc comments", function () {
+  const output = swc.parseSync("/** Docs */\nfunction foo() {}", {
+    syntax: "ecmascript",
+    jsdoc: true,
+  });
+
+  expect(output.jsdoc.map((item) => item.name)).toEqual(["foo"]);
+});
@@ -12,7 +12,10 @@ use swc_common::{
     errors::{DiagnosticBuilder, Emitter, Handler, SourceMapperDyn},
     FileName, FilePathMapping, SourceMap,
 };
-use swc_ecmascript::ast::Program;
+use swc_ecmascript::{
+    ast::{binary, Program},
+    parser::lexer::lossless::to_utf16_offsets,
+};
 use wasm_bindgen::prelude::*;
 
 #[wasm_bindgen(js_name = "parseSync")]
@@ -25,12 +28,72 @@ pub fn parse_sync(s: &str, opts: JsValue) -> Result<JsValue, JsValue> {
 
     let (c, errors) = compiler();
 
+    let fm = c.cm.new_source_file(FileName::Anon, s.into());
+    let program = c
+        .parse_js(
+            fm,
+            opts.target,
+            opts.syntax,
+            opts.is_module,
+            opts.comments || opts.jsdoc,
+        )
+        .map_err(|err| format!("failed to parse: {}\n{}", err, errors))?;
+
+    if !opts.jsdoc {
+        return Ok(JsValue::from_serde(&program)
+            .map_err(|err| format!("failed to return value: {}", err))?);
+    }
+
+    let docs = c.collect_jsdoc(&program);
+    let mut json = serde_json::to_value(&program)
+        .map_err(|err| format!("failed to serialize Program: {}", err))?;
+    if let Some(obj) = json.as_object_mut() {
+        obj.insert(
+            "jsdoc".into(),
+            serde_json::to_value(&docs)
+                .map_err(|err| format!("failed to serialize jsdoc: {}", err))?,
+        );
+    }
+
+    Ok(JsValue::from_serde(&json).map_err(|err| format!("failed to return value: {}", err))?)
+}
+
+#[wasm_bindgen(js_name = "parseBinarySync")]
+pub fn parse_binary_sync(s: &str, opts: JsValue) -> Result<Vec<u8>, JsValue> {
+    console_error_panic_hook::set_once();
+
+    let opts: ParseOptions = opts
+        .into_serde()
+        .map_err(|err| format!("failed to parse options: {}", err))?;
+    if opts.jsdoc {
+        return Err("`jsdoc` is not supported by parseBinarySync".into());
+    }
+
+    let (c, errors) = compiler();
+
     let fm = c.cm.new_source_file(FileName::Anon, s.into());
     let program = c
         .parse_js(fm, opts.target, opts.syntax, opts.is_module, opts.comments)
         .map_err(|err| format!("failed to parse: {}\n{}", err, errors))?;
 
-    Ok(JsValue::from_serde(&program).map_err(|err| format!("failed to return value: {}", err))?)
+    Ok(binary::to_vec(&program).map_err(|err| format!("failed to encode program: {}", err))?)
+}
+
+#[wasm_bindgen(js_name = "tokenizeSync")]
+pub fn tokenize_sync(s: &str, opts: JsValue) -> Result<JsValue, JsValue> {
+    console_error_panic_hook::set_once();
+
+    let opts: ParseOptions = opts
+        .into_serde()
+        .map_err(|err| format!("failed to parse options: {}", err))?;
+
+    let (c, _errors) = compiler();
+
+    let fm = c.cm.new_source_file(FileName::Anon, s.into());
+    let mut tokens = c.tokenize(fm, opts.target, opts.syntax);
+    to_utf16_offsets(&mut tokens);
+
+    Ok(JsValue::from_serde(&tokens).map_err(|err| format!("failed to return value: {}", err))?)
 }
 
 #[wasm_bindgen(js_name = "printSync")]
@@ -62,6 +125,34 @@ pub fn print_sync(s: JsValue, opts: JsValue) -> Result<JsValue, JsValue> {
     Ok(JsValue::from_serde(&s).map_err(|err| format!("failed to print: {}\n{}", err, errors))?)
 }
 
+#[wasm_bindgen(js_name = "printBinarySync")]
+pub fn print_binary_sync(data: &[u8], opts: JsValue) -> Result<JsValue, JsValue> {
+    console_error_panic_hook::set_once();
+
+    let program: Program =
+        binary::from_slice(data).map_err(|err| format!("not a program: {}", err))?;
+
+    let opts: Options = opts
+        .into_serde()
+        .map_err(|err| format!("failed to parse options: {}", err))?;
+
+    let (c, errors) = compiler();
+
+    let s = c
+        .print(
+            &program,
+            opts.codegen_target().unwrap_or(JscTarget::Es2020),
+            opts.source_maps
+                .clone()
+                .unwrap_or(SourceMapsConfig::Bool(false)),
+            None,
+            opts.config.minify.unwrap_or_default(),
+        )
+        .map_err(|err| format!("failed to print: {}\n{}", err, errors))?;
+
+    Ok(JsValue::from_serde(&s).map_err(|err| format!("failed to print: {}\n{}", err, errors))?)
+}
+
 #[wasm_bindgen(js_name = "transformSync")]
 pub fn transform_sync(s: &str, opts: JsValue) -> Result<JsValue, JsValue> {
     console_error_panic_hook::set_once();